@@ -0,0 +1,31 @@
+// Build script: compiles the FFI lesson's C library with the system `cc`
+// The example links it with #[link(name = "greeting", kind = "static")]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn run(command: &mut Command) {
+    let status = command
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run {:?}: {}", command, e));
+    assert!(status.success(), "{:?} exited with {}", command, status);
+}
+
+fn main() {
+    let source = "examples/ffi/greeting.c";
+    println!("cargo:rerun-if-changed={}", source);
+    println!("cargo:rerun-if-env-changed=CC");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let object = out_dir.join("greeting.o");
+    let archive = out_dir.join("libgreeting.a");
+
+    let cc = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    run(Command::new(cc)
+        .args(["-c", "-O2", "-fPIC", "-Wall", source, "-o"])
+        .arg(&object));
+    run(Command::new("ar").arg("rcs").arg(&archive).arg(&object));
+
+    println!("cargo:rustc-link-search=native={}", out_dir.display());
+}
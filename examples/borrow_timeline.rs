@@ -0,0 +1,75 @@
+// Borrow Timeline Visualiser
+// Draws where each binding lives and where each borrow is live, next to the source
+
+use learn_rust::timeline::{self, Diagram, BUILTIN, LEGEND};
+use std::env;
+use std::fs;
+use std::process;
+
+fn print_menu() {
+    println!("\n=== Borrow Timelines ===");
+    for (file, text) in BUILTIN {
+        println!("{}", file);
+        for diagram in timeline::parse_file(text).unwrap_or_default() {
+            println!("   {}", diagram.title);
+        }
+    }
+    println!("\nUsage: cargo run --example borrow_timeline [2|3|5|6] [section]");
+    println!("       cargo run --example borrow_timeline <annotated-snippet.txt>");
+}
+
+fn load(arg: &str) -> Result<Vec<Diagram>, String> {
+    // "3" picks 03_mutable_borrowing.rs, and so on
+    let prefix = format!("{:0>2}_", arg);
+    if let Some((_, text)) = BUILTIN.iter().find(|(file, _)| file.starts_with(&prefix)) {
+        return timeline::parse_file(text);
+    }
+
+    let text = fs::read_to_string(arg).map_err(|e| format!("{}: {}", arg, e))?;
+    if text.lines().any(|line| line.starts_with("== ")) {
+        timeline::parse_file(&text)
+    } else {
+        timeline::parse_snippet(arg, &text).map(|diagram| vec![diagram])
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let choice = if args.len() > 1 {
+        args[1].as_str()
+    } else {
+        print_menu();
+        return;
+    };
+
+    let diagrams = match load(choice) {
+        Ok(diagrams) => diagrams,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // Optional section number: "3 2" shows every diagram for section 2
+    let section = args.get(2).map(|s| format!("{}. ", s));
+    let selected: Vec<&Diagram> = diagrams
+        .iter()
+        .filter(|d| {
+            section
+                .as_ref()
+                .is_none_or(|prefix| d.title.starts_with(prefix))
+        })
+        .collect();
+
+    if selected.is_empty() {
+        println!("No diagram for section {}", args[2]);
+        print_menu();
+        return;
+    }
+
+    for diagram in selected {
+        println!("{}", timeline::render(diagram));
+    }
+    println!("{}", LEGEND);
+}
@@ -0,0 +1,166 @@
+// Borrow Checker REPL
+// Type ownership code line by line and see which line breaks which rule
+
+use learn_rust::minilang::{self, Session, SAMPLES};
+use learn_rust::play::{self, Limits, Outcome};
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+fn print_help() {
+    println!("   Type statements like the lesson examples, e.g.");
+    println!("      let mut s = String::from(\"hello\");");
+    println!("      let r = &s;");
+    println!("      print(r);");
+    println!("   An entry that breaks a borrow rule is rejected and explained.");
+    println!();
+    println!("   :show          the program so far");
+    println!("   :undo          remove the last entry");
+    println!("   :reset         start over");
+    println!(
+        "   :load <name>   load a sample ({}) or a file",
+        sample_names()
+    );
+    println!("   :play <file>   compile and run a Rust file for real, with rustc");
+    println!("   :help          this message");
+    println!("   :quit          exit");
+}
+
+fn sample_names() -> String {
+    SAMPLES
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn load(name: &str) -> Result<String, String> {
+    match SAMPLES.iter().find(|(sample, _)| *sample == name) {
+        Some((_, source)) => Ok(source.to_string()),
+        None => fs::read_to_string(name).map_err(|e| format!("{}: {}", name, e)),
+    }
+}
+
+fn report(result: Result<Vec<String>, String>) {
+    match result {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Err(errors) => print!("{}", errors),
+    }
+}
+
+// Real Rust rather than the mini language, through the playground
+fn play_file(name: &str) {
+    let snippet = match fs::read_to_string(name) {
+        Ok(snippet) => snippet,
+        Err(e) => {
+            println!("   Error: {}: {}", name, e);
+            return;
+        }
+    };
+    match play::play(&snippet, name, Limits::default()) {
+        Ok(Outcome::CompileError(errors)) => print!("{}", errors),
+        Ok(Outcome::Ran(run)) => {
+            print!("{}{}{}", run.warnings, run.stdout, run.stderr);
+            if let Some(reason) = run.killed {
+                println!("   Stopped: the program {}", reason);
+            }
+        }
+        Err(e) => println!("   Error: {}", e),
+    }
+}
+
+// Check and run a whole file, like `rustc file.rs && ./file`
+fn run_file(name: &str) {
+    let source = match load(name) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match minilang::check(&source) {
+        Ok(program) => match minilang::run_program(&program) {
+            Ok(lines) => lines.iter().for_each(|line| println!("{}", line)),
+            Err(e) => {
+                eprintln!("runtime error: {}", e);
+                process::exit(101);
+            }
+        },
+        Err(errors) => {
+            eprint!("{}", minilang::render_errors(&errors, &source, name));
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    if let Some(name) = env::args().nth(1) {
+        run_file(&name);
+        return;
+    }
+
+    println!("\n=== Borrow Checker REPL ===");
+    print_help();
+
+    let mut session = Session::new();
+    let mut pending = String::new();
+    let stdin = io::stdin();
+
+    loop {
+        print!("{}", if pending.is_empty() { ">> " } else { ".. " });
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            println!();
+            break;
+        }
+
+        if pending.is_empty() && line.trim().starts_with(':') {
+            let mut parts = line.trim().splitn(2, ' ');
+            match (parts.next().unwrap_or(""), parts.next()) {
+                (":quit" | ":q", _) => break,
+                (":help", _) => print_help(),
+                (":show", _) => {
+                    for (i, line) in session.source().lines().enumerate() {
+                        println!("{:>3} | {}", i + 1, line);
+                    }
+                }
+                (":undo", _) => match session.undo() {
+                    Some(entry) => println!("   Removed: {}", entry.lines().next().unwrap_or("")),
+                    None => println!("   Nothing to undo"),
+                },
+                (":reset", _) => {
+                    session.reset();
+                    println!("   Started over");
+                }
+                (":load", Some(name)) => match load(name.trim()) {
+                    Ok(source) => {
+                        session.reset();
+                        report(session.submit(&source));
+                    }
+                    Err(e) => println!("   Error: {}", e),
+                },
+                (":play", Some(name)) => play_file(name.trim()),
+                (command, _) => println!("   Unknown command {} (try :help)", command),
+            }
+            continue;
+        }
+
+        // Keep reading until braces and parentheses are balanced
+        pending.push_str(&line);
+        if minilang::is_incomplete(&pending) {
+            continue;
+        }
+        if !pending.trim().is_empty() {
+            report(session.submit(&pending));
+        }
+        pending.clear();
+    }
+}
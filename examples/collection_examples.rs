@@ -0,0 +1,198 @@
+// Collection Examples in Rust
+// HashMap, BTreeMap, HashSet and VecDeque - the collections a real find_user would use
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+// ============================================================================
+// 1. HashMap - A User Directory
+// ============================================================================
+
+fn build_directory() -> HashMap<u32, String> {
+    let mut users = HashMap::new();
+    users.insert(1, String::from("Alice"));
+    users.insert(2, String::from("Bob"));
+    users
+}
+
+// The option_examples version used if/else on the id.
+// A map lookup already returns an Option - no match needed.
+fn find_user(users: &HashMap<u32, String>, id: u32) -> Option<&String> {
+    users.get(&id)
+}
+
+fn demonstrate_hashmap() {
+    let users = build_directory();
+
+    println!("   User 1: {:?}", find_user(&users, 1));
+    println!("   User 99: {:?}", find_user(&users, 99));
+
+    // get returns Option<&String>, so every Option method works
+    let name = find_user(&users, 99).map(|n| n.as_str()).unwrap_or("Guest");
+    println!("   Username for ID 99: {}", name);
+
+    println!("   Contains ID 2? {}", users.contains_key(&2));
+    println!("   Number of users: {}", users.len());
+}
+
+// ============================================================================
+// 2. BTreeMap - Sorted Keys
+// ============================================================================
+
+fn demonstrate_btreemap() {
+    // Same API as HashMap, but iterates in key order
+    let mut users = BTreeMap::new();
+    users.insert(3, String::from("Carol"));
+    users.insert(1, String::from("Alice"));
+    users.insert(2, String::from("Bob"));
+
+    for (id, name) in &users {
+        println!("   {} => {}", id, name);
+    }
+
+    // Ordered keys make range queries possible
+    let first_two: Vec<&String> = users.range(1..=2).map(|(_, name)| name).collect();
+    println!("   IDs 1..=2: {:?}", first_two);
+
+    // HashMap iteration order is unspecified - use BTreeMap
+    // when you need stable output
+}
+
+// ============================================================================
+// 3. The Entry API - Insert or Update in One Lookup
+// ============================================================================
+
+fn demonstrate_entry() {
+    let logins = ["alice", "bob", "alice", "carol", "alice", "bob"];
+
+    // entry() gives a handle to the slot, whether it's filled or not
+    let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+    for user in logins {
+        *counts.entry(user).or_insert(0) += 1;
+    }
+    println!("   Login counts: {:?}", counts);
+
+    // or_insert_with only builds the default when the key is missing
+    let mut users: HashMap<u32, String> = build_directory();
+    let name = users.entry(3).or_insert_with(|| String::from("Carol"));
+    println!("   Inserted: {}", name);
+
+    // and_modify changes an existing value
+    users
+        .entry(1)
+        .and_modify(|name| name.push_str(" (admin)"))
+        .or_insert_with(|| String::from("Nobody"));
+    println!("   Modified: {:?}", users.get(&1));
+}
+
+// ============================================================================
+// 4. HashSet - Unique Values and Set Operations
+// ============================================================================
+
+// Sets have no order either - sort before printing
+fn sorted<'a>(set: impl Iterator<Item = &'a &'a str>) -> Vec<&'a str> {
+    let mut items: Vec<&str> = set.copied().collect();
+    items.sort();
+    items
+}
+
+fn demonstrate_hashset() {
+    let admins: HashSet<&str> = ["alice", "carol"].into_iter().collect();
+    let online: HashSet<&str> = ["alice", "bob"].into_iter().collect();
+
+    // insert returns false if the value was already there
+    let mut seen = HashSet::new();
+    println!("   First insert of alice: {}", seen.insert("alice"));
+    println!("   Second insert of alice: {}", seen.insert("alice"));
+
+    println!("   Online admins: {:?}", sorted(admins.intersection(&online)));
+    println!("   Everyone: {:?}", sorted(admins.union(&online)));
+    println!("   Offline admins: {:?}", sorted(admins.difference(&online)));
+}
+
+// ============================================================================
+// 5. VecDeque - A Queue
+// ============================================================================
+
+fn demonstrate_vecdeque() {
+    // Vec is fast at the back only; VecDeque is fast at both ends
+    let mut requests: VecDeque<u32> = VecDeque::new();
+    requests.push_back(1);
+    requests.push_back(2);
+    requests.push_back(99);
+    requests.push_front(2); // urgent request jumps the queue
+
+    let users = build_directory();
+    while let Some(id) = requests.pop_front() {
+        match find_user(&users, id) {
+            Some(name) => println!("   Serving {}", name),
+            None => println!("   Unknown user {}", id),
+        }
+    }
+}
+
+// ============================================================================
+// 6. Borrow Pitfalls - Mutating While Holding a Reference
+// ============================================================================
+
+fn demonstrate_borrow_pitfalls() {
+    let mut users = build_directory();
+
+    // get() returns a reference INTO the map
+    let alice = users.get(&1).unwrap();
+    println!("   Borrowed: {}", alice);
+    // users.insert(3, String::from("Carol")); // ERROR: users is borrowed by alice
+    // println!("{}", alice);                  // ...because alice is used here
+    // Inserting could reallocate the map and leave alice dangling
+
+    // Fix 1: finish with the reference before mutating
+    users.insert(3, String::from("Carol"));
+
+    // Fix 2: clone what you need so the borrow ends immediately
+    let bob = users.get(&2).cloned().unwrap_or_default();
+    users.remove(&2);
+    println!("   Cloned before removing: {}", bob);
+
+    // Fix 3: get_mut when you want to change the value in place
+    if let Some(name) = users.get_mut(&3) {
+        name.push('!');
+    }
+    println!("   Changed in place: {:?}", users.get(&3));
+
+    // Fix 4: entry() does lookup and mutation with a single borrow
+    users.entry(2).or_insert(bob);
+    println!("   Re-added: {:?}", users.get(&2));
+}
+
+// ============================================================================
+// Main Function - Demonstrating All Examples
+// ============================================================================
+
+fn main() {
+    println!("=== Collection Examples ===\n");
+
+    println!("1. HashMap user directory:");
+    demonstrate_hashmap();
+
+    println!("\n2. BTreeMap (sorted keys):");
+    demonstrate_btreemap();
+
+    println!("\n3. Entry API:");
+    demonstrate_entry();
+
+    println!("\n4. HashSet operations:");
+    demonstrate_hashset();
+
+    println!("\n5. VecDeque as a queue:");
+    demonstrate_vecdeque();
+
+    println!("\n6. Borrow pitfalls:");
+    demonstrate_borrow_pitfalls();
+
+    println!("\n=== Key Takeaways ===");
+    println!("• HashMap::get returns Option<&V> - handle it like any Option");
+    println!("• BTreeMap keeps keys sorted; HashMap order is unspecified");
+    println!("• entry() inserts or updates with a single lookup");
+    println!("• HashSet stores unique values and supports set operations");
+    println!("• VecDeque is a queue: push/pop at both ends");
+    println!("• Can't mutate a map while holding a reference into it");
+}
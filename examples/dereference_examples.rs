@@ -1,6 +1,8 @@
 // Dereference Operator (*) in Rust
 // Using * to access the value behind a reference
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. Basic Dereferencing
 // ============================================================================
@@ -114,6 +116,7 @@ fn demonstrate_auto_deref() {
 // 7. Dereferencing with Pattern Matching
 // ============================================================================
 
+#[allow(clippy::single_match)] // matching on *r_mut is the point
 fn demonstrate_deref_pattern() {
     let x = 5;
     let r = &x;
@@ -140,38 +143,30 @@ fn demonstrate_deref_pattern() {
 }
 
 // ============================================================================
-// Main Function
-// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Dereference Operator (*)",
+    sections: [
+        "Basic Dereferencing" => demonstrate_basic_deref,
+        "Modifying Through Mutable References" => demonstrate_mutable_deref,
+        "Dereferencing in Comparisons" => demonstrate_deref_comparison,
+        "Dereferencing in Loops" => demonstrate_deref_in_loops,
+        "Multiple Levels of References" => demonstrate_multiple_deref,
+        "Auto-Dereferencing" => demonstrate_auto_deref,
+        "Dereferencing with Pattern Matching" => demonstrate_deref_pattern,
+    ],
+    takeaways: [
+        "* accesses the value behind a reference",
+        "Use *r to read the value",
+        "Use *r = value to modify through &mut",
+        "Multiple * for multiple reference levels",
+        "Rust auto-derefs for method calls",
+        "Need explicit * for comparisons and assignments",
+    ],
+}
 
 fn main() {
-    println!("=== Dereference Operator (*) ===\n");
-
-    println!("1. Basic Dereferencing:");
-    demonstrate_basic_deref();
-    
-    println!("\n2. Modifying Through Mutable References:");
-    demonstrate_mutable_deref();
-    
-    println!("\n3. Dereferencing in Comparisons:");
-    demonstrate_deref_comparison();
-    
-    println!("\n4. Dereferencing in Loops:");
-    demonstrate_deref_in_loops();
-    
-    println!("\n5. Multiple Levels of References:");
-    demonstrate_multiple_deref();
-    
-    println!("\n6. Auto-Dereferencing:");
-    demonstrate_auto_deref();
-    
-    println!("\n7. Dereferencing with Pattern Matching:");
-    demonstrate_deref_pattern();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• * accesses the value behind a reference");
-    println!("• Use *r to read the value");
-    println!("• Use *r = value to modify through &mut");
-    println!("• Multiple * for multiple reference levels");
-    println!("• Rust auto-derefs for method calls");
-    println!("• Need explicit * for comparisons and assignments");
+    LESSON.run();
 }
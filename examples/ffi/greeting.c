@@ -0,0 +1,49 @@
+// Tiny C library for the FFI lesson (examples/ffi_examples.rs)
+// Compiled into libgreeting.a by build.rs with the system `cc`
+
+#include <ctype.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+// Implemented in Rust and exported with #[no_mangle]
+extern char *rust_to_upper(const char *s);
+extern void rust_free_string(char *s);
+
+// Borrows `s`: reads it and keeps nothing
+size_t greeting_count_vowels(const char *s) {
+    size_t count = 0;
+    for (; *s != '\0'; s++) {
+        if (strchr("aeiouAEIOU", *s) != NULL) {
+            count++;
+        }
+    }
+    return count;
+}
+
+// Returns memory C owns - give it back with greeting_free
+char *greeting_make(const char *name) {
+    size_t len = strlen("Hello, !") + strlen(name) + 1;
+    char *out = malloc(len);
+    if (out != NULL) {
+        snprintf(out, len, "Hello, %s!", name);
+    }
+    return out;
+}
+
+void greeting_free(char *s) {
+    free(s);
+}
+
+// Calls back into Rust: Rust allocates the result, C copies it out
+// and hands the pointer back to Rust to free
+int greeting_shout(const char *s, char *out, size_t out_len) {
+    char *upper = rust_to_upper(s);
+    if (upper == NULL) {
+        return -1;
+    }
+
+    int written = snprintf(out, out_len, "%s", upper);
+    rust_free_string(upper);
+    return written;
+}
@@ -0,0 +1,244 @@
+// FFI in Rust - Calling C and Being Called by C
+// CString and CStr are the String and &str of the C boundary
+
+use learn_rust::lesson;
+use std::ffi::{c_char, CStr, CString};
+
+// The C side lives in examples/ffi/greeting.c, built by build.rs
+#[link(name = "greeting", kind = "static")]
+extern "C" {
+    fn greeting_count_vowels(s: *const c_char) -> usize;
+    fn greeting_make(name: *const c_char) -> *mut c_char;
+    fn greeting_free(s: *mut c_char);
+    fn greeting_shout(s: *const c_char, out: *mut c_char, out_len: usize) -> i32;
+}
+
+// ============================================================================
+// 1. CString - Owned, Nul-Terminated
+// ============================================================================
+
+fn demonstrate_cstring() {
+    // The same strings demonstrate_conversions built as Strings
+    let s1: String = "hello".to_string();
+    let s2: String = String::from("world");
+    let s3: String = "rust".to_owned();
+
+    // String -> CString adds the trailing \0 C expects
+    for s in [s1, s2, s3] {
+        let c = CString::new(s).expect("no interior nul bytes");
+        println!(
+            "   {:?} is {} bytes with its nul",
+            c,
+            c.as_bytes_with_nul().len()
+        );
+    }
+
+    // C strings end at the first \0, so one in the middle is an error
+    match CString::new("hel\0lo") {
+        Ok(c) => println!("   Unexpected: {:?}", c),
+        Err(e) => println!("   CString::new(\"hel\\0lo\"): {}", e),
+    }
+}
+
+// ============================================================================
+// 2. Calling C - Lending a Pointer
+// ============================================================================
+
+fn count_vowels(s: &str) -> usize {
+    let c = CString::new(s).expect("no interior nul bytes");
+    // SAFETY: c is a valid nul-terminated string and outlives the call;
+    // the C function only reads it and keeps no copy
+    unsafe { greeting_count_vowels(c.as_ptr()) }
+}
+
+fn demonstrate_calling_c() {
+    println!(
+        "   Vowels in \"hello world\": {}",
+        count_vowels("hello world")
+    );
+
+    // as_ptr() BORROWS - the CString must stay alive while C uses it.
+    //     let p = CString::new("hi").unwrap().as_ptr(); // dangling!
+    // The temporary CString is dropped at the end of that line.
+    let name = CString::new("rust").unwrap();
+    let p = name.as_ptr(); // name still owns the bytes
+
+    // SAFETY: name is alive until the end of this function
+    let vowels = unsafe { greeting_count_vowels(p) };
+    println!("   Vowels in \"rust\": {}", vowels);
+}
+
+// ============================================================================
+// 3. CStr - Borrowing Memory C Owns
+// ============================================================================
+
+fn make_greeting(name: &str) -> String {
+    let name = CString::new(name).expect("no interior nul bytes");
+
+    // SAFETY: name is valid for the call; greeting_make returns a fresh
+    // malloc'd string or null
+    let raw = unsafe { greeting_make(name.as_ptr()) };
+    assert!(!raw.is_null(), "greeting_make ran out of memory");
+
+    // CStr borrows C's memory - copy it into a String we own...
+    // SAFETY: raw is non-null and nul-terminated
+    let greeting = unsafe { CStr::from_ptr(raw) }
+        .to_string_lossy()
+        .into_owned();
+
+    // ...then give the memory back to C. Rust's allocator didn't make it,
+    // so Rust must not free it (no CString::from_raw here!)
+    // SAFETY: raw came from greeting_make and is freed exactly once
+    unsafe { greeting_free(raw) };
+
+    greeting
+}
+
+fn demonstrate_cstr() {
+    println!("   From C: {}", make_greeting("world"));
+
+    // CStr also wraps Rust bytes - the &str of C strings
+    let borrowed: &CStr = c"static C string";
+    println!("   c\"...\" literal: {:?}", borrowed);
+    println!("   to_str(): {:?}", borrowed.to_str());
+}
+
+// ============================================================================
+// 4. Exposing Rust to C - extern "C" fn
+// ============================================================================
+
+// #[no_mangle] keeps the symbol name so C can find it;
+// extern "C" uses the C calling convention
+
+/// Returns an uppercase copy of `s`, owned by Rust.
+///
+/// # Safety
+///
+/// `s` must be null or a valid nul-terminated string. The result must be
+/// released with `rust_free_string`, not C's `free`.
+#[no_mangle]
+pub unsafe extern "C" fn rust_to_upper(s: *const c_char) -> *mut c_char {
+    if s.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    // SAFETY: the caller passes a valid nul-terminated string
+    let input = unsafe { CStr::from_ptr(s) };
+    let upper = input.to_string_lossy().to_uppercase();
+
+    // into_raw hands ownership to C - Rust won't drop it
+    match CString::new(upper) {
+        Ok(c) => c.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Frees a string returned by `rust_to_upper`.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer from `rust_to_upper` that hasn't been
+/// freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_free_string(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+    // SAFETY: s came from CString::into_raw in rust_to_upper
+    // and the caller frees it only once
+    drop(unsafe { CString::from_raw(s) });
+}
+
+fn shout(s: &str) -> String {
+    let input = CString::new(s).expect("no interior nul bytes");
+    let mut buffer = vec![0u8; s.len() * 4 + 1]; // room for any UTF-8 growth
+
+    // SAFETY: input is valid for the call; buffer is writable for its
+    // whole length and C writes at most buffer.len() bytes including \0
+    let written =
+        unsafe { greeting_shout(input.as_ptr(), buffer.as_mut_ptr().cast(), buffer.len()) };
+    assert!(written >= 0, "rust_to_upper returned null");
+
+    CStr::from_bytes_until_nul(&buffer)
+        .expect("C wrote a nul terminator")
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn demonstrate_rust_from_c() {
+    // Rust -> C (greeting_shout) -> Rust (rust_to_upper) -> C -> Rust
+    println!("   shout(\"hello\"): {}", shout("hello"));
+    println!("   shout(\"rust\"): {}", shout("rust"));
+}
+
+// ============================================================================
+// 5. Ownership Across the Boundary
+// ============================================================================
+
+fn demonstrate_ownership_rules() {
+    println!("   Rust -> C, borrowed:  CString::as_ptr()   Rust frees (drop)");
+    println!("   Rust -> C, owned:     CString::into_raw() C returns it to Rust");
+    println!("   C -> Rust, borrowed:  CStr::from_ptr()    C frees (its own free)");
+    println!("   Never free with the other side's allocator");
+}
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "FFI: CString and CStr",
+    sections: [
+        "CString" => demonstrate_cstring,
+        "Calling C" => demonstrate_calling_c,
+        "CStr (C-owned memory)" => demonstrate_cstr,
+        "Exposing Rust to C" => demonstrate_rust_from_c,
+        "Ownership Across the Boundary" => demonstrate_ownership_rules,
+    ],
+    takeaways: [
+        "CString: owned and nul-terminated, like String for C",
+        "CStr: borrowed C string, like &str for C",
+        "as_ptr() lends - keep the CString alive during the call",
+        "into_raw() gives ownership away; from_raw() takes it back",
+        "Whoever allocated the memory must free it",
+        "#[no_mangle] extern \"C\" fn makes Rust callable from C",
+    ],
+}
+
+fn main() {
+    LESSON.run();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rust_calls_c_with_borrowed_strings() {
+        assert_eq!(count_vowels("hello world"), 3);
+        assert_eq!(count_vowels(""), 0);
+    }
+
+    #[test]
+    fn rust_takes_c_owned_strings() {
+        assert_eq!(make_greeting("rust"), "Hello, rust!");
+    }
+
+    #[test]
+    fn c_calls_back_into_rust() {
+        assert_eq!(shout("hello"), "HELLO");
+        assert_eq!(shout("straße"), "STRASSE");
+    }
+
+    #[test]
+    fn rust_exports_round_trip() {
+        let input = CString::new("abc").unwrap();
+        // SAFETY: input is nul-terminated; upper is freed exactly once
+        unsafe {
+            let upper = rust_to_upper(input.as_ptr());
+            assert_eq!(CStr::from_ptr(upper).to_str(), Ok("ABC"));
+            rust_free_string(upper);
+            assert!(rust_to_upper(std::ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,293 @@
+// Iterators and Closures in Rust
+// Iterators produce values one at a time; closures are functions that capture their surroundings
+
+// ============================================================================
+// 1. Implementing Iterator - A Word Splitter
+// ============================================================================
+
+// Holds the part of the text we haven't handed out yet.
+// The 'a says every word we return borrows from the original text.
+struct Words<'a> {
+    rest: &'a str,
+}
+
+fn words(s: &str) -> Words<'_> {
+    Words { rest: s }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    // The only method we have to write - everything else comes for free
+    fn next(&mut self) -> Option<&'a str> {
+        let trimmed = self.rest.trim_start_matches(' ');
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let end = trimmed.find(' ').unwrap_or(trimmed.len());
+        let (word, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+// string_types' get_first_word walks the bytes by hand:
+//     for (i, &byte) in bytes.iter().enumerate() { ... }
+// With the splitter it's one call. Unlike the loop, it skips leading spaces.
+fn get_first_word(s: &str) -> &str {
+    words(s).next().unwrap_or("")
+}
+
+fn demonstrate_custom_iterator() {
+    let sentence = "hello world rust";
+
+    println!("   First word: {}", get_first_word(sentence));
+
+    for word in words(sentence) {
+        println!("   Word: {}", word);
+    }
+
+    // Calling next() by hand shows what the for loop does for us
+    let mut iter = words("one two");
+    println!("   next(): {:?}", iter.next());
+    println!("   next(): {:?}", iter.next());
+    println!("   next(): {:?}", iter.next()); // None - the iterator is done
+}
+
+// ============================================================================
+// 2. Adapter Chains - Lazy Until Consumed
+// ============================================================================
+
+// Same as sum_vector from borrowing, but taking a slice
+fn sum_vector(v: &[i32]) -> i32 {
+    v.iter().sum()
+}
+
+fn demonstrate_adapters() {
+    let numbers = vec![1, 2, 3, 4, 5, 6];
+    println!("   Numbers: {:?}", numbers);
+    println!("   Sum: {}", sum_vector(&numbers));
+
+    // Adapters (filter, map) build a pipeline; collect runs it
+    let even_squares: Vec<i32> = numbers
+        .iter()
+        .filter(|&&n| n % 2 == 0)
+        .map(|&n| n * n)
+        .collect();
+    println!("   Even squares: {:?}", even_squares);
+
+    // Nothing happens until a consumer (collect, sum, for) asks for values
+    let lazy = numbers.iter().map(|n| n * 10);
+    let first_two: Vec<i32> = lazy.take(2).collect();
+    println!("   First two * 10: {:?}", first_two);
+
+    // Our custom iterator gets every adapter for free
+    let lengths: Vec<(usize, usize)> = words("iterators are lazy")
+        .map(|w| w.len())
+        .enumerate()
+        .collect();
+    println!("   Word lengths: {:?}", lengths);
+}
+
+// ============================================================================
+// 3. iter(), iter_mut() and into_iter() - Borrowing Again
+// ============================================================================
+
+// Same as double_values from mutable_borrowing
+fn double_values(v: &mut [i32]) {
+    for num in v.iter_mut() {
+        *num *= 2;
+    }
+}
+
+fn demonstrate_iter_kinds() {
+    let mut numbers = vec![1, 2, 3];
+
+    // iter() borrows: yields &i32, numbers is still ours
+    let total: i32 = numbers.iter().sum();
+    println!("   iter() sum: {}, numbers still valid: {:?}", total, numbers);
+
+    // iter_mut() borrows mutably: yields &mut i32
+    double_values(&mut numbers);
+    println!("   iter_mut() doubled: {:?}", numbers);
+
+    // into_iter() takes ownership: yields i32, numbers is moved
+    let strings: Vec<String> = numbers.into_iter().map(|n| n.to_string()).collect();
+    println!("   into_iter() strings: {:?}", strings);
+    // println!("{:?}", numbers); // ERROR: numbers was moved
+}
+
+// ============================================================================
+// 4. Closures - Fn, FnMut and FnOnce
+// ============================================================================
+
+// Fn: only reads what it captured (like holding a & borrow)
+fn call_twice<F: Fn() -> usize>(f: F) -> usize {
+    f() + f()
+}
+
+// FnMut: changes what it captured (like holding a &mut borrow)
+fn call_three_times<F: FnMut()>(mut f: F) {
+    f();
+    f();
+    f();
+}
+
+// FnOnce: gives away what it captured, so it can only run once
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+fn demonstrate_closures() {
+    // Fn - captures `name` by & borrow
+    let name = String::from("Alice");
+    let len = || name.len();
+    println!("   Fn called twice: {}", call_twice(len));
+    println!("   name still readable: {}", name); // many & borrows are fine
+
+    // FnMut - captures `count` by &mut borrow
+    let mut count = 0;
+    let mut increment = || count += 1;
+    // println!("{}", count); // ERROR: count is mutably borrowed by increment
+    increment();
+    call_three_times(increment);
+    println!("   FnMut count: {}", count); // borrow ended with the closure
+
+    // FnOnce - moves `greeting` out when called
+    let greeting = String::from("hello");
+    let consume = || greeting; // returning it moves it out of the closure
+    println!("   FnOnce returned: {}", call_once(consume));
+    // println!("{}", greeting); // ERROR: greeting was moved
+
+    // `move` forces ownership into the closure even if it only reads
+    let owned = String::from("moved in");
+    let shout = move || owned.to_uppercase();
+    println!("   move closure: {}", shout());
+    // println!("{}", owned); // ERROR: owned now lives in the closure
+}
+
+// ============================================================================
+// 5. Comparing the Loops
+// ============================================================================
+
+// Index loop: bounds checked on every v[i]
+#[allow(clippy::needless_range_loop)]
+fn sum_with_index(v: &[i32]) -> i32 {
+    let mut total = 0;
+    for i in 0..v.len() {
+        total += v[i];
+    }
+    total
+}
+
+// What `for n in v` desugars into
+#[allow(clippy::while_let_on_iterator)]
+fn sum_with_desugared_for(v: &[i32]) -> i32 {
+    let mut total = 0;
+    let mut iter = IntoIterator::into_iter(v);
+    while let Some(n) = iter.next() {
+        total += n;
+    }
+    total
+}
+
+// Iterator chain: compiles to the same loop as the two above
+#[allow(clippy::unnecessary_fold)]
+fn sum_with_fold(v: &[i32]) -> i32 {
+    v.iter().fold(0, |total, n| total + n)
+}
+
+fn demonstrate_loop_comparison() {
+    let numbers = vec![3, 1, 4, 1, 5, 9, 2, 6];
+
+    println!("   Index loop:     {}", sum_with_index(&numbers));
+    println!("   Desugared for:  {}", sum_with_desugared_for(&numbers));
+    println!("   fold:           {}", sum_with_fold(&numbers));
+
+    // In release builds these optimise to the same tight loop -
+    // iterators are a "zero-cost abstraction", and without v[i]
+    // there is no per-index bounds check to pay for
+}
+
+// ============================================================================
+// 6. Custom Adapters with an Extension Trait
+// ============================================================================
+
+// An adapter is just an iterator that wraps another iterator
+struct RunningTotal<I> {
+    inner: I,
+    total: i32,
+}
+
+impl<I: Iterator<Item = i32>> Iterator for RunningTotal<I> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let n = self.inner.next()?;
+        self.total += n;
+        Some(self.total)
+    }
+}
+
+// Extension trait: adds .running_total() to every iterator of i32
+trait RunningTotalExt: Iterator<Item = i32> + Sized {
+    fn running_total(self) -> RunningTotal<Self> {
+        RunningTotal {
+            inner: self,
+            total: 0,
+        }
+    }
+}
+
+// Blanket impl - any matching iterator gets the method
+impl<I: Iterator<Item = i32>> RunningTotalExt for I {}
+
+fn demonstrate_extension_trait() {
+    let deposits = vec![10, 20, -5, 40];
+
+    let balances: Vec<i32> = deposits.iter().copied().running_total().collect();
+    println!("   Deposits: {:?}", deposits);
+    println!("   Balances: {:?}", balances);
+
+    // Chains with the built-in adapters like any other
+    let word_lengths: Vec<i32> = words("a bb ccc")
+        .map(|w| w.len() as i32)
+        .running_total()
+        .collect();
+    println!("   Running word lengths: {:?}", word_lengths);
+}
+
+// ============================================================================
+// Main Function
+// ============================================================================
+
+fn main() {
+    println!("=== Iterators and Closures ===\n");
+
+    println!("1. Custom Iterator (word splitter):");
+    demonstrate_custom_iterator();
+
+    println!("\n2. Adapter Chains:");
+    demonstrate_adapters();
+
+    println!("\n3. iter vs iter_mut vs into_iter:");
+    demonstrate_iter_kinds();
+
+    println!("\n4. Closures (Fn, FnMut, FnOnce):");
+    demonstrate_closures();
+
+    println!("\n5. Comparing Loops:");
+    demonstrate_loop_comparison();
+
+    println!("\n6. Custom Adapter (extension trait):");
+    demonstrate_extension_trait();
+
+    println!("\n=== Key Takeaways ===");
+    println!("• Implement next() to get every iterator adapter for free");
+    println!("• Adapters are lazy - nothing runs until a consumer asks");
+    println!("• iter() borrows, iter_mut() borrows mutably, into_iter() moves");
+    println!("• Fn reads (&), FnMut modifies (&mut), FnOnce consumes (move)");
+    println!("• Iterator chains compile to the same loops you'd write by hand");
+    println!("• Extension traits add your own adapters to every iterator");
+}
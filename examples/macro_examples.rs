@@ -0,0 +1,211 @@
+// Declarative Macros in Rust
+// macro_rules! matches on syntax and expands to code before type checking
+
+use learn_rust::lesson;
+use std::collections::HashMap;
+
+// ============================================================================
+// 1. macro_rules! Basics - Patterns and Arms
+// ============================================================================
+
+// Each arm is (pattern) => { expansion }, tried top to bottom like match
+macro_rules! greet {
+    () => {
+        println!("   Hello, stranger!")
+    };
+    ($name:expr) => {
+        println!("   Hello, {}!", $name)
+    };
+}
+
+fn demonstrate_basics() {
+    greet!();
+    greet!("Alice");
+
+    // println!, vec!, format! are all macros - the ! is the giveaway
+    let name = String::from("Bob");
+    greet!(name);
+}
+
+// ============================================================================
+// 2. Fragment Specifiers - What Each $var Can Match
+// ============================================================================
+
+// expr: any expression, captured as ONE unit
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+// ident: a name - lets the macro declare new items
+// ty: a type   literal: a literal value
+macro_rules! constant_fn {
+    ($name:ident, $t:ty, $value:literal) => {
+        fn $name() -> $t {
+            $value
+        }
+    };
+}
+
+constant_fn!(answer, i32, 42);
+constant_fn!(greeting, &'static str, "hi");
+
+// block: a { ... } block   pat: a pattern
+macro_rules! when_matches {
+    ($value:expr, $p:pat, $body:block) => {
+        if let $p = $value $body
+    };
+}
+
+fn demonstrate_fragments() {
+    // 2 + 3 is captured as (2 + 3), not pasted as text: 25, not 11
+    println!("   square!(2 + 3) = {}", square!(2 + 3));
+
+    println!("   answer() = {}", answer());
+    println!("   greeting() = {}", greeting());
+
+    when_matches!(Some(7), Some(n), {
+        println!("   Pattern matched: {}", n);
+    });
+
+    // Others: stmt, item, path, tt (single token tree), vis, lifetime, meta
+}
+
+// ============================================================================
+// 3. Repetition - $( ... ),* and $( ... ),+
+// ============================================================================
+
+// * means zero or more, + means one or more; the separator goes before it
+macro_rules! my_vec {
+    ( $( $x:expr ),* $(,)? ) => {{
+        // `mut` goes unused when the list is empty - that's fine
+        #[allow(unused_mut)]
+        let mut v = Vec::new();
+        $( v.push($x); )* // expanded once per matched $x
+        v
+    }};
+}
+
+// Repetitions can capture several fragments side by side
+macro_rules! map {
+    ( $( $key:expr => $value:expr ),* $(,)? ) => {{
+        let mut m = HashMap::new();
+        $( m.insert($key, $value); )*
+        m
+    }};
+}
+
+// Recursion: peel one argument off per expansion. $x is pasted in as code,
+// so it's bound once - written twice, max!(next(), 1) would call next() twice
+macro_rules! max {
+    ($x:expr) => { $x };
+    ($x:expr, $( $rest:expr ),+) => {{
+        let x = $x;
+        let rest = max!($( $rest ),+);
+        if x > rest { x } else { rest }
+    }};
+}
+
+// clippy would rather we used vec! - which is exactly what we're rebuilding
+#[allow(clippy::vec_init_then_push)]
+fn demonstrate_repetition() {
+    let v: Vec<i32> = my_vec![1, 2, 3];
+    println!("   my_vec![1, 2, 3] = {:?}", v);
+
+    let empty: Vec<i32> = my_vec![];
+    println!("   my_vec![] = {:?}", empty);
+
+    let users = map! { 1 => "Alice", 2 => "Bob" };
+    println!("   map! lookup of 2: {:?}", users.get(&2));
+
+    println!("   max!(3, 9, 4) = {}", max!(3, 9, 4));
+}
+
+// ============================================================================
+// 4. Hygiene - Macro Variables Don't Leak
+// ============================================================================
+
+// The `total` inside the macro is a different variable from any
+// `total` at the call site, even though they're spelled the same
+macro_rules! sum_twice {
+    ($x:expr) => {{
+        let total = $x;
+        total + total
+    }};
+}
+
+// To share a name with the caller, the caller has to pass it in
+macro_rules! set_to_zero {
+    ($var:ident) => {
+        $var = 0
+    };
+}
+
+fn demonstrate_hygiene() {
+    let total = 10;
+    let doubled = sum_twice!(total + 1); // $x refers to the caller's total
+    println!("   sum_twice!(total + 1) = {}", doubled);
+    println!("   caller's total untouched: {}", total);
+
+    let mut count = 5;
+    println!("   count before: {}", count);
+    set_to_zero!(count);
+    println!("   set_to_zero!(count) -> {}", count);
+
+    // Hygiene covers local variables, not items: functions and types
+    // in a macro are looked up where the macro is USED. That's why
+    // exported macros write $crate::path::to::Item
+}
+
+// ============================================================================
+// 5. Capstone - The lesson! Macro
+// ============================================================================
+
+// Every lesson used to end with the same hand-written main():
+//     println!("\n2. Borrowing Vectors:");
+//     demonstrate_vector_borrow();
+//     ...
+//     println!("• Use & to borrow without taking ownership");
+//
+// lesson! (src/lesson.rs) turns that into data. Its pattern is:
+//     title: $title:literal,
+//     sections: [ $( $heading:literal => $demo:path ),+ $(,)? ],
+//     takeaways: [ $( $takeaway:literal ),* $(,)? ]
+// and it expands to `pub static LESSON: $crate::lesson::Lesson`.
+// All five ownership lessons - and this one - are built with it.
+
+fn demonstrate_lesson_macro() {
+    println!("   This lesson is itself a lesson! invocation:");
+    println!("   title: {:?}", LESSON.title);
+    for (i, section) in LESSON.sections.iter().enumerate() {
+        println!("   section {}: {:?}", i + 1, section.title);
+    }
+    println!("   takeaways: {}", LESSON.takeaways.len());
+}
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Declarative Macros",
+    sections: [
+        "macro_rules! Basics" => demonstrate_basics,
+        "Fragment Specifiers" => demonstrate_fragments,
+        "Repetition" => demonstrate_repetition,
+        "Hygiene" => demonstrate_hygiene,
+        "Capstone: lesson!" => demonstrate_lesson_macro,
+    ],
+    takeaways: [
+        "macro_rules! matches syntax, not types",
+        "Fragment specifiers ($x:expr, $n:ident, ...) say what to capture",
+        "$( ... ),* repeats an expansion once per match",
+        "Macro locals are hygienic - they can't clash with yours",
+        "Use $crate:: for paths in exported macros",
+    ],
+}
+
+fn main() {
+    LESSON.run();
+}
@@ -1,11 +1,13 @@
 // Option Type Examples in Rust
 // Option<T> represents a value that might or might not exist
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. Basic Option Usage - Finding an item
 // ============================================================================
 
-fn find_user(id: u32) -> Option<String> {
+pub fn find_user(id: u32) -> Option<String> {
     if id == 1 {
         Some(String::from("Alice"))
     } else if id == 2 {
@@ -19,7 +21,7 @@ fn find_user(id: u32) -> Option<String> {
 // 2. Using unwrap_or for Default Values
 // ============================================================================
 
-fn get_username(id: u32) -> String {
+pub fn get_username(id: u32) -> String {
     find_user(id).unwrap_or(String::from("Guest"))
 }
 
@@ -59,56 +61,77 @@ fn get_long_username(id: u32) -> Option<String> {
 }
 
 // ============================================================================
-// Main Function - Demonstrating All Examples
+// Demonstrations
 // ============================================================================
 
-fn main() {
-    println!("=== Option Type Examples ===\n");
-
-    // Example 1: Basic Option
-    println!("1. Basic Option - Finding users:");
+fn demonstrate_basic_option() {
     println!("   User 1: {:?}", find_user(1));
     println!("   User 2: {:?}", find_user(2));
     println!("   User 99: {:?}", find_user(99));
+}
 
-    // Example 2: unwrap_or for defaults
-    println!("\n2. Using unwrap_or for defaults:");
+fn demonstrate_unwrap_or() {
     println!("   Username for ID 1: {}", get_username(1));
     println!("   Username for ID 99: {}", get_username(99));
+}
 
-    // Example 3: Pattern matching
-    println!("\n3. Pattern matching:");
+fn demonstrate_pattern_matching() {
     print!("   ");
     greet_user(1);
     print!("   ");
     greet_user(99);
+}
 
-    // Example 4: map to transform
-    println!("\n4. Using map to transform:");
+fn demonstrate_map() {
     println!("   Length of user 1's name: {:?}", get_user_length(1));
     println!("   Length of user 99's name: {:?}", get_user_length(99));
+}
 
-    // Example 5: and_then to chain
-    println!("\n5. Using and_then to chain:");
+fn demonstrate_and_then() {
     println!("   First char of user 1: {:?}", get_first_char(1));
     println!("   First char of user 99: {:?}", get_first_char(99));
+}
 
-    // Example 6: filter
-    println!("\n6. Using filter:");
+fn demonstrate_filter() {
     println!("   Long username for ID 1: {:?}", get_long_username(1));
     println!("   Long username for ID 2: {:?}", get_long_username(2));
+}
 
-    // Bonus: is_some() and is_none()
-    println!("\n7. Checking Option state:");
+// Bonus: is_some() and is_none()
+fn demonstrate_state() {
     let result = find_user(1);
     println!("   Has value? {}", result.is_some());
     println!("   Is empty? {}", result.is_none());
+}
 
-    // Bonus: if let syntax
-    println!("\n8. Using if let:");
+// Bonus: if let syntax
+fn demonstrate_if_let() {
     if let Some(name) = find_user(1) {
         println!("   Found user: {}", name);
     } else {
         println!("   No user found");
     }
 }
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Option Type Examples",
+    sections: [
+        "Basic Option - Finding users" => demonstrate_basic_option,
+        "Using unwrap_or for defaults" => demonstrate_unwrap_or,
+        "Pattern matching" => demonstrate_pattern_matching,
+        "Using map to transform" => demonstrate_map,
+        "Using and_then to chain" => demonstrate_and_then,
+        "Using filter" => demonstrate_filter,
+        "Checking Option state" => demonstrate_state,
+        "Using if let" => demonstrate_if_let,
+    ],
+    takeaways: [],
+}
+
+fn main() {
+    LESSON.run();
+}
@@ -1,6 +1,8 @@
 // Ownership Basics in Rust
 // Each value has a single owner, and when the owner goes out of scope, the value is dropped
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. Basic Ownership - Values Move by Default
 // ============================================================================
@@ -35,6 +37,7 @@ fn demonstrate_copy() {
 // 3. Returning Ownership
 // ============================================================================
 
+#[allow(clippy::let_and_return)] // the named value is what moves out
 fn create_string() -> String {
     let s = String::from("created");
     s // ownership moves out to caller
@@ -45,6 +48,13 @@ fn take_and_return(s: String) -> String {
     s // give ownership back
 }
 
+fn demonstrate_returning_ownership() {
+    let s = create_string();
+    println!("   Got string: {}", s);
+    let s = take_and_return(s);
+    println!("   Got it back: {}", s);
+}
+
 // ============================================================================
 // 4. Clone - Explicit Deep Copy
 // ============================================================================
@@ -72,34 +82,23 @@ fn demonstrate_scope() {
 }
 
 // ============================================================================
-// Main Function
+// Lesson Definition
 // ============================================================================
 
-pub fn main() {
-    println!("=== Ownership Basics ===\n");
-
-    println!("1. Move Semantics:");
-    demonstrate_move();
-    
-    println!("\n2. Copy Types (integers, bools, etc.):");
-    demonstrate_copy();
-    
-    println!("\n3. Returning Ownership:");
-    let s = create_string();
-    println!("   Got string: {}", s);
-    let s = take_and_return(s);
-    println!("   Got it back: {}", s);
-    
-    println!("\n4. Using Clone:");
-    demonstrate_clone();
-    
-    println!("\n5. Scope and Drop:");
-    demonstrate_scope();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• Each value has exactly one owner");
-    println!("• When owner goes out of scope, value is dropped");
-    println!("• Assignment/passing moves ownership (for heap types)");
-    println!("• Simple types (i32, bool, etc.) are copied instead");
-    println!("• Use .clone() for explicit deep copies");
+lesson! {
+    title: "Ownership Basics",
+    sections: [
+        "Move Semantics" => demonstrate_move,
+        "Copy Types (integers, bools, etc.)" => demonstrate_copy,
+        "Returning Ownership" => demonstrate_returning_ownership,
+        "Using Clone" => demonstrate_clone,
+        "Scope and Drop" => demonstrate_scope,
+    ],
+    takeaways: [
+        "Each value has exactly one owner",
+        "When owner goes out of scope, value is dropped",
+        "Assignment/passing moves ownership (for heap types)",
+        "Simple types (i32, bool, etc.) are copied instead",
+        "Use .clone() for explicit deep copies",
+    ],
 }
@@ -1,6 +1,8 @@
 // Borrowing in Rust (Immutable References)
 // Borrowing lets you reference a value without taking ownership
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. Basic Borrowing with &
 // ============================================================================
@@ -10,10 +12,13 @@
 /// # Examples
 /// 
 /// ```
+/// use learn_rust::lessons::prelude::calculate_length;
+/// 
 /// let len = calculate_length(&String::from("hello"));
 /// assert_eq!(len, 5);
 /// ```
-fn calculate_length(s: &String) -> usize {
+#[allow(clippy::ptr_arg)] // &String keeps it about borrowing; slices come later
+pub fn calculate_length(s: &String) -> usize {
     s.len() // can read the value
     // s is NOT dropped here - we don't own it
 }
@@ -30,6 +35,7 @@ fn demonstrate_basic_borrow() {
 // 2. Multiple Immutable Borrows are Allowed
 // ============================================================================
 
+#[allow(dead_code)]
 fn print_twice(s: &String) {
     println!("   First: {}", s);
     println!("   Second: {}", s);
@@ -67,7 +73,8 @@ fn demonstrate_valid_references() {
 // 4. Borrowing with Different Types
 // ============================================================================
 
-fn sum_vector(v: &Vec<i32>) -> i32 {
+#[allow(clippy::ptr_arg)]
+pub fn sum_vector(v: &Vec<i32>) -> i32 {
     v.iter().sum()
 }
 
@@ -96,31 +103,23 @@ fn demonstrate_dereference() {
 }
 
 // ============================================================================
-// Main Function
+// Lesson Definition
 // ============================================================================
 
-pub fn main() {
-    println!("=== Borrowing (Immutable References) ===\n");
-
-    println!("1. Basic Borrowing:");
-    demonstrate_basic_borrow();
-    
-    println!("\n2. Multiple Immutable Borrows:");
-    demonstrate_multiple_borrows();
-    
-    println!("\n3. Reference Validity:");
-    demonstrate_valid_references();
-    
-    println!("\n4. Borrowing Vectors:");
-    demonstrate_vector_borrow();
-    
-    println!("\n5. Dereferencing:");
-    demonstrate_dereference();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• Use & to borrow without taking ownership");
-    println!("• Can have multiple immutable borrows at once");
-    println!("• References must always point to valid data");
-    println!("• Original owner can still read the value");
-    println!("• Use * to dereference (often automatic)");
+lesson! {
+    title: "Borrowing (Immutable References)",
+    sections: [
+        "Basic Borrowing" => demonstrate_basic_borrow,
+        "Multiple Immutable Borrows" => demonstrate_multiple_borrows,
+        "Reference Validity" => demonstrate_valid_references,
+        "Borrowing Vectors" => demonstrate_vector_borrow,
+        "Dereferencing" => demonstrate_dereference,
+    ],
+    takeaways: [
+        "Use & to borrow without taking ownership",
+        "Can have multiple immutable borrows at once",
+        "References must always point to valid data",
+        "Original owner can still read the value",
+        "Use * to dereference (often automatic)",
+    ],
 }
@@ -1,11 +1,13 @@
 // Mutable Borrowing in Rust
 // &mut allows you to modify borrowed values, but with strict rules
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. Basic Mutable Borrowing
 // ============================================================================
 
-fn add_world(s: &mut String) {
+pub fn add_world(s: &mut String) {
     s.push_str(", world");
 }
 
@@ -34,7 +36,7 @@ fn demonstrate_single_mutable_borrow() {
     
     // But after r1 is done, we can create a new mutable borrow
     let r2 = &mut s;
-    r2.push_str("!");
+    r2.push('!');
     println!("   r2: {}", r2);
 }
 
@@ -59,7 +61,8 @@ fn demonstrate_borrow_rules() {
 // 4. Modifying Vector Elements
 // ============================================================================
 
-fn double_values(v: &mut Vec<i32>) {
+#[allow(clippy::ptr_arg)]
+pub fn double_values(v: &mut Vec<i32>) {
     for num in v.iter_mut() {
         *num *= 2;
     }
@@ -77,6 +80,7 @@ fn demonstrate_vector_mutation() {
 // 5. Returning Mutable References
 // ============================================================================
 
+#[allow(clippy::ptr_arg)]
 fn get_first_mut(v: &mut Vec<i32>) -> &mut i32 {
     &mut v[0]
 }
@@ -92,31 +96,23 @@ fn demonstrate_return_mut_ref() {
 }
 
 // ============================================================================
-// Main Function
+// Lesson Definition
 // ============================================================================
 
-pub fn main() {
-    println!("=== Mutable Borrowing ===\n");
-
-    println!("1. Basic Mutable Borrow:");
-    demonstrate_mutable_borrow();
-    
-    println!("\n2. Only One Mutable Borrow:");
-    demonstrate_single_mutable_borrow();
-    
-    println!("\n3. Borrow Rules (can't mix &mut with &):");
-    demonstrate_borrow_rules();
-    
-    println!("\n4. Mutating Vector Elements:");
-    demonstrate_vector_mutation();
-    
-    println!("\n5. Returning Mutable References:");
-    demonstrate_return_mut_ref();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• Use &mut to borrow and modify");
-    println!("• Only ONE mutable borrow at a time");
-    println!("• Can't have &mut and & at the same time");
-    println!("• Prevents data races at compile time");
-    println!("• Original value must be declared 'mut'");
+lesson! {
+    title: "Mutable Borrowing",
+    sections: [
+        "Basic Mutable Borrow" => demonstrate_mutable_borrow,
+        "Only One Mutable Borrow" => demonstrate_single_mutable_borrow,
+        "Borrow Rules (can't mix &mut with &)" => demonstrate_borrow_rules,
+        "Mutating Vector Elements" => demonstrate_vector_mutation,
+        "Returning Mutable References" => demonstrate_return_mut_ref,
+    ],
+    takeaways: [
+        "Use &mut to borrow and modify",
+        "Only ONE mutable borrow at a time",
+        "Can't have &mut and & at the same time",
+        "Prevents data races at compile time",
+        "Original value must be declared 'mut'",
+    ],
 }
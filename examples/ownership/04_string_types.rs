@@ -1,6 +1,12 @@
 // String Types in Rust
 // Understanding String vs &str and when to use each
 
+use learn_rust::lesson;
+use learn_rust::lessons::prelude::read_file_contents;
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
 // ============================================================================
 // 1. String vs &str Basics
 // ============================================================================
@@ -102,12 +108,12 @@ fn demonstrate_slicing() {
 // ============================================================================
 
 // Return String when creating new data
-fn create_greeting(name: &str) -> String {
+pub fn create_greeting(name: &str) -> String {
     format!("Hello, {}!", name)
 }
 
 // Return &str when returning part of input
-fn get_first_word(s: &str) -> &str {
+pub fn get_first_word(s: &str) -> &str {
     let bytes = s.as_bytes();
     
     for (i, &byte) in bytes.iter().enumerate() {
@@ -130,35 +136,197 @@ fn demonstrate_return_types() {
 }
 
 // ============================================================================
-// Main Function
+// 7. OsString and OsStr - Text From the Operating System
 // ============================================================================
 
-pub fn main() {
-    println!("=== String Types ===\n");
+// Env vars, command-line args and file names come from the OS, which
+// doesn't promise UTF-8. OsString/&OsStr hold them without losing data.
+fn demonstrate_os_strings() {
+    let os: OsString = OsString::from("hello");
+    let borrowed: &OsStr = &os;
+    println!("   OsString: {:?}", os);
 
-    println!("1. String vs &str:");
-    demonstrate_string_types();
-    
-    println!("\n2. Mutability:");
-    demonstrate_mutability();
-    
-    println!("\n3. Function Parameters:");
-    demonstrate_parameters();
-    
-    println!("\n4. Conversions:");
-    demonstrate_conversions();
-    
-    println!("\n5. String Slicing:");
-    demonstrate_slicing();
-    
-    println!("\n6. Return Types:");
-    demonstrate_return_types();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• String: owned, heap-allocated, mutable");
-    println!("• &str: borrowed reference, immutable");
-    println!("• Prefer &str for function parameters");
-    println!("• Return String when creating new data");
-    println!("• Return &str when returning part of input");
-    println!("• Use & to convert String to &str");
+    // OsStr -> &str can fail, so it returns an Option
+    println!("   to_str(): {:?}", borrowed.to_str());
+
+    // A name that isn't valid UTF-8 (Latin-1 "café")
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        let latin1: &OsStr = OsStr::from_bytes(b"caf\xe9.txt");
+        println!("   Non-UTF-8 to_str(): {:?}", latin1.to_str());
+        println!("   to_string_lossy(): {}", latin1.to_string_lossy());
+    }
+
+    // Args work the same way: std::env::args() panics on non-UTF-8,
+    // std::env::args_os() hands you OsStrings instead
+    let program: Option<OsString> = std::env::args_os().next();
+    println!("   args_os() gave a program name: {}", program.is_some());
+}
+
+// ============================================================================
+// 8. PathBuf and Path - String Types for File Paths
+// ============================================================================
+
+// PathBuf is to Path what String is to &str (both wrap OsString/OsStr)
+fn demonstrate_paths() {
+    let mut path = PathBuf::from("lessons");
+    path.push("ownership");
+    path.push("04_string_types.rs");
+    println!("   PathBuf: {}", path.display());
+
+    let borrowed: &Path = &path;
+    println!("   file_name(): {:?}", borrowed.file_name());
+    println!("   extension(): {:?}", borrowed.extension());
+    println!("   parent(): {:?}", borrowed.parent());
+
+    // Path -> &str can fail for the same reason OsStr can
+    println!("   to_str(): {:?}", borrowed.to_str());
+
+    // display() is for printing only - lossy on non-UTF-8 names
+    let joined = Path::new("/tmp").join("notes.txt");
+    println!("   join(): {}", joined.display());
+}
+
+// ============================================================================
+// 9. Vec<u8> and &[u8] - Raw Bytes
+// ============================================================================
+
+fn demonstrate_bytes() {
+    // Byte string literals: b"..." is a &[u8; N]
+    let bytes: &[u8] = b"hello";
+    println!("   b\"hello\": {:?}", bytes);
+
+    // &str -> &[u8] always works, and len() counts those bytes
+    let text = "héllo";
+    println!("   \"héllo\".as_bytes(): {:?}", text.as_bytes()); // é is 2 bytes
+
+    // &[u8] -> &str can fail: not every byte sequence is UTF-8
+    let owned: Vec<u8> = vec![0x68, 0x69];
+    println!("   from_utf8([0x68, 0x69]): {:?}", String::from_utf8(owned));
+    let invalid: Vec<u8> = vec![0x68, 0xff];
+    println!(
+        "   from_utf8([0x68, 0xff]) is_ok: {}",
+        std::str::from_utf8(&invalid).is_ok()
+    );
+    println!("   from_utf8_lossy: {}", String::from_utf8_lossy(&invalid));
+}
+
+// ============================================================================
+// 10. Cow<str> - Borrow When You Can, Own When You Must
+// ============================================================================
+
+// Only allocates when there's actually something to change
+fn normalize_spaces(s: &str) -> Cow<'_, str> {
+    if s.contains('\t') {
+        Cow::Owned(s.replace('\t', " "))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn demonstrate_cow() {
+    for input in ["no tabs here", "tab\there"] {
+        let result = normalize_spaces(input);
+        let kind = match result {
+            Cow::Borrowed(_) => "borrowed",
+            Cow::Owned(_) => "owned",
+        };
+        println!("   {:?} -> {:?} ({})", input, result, kind);
+    }
+
+    // to_string_lossy() returns Cow for the same reason
+    let lossy: Cow<str> = String::from_utf8_lossy(b"valid");
+    println!(
+        "   from_utf8_lossy(b\"valid\") borrowed: {}",
+        matches!(lossy, Cow::Borrowed(_))
+    );
+
+    // into_owned() turns either kind into a String
+    let owned: String = normalize_spaces("a\tb").into_owned();
+    println!("   into_owned(): {}", owned);
+}
+
+// ============================================================================
+// 11. Box<str> - Owned Text That Never Grows
+// ============================================================================
+
+fn demonstrate_boxed_str() {
+    let s = String::from("fixed label");
+    println!("   String capacity: {}", s.capacity());
+
+    // Box<str> drops the capacity field: just pointer + length
+    let boxed: Box<str> = s.into_boxed_str();
+    println!("   Box<str>: {} ({} bytes)", boxed, boxed.len());
+    println!(
+        "   Size on stack: String = {}, Box<str> = {}",
+        std::mem::size_of::<String>(),
+        std::mem::size_of::<Box<str>>()
+    );
+
+    // No push_str - convert back to String to grow it
+    let mut grown: String = boxed.into_string();
+    grown.push('!');
+    println!("   Back to String: {}", grown);
+}
+
+// ============================================================================
+// 12. Path Parameters - impl AsRef<Path>
+// ============================================================================
+
+// The Result lesson's read_file_contents used to take path: &str. Now it
+// takes path: impl AsRef<Path>, which accepts &str, String, &Path, PathBuf,
+// OsString...
+fn demonstrate_as_ref_path() {
+    let as_str: &str = "nonexistent.txt";
+    let as_string: String = String::from("nonexistent.txt");
+    let as_pathbuf: PathBuf = PathBuf::from("nonexistent.txt");
+
+    // One function, every string-ish type - and non-UTF-8 paths too
+    println!("   &str: {}", read_file_contents(as_str).is_ok());
+    println!("   String: {}", read_file_contents(&as_string).is_ok());
+    println!("   PathBuf: {}", read_file_contents(&as_pathbuf).is_ok());
+
+    // Converting the other way: Path -> String can fail
+    match as_pathbuf.into_os_string().into_string() {
+        Ok(s) => println!("   PathBuf -> String: {}", s),
+        Err(os) => println!("   Not UTF-8: {:?}", os),
+    }
+}
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "String Types",
+    sections: [
+        "String vs &str" => demonstrate_string_types,
+        "Mutability" => demonstrate_mutability,
+        "Function Parameters" => demonstrate_parameters,
+        "Conversions" => demonstrate_conversions,
+        "String Slicing" => demonstrate_slicing,
+        "Return Types" => demonstrate_return_types,
+        "OsString and OsStr" => demonstrate_os_strings,
+        "PathBuf and Path" => demonstrate_paths,
+        "Vec<u8> and &[u8]" => demonstrate_bytes,
+        "Cow<str>" => demonstrate_cow,
+        "Box<str>" => demonstrate_boxed_str,
+        "impl AsRef<Path> Parameters" => demonstrate_as_ref_path,
+    ],
+    takeaways: [
+        "String: owned, heap-allocated, mutable",
+        "&str: borrowed reference, immutable",
+        "Prefer &str for function parameters",
+        "Return String when creating new data",
+        "Return &str when returning part of input",
+        "Use & to convert String to &str",
+        "Text you build or edit   -> String / &str",
+        "OS text (args, env)      -> OsString / &OsStr",
+        "File system paths        -> PathBuf / &Path (take impl AsRef<Path>)",
+        "Bytes, maybe not UTF-8   -> Vec<u8> / &[u8]",
+        "Usually borrowed         -> Cow<str>",
+        "Fixed text, never grows  -> Box<str>",
+        "OS strings and bytes -> &str can fail: to_str() returns Option",
+    ],
 }
@@ -1,6 +1,8 @@
 // Lifetimes in Rust
 // Ensuring references stay valid
 
+use learn_rust::lesson;
+
 // ============================================================================
 // 1. The Problem Lifetimes Solve
 // ============================================================================
@@ -11,7 +13,7 @@
 // }
 
 // Solution: lifetime annotations tell Rust the relationship
-fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
     if x.len() > y.len() { x } else { y }
 }
 
@@ -23,13 +25,22 @@ fn demonstrate_basic_lifetime() {
     println!("   Longest: {}", result);
 }
 
+// This won't compile either - result would outlive string2:
+// let result;
+// {
+//     let string2 = String::from("xyz");
+//     result = longest(&string1, &string2);
+// }
+// println!("   Longest: {}", result); // ERROR: string2 doesn't live long enough
+
 // ============================================================================
 // 2. Lifetime Annotations Explained
 // ============================================================================
 
 // 'a is a lifetime parameter
 // It says: "the returned reference lives as long as the shortest input"
-fn first_word<'a>(s: &'a str) -> &'a str {
+#[allow(clippy::needless_lifetimes)] // the annotation is the lesson
+pub fn first_word<'a>(s: &'a str) -> &'a str {
     let bytes = s.as_bytes();
     
     for (i, &byte) in bytes.iter().enumerate() {
@@ -53,7 +64,8 @@ fn demonstrate_lifetime_annotation() {
 // ============================================================================
 
 // Different lifetimes for different parameters
-fn announce_and_return<'a, 'b>(announcement: &'a str, value: &'b str) -> &'a str {
+#[allow(clippy::needless_lifetimes)]
+fn announce_and_return<'a, 'b>(announcement: &'a str, _value: &'b str) -> &'a str {
     println!("   Announcement: {}", announcement);
     announcement // only returns 'a, not 'b
 }
@@ -76,6 +88,7 @@ fn first_char(s: &str) -> &str {
 }
 
 // These are equivalent:
+#[allow(clippy::needless_lifetimes)] // what elision fills in
 fn explicit_lifetime<'a>(s: &'a str) -> &'a str {
     &s[0..1]
 }
@@ -133,35 +146,25 @@ fn demonstrate_static() {
 }
 
 // ============================================================================
-// Main Function
+// Lesson Definition
 // ============================================================================
 
-pub fn main() {
-    println!("=== Lifetimes ===\n");
-
-    println!("1. Basic Lifetime:");
-    demonstrate_basic_lifetime();
-    
-    println!("\n2. Lifetime Annotations:");
-    demonstrate_lifetime_annotation();
-    
-    println!("\n3. Multiple Lifetimes:");
-    demonstrate_multiple_lifetimes();
-    
-    println!("\n4. Lifetime Elision:");
-    demonstrate_elision();
-    
-    println!("\n5. Structs with Lifetimes:");
-    demonstrate_struct_lifetime();
-    
-    println!("\n6. Static Lifetime:");
-    demonstrate_static();
-    
-    println!("\n=== Key Takeaways ===");
-    println!("• Lifetimes ensure references stay valid");
-    println!("• 'a is a lifetime parameter (like a generic)");
-    println!("• Returned reference can't outlive inputs");
-    println!("• Rust often infers lifetimes (elision)");
-    println!("• Structs with references need lifetimes");
-    println!("• 'static lives for entire program");
+lesson! {
+    title: "Lifetimes",
+    sections: [
+        "Basic Lifetime" => demonstrate_basic_lifetime,
+        "Lifetime Annotations" => demonstrate_lifetime_annotation,
+        "Multiple Lifetimes" => demonstrate_multiple_lifetimes,
+        "Lifetime Elision" => demonstrate_elision,
+        "Structs with Lifetimes" => demonstrate_struct_lifetime,
+        "Static Lifetime" => demonstrate_static,
+    ],
+    takeaways: [
+        "Lifetimes ensure references stay valid",
+        "'a is a lifetime parameter (like a generic)",
+        "Returned reference can't outlive inputs",
+        "Rust often infers lifetimes (elision)",
+        "Structs with references need lifetimes",
+        "'static lives for entire program",
+    ],
 }
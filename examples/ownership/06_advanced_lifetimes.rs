@@ -0,0 +1,194 @@
+// Advanced Lifetimes in Rust
+// Bounds, higher-ranked trait bounds, variance and the structs you can't write
+// Every "this won't compile" claim has a matching file in tests/compile_fail/
+
+use learn_rust::lesson;
+use std::fmt::Debug;
+
+// ============================================================================
+// 1. Lifetime Bounds - T: 'a
+// ============================================================================
+
+// T: 'a reads "every reference inside T lives at least as long as 'a".
+// A Box<dyn Debug + 'a> may be used for all of 'a, so T must last that long.
+// Without the bound: error[E0309] (advanced_lifetimes_bound.rs)
+fn boxed<'a, T: Debug + 'a>(value: T) -> Box<dyn Debug + 'a> {
+    Box::new(value)
+}
+
+// For plain references the compiler infers the bound for you:
+// &'a T already implies T: 'a
+struct Holder<'a, T> {
+    item: &'a T,
+}
+
+fn demonstrate_lifetime_bounds() {
+    let word = String::from("borrowed");
+
+    let owned = boxed(42); // i32 contains no references - outlives anything
+    let borrowing = boxed(&word); // &'w String: the box can't outlive word
+    println!("   boxed(42): {:?}", owned);
+    println!("   boxed(&word): {:?}", borrowing);
+
+    let holder = Holder { item: &word };
+    println!("   Holder item: {}", holder.item);
+}
+
+// ============================================================================
+// 2. Higher-Ranked Trait Bounds - for<'a>
+// ============================================================================
+
+// first_word from the lifetimes lesson
+#[allow(clippy::needless_lifetimes)]
+fn first_word<'a>(s: &'a str) -> &'a str {
+    s.split(' ').next().unwrap_or("")
+}
+
+// for<'a> says: f works for EVERY lifetime, including ones that only
+// exist inside this function. That's what lets us pass it a local.
+fn apply_to_local<F>(f: F) -> String
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    let local = String::from("hello world");
+    f(&local).to_string() // 'a here is just this line
+}
+
+// With <'a, F: Fn(&'a str) -> &'a str> the CALLER picks one 'a,
+// and no caller's lifetime can be as short as `local`:
+// error[E0597] (advanced_lifetimes_hrtb.rs)
+
+fn demonstrate_hrtb() {
+    println!("   apply_to_local(first_word): {}", apply_to_local(first_word));
+
+    // Closures can satisfy it too - the elided form Fn(&str) -> &str
+    // is sugar for exactly this for<'a> bound
+    let trimmed = apply_to_local(|s| s.trim_end_matches('d'));
+    println!("   apply_to_local(trim 'd'): {}", trimmed);
+}
+
+// ============================================================================
+// 3. Variance - Why &mut Is Stricter Than &
+// ============================================================================
+
+// &'a T is covariant: a longer-lived reference can stand in for a shorter one
+fn shorter<'a>(long: &'static str, _anchor: &'a str) -> &'a str {
+    long // &'static str shrinks to &'a str - always safe for reading
+}
+
+// &mut T is invariant in T: &mut &'static str can't become &mut &'a str.
+// If it could, we could write a short-lived &str into a 'static slot:
+fn assign<'a>(target: &mut &'a str, value: &'a str) {
+    *target = value;
+}
+// let mut s: &'static str = "static";
+// {
+//     let local = String::from("short");
+//     assign(&mut s, &local);
+// }
+// println!("{}", s); // would read freed memory
+// error[E0597] (advanced_lifetimes_variance.rs)
+
+fn demonstrate_variance() {
+    let anchor = String::from("anchor");
+    println!("   Covariant &: {}", shorter("static text", &anchor));
+
+    // Fine when both lifetimes really match
+    let local = String::from("short");
+    let mut slot: &str = "static";
+    assign(&mut slot, &local);
+    println!("   Invariant &mut, same lifetime: {}", slot);
+}
+
+// ============================================================================
+// 4. Self-Referential Structs - Why Excerpt Can't Own Its Novel
+// ============================================================================
+
+// Excerpt from the lifetimes lesson borrows from a novel someone else owns
+struct Excerpt<'a> {
+    text: &'a str,
+}
+
+// Putting the novel and the excerpt in one struct doesn't work:
+//     struct Book<'a> { novel: String, excerpt: Excerpt<'a> }
+// Moving the Book moves `novel`, which would leave `excerpt` pointing at
+// the old location - so the borrow checker refuses the move, as it does
+// here:
+//     let novel = String::from("Call me Ishmael. Some years ago...");
+//     let excerpt = Excerpt { text: &novel[..15] };
+//     let moved = novel; // as moving a Book would
+//     println!("   Excerpt: {}", excerpt.text);
+// error[E0505] (advanced_lifetimes_self_ref.rs)
+
+// Fix 1: keep owner and borrower separate (the owner outlives the view)
+// Fix 2: store positions instead of references
+struct Book {
+    novel: String,
+    excerpt: std::ops::Range<usize>,
+}
+
+impl Book {
+    fn new(novel: String) -> Book {
+        let end = novel.find('.').unwrap_or(novel.len());
+        Book { novel, excerpt: 0..end }
+    }
+
+    // Borrow on demand - the lifetime is tied to &self, not stored
+    fn excerpt(&self) -> Excerpt<'_> {
+        Excerpt {
+            text: &self.novel[self.excerpt.clone()],
+        }
+    }
+}
+
+fn demonstrate_self_reference() {
+    let book = Book::new(String::from("Call me Ishmael. Some years ago..."));
+    let moved = book; // fine: no reference points into it
+    println!("   Excerpt: {}", moved.excerpt().text);
+}
+
+// ============================================================================
+// 5. &'static T vs T: 'static
+// ============================================================================
+
+// T: 'static means "T holds no short-lived borrows" - NOT "lives forever".
+// Owned types like String qualify; a value of them can still be dropped.
+fn describe_static<T: Debug + 'static>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+fn demonstrate_static_bound() {
+    let owned = String::from("owned String");
+    println!("   String: {}", describe_static(owned)); // String: 'static
+
+    let literal: &'static str = "literal";
+    println!("   &'static str: {}", describe_static(literal));
+
+    // A borrow of a local is not 'static:
+    //     let local = String::from("x");
+    //     describe_static(&local);
+    // error[E0597] (advanced_lifetimes_static.rs)
+    println!("   (std::thread::spawn uses the same T: 'static bound)");
+}
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Advanced Lifetimes",
+    sections: [
+        "Lifetime Bounds (T: 'a)" => demonstrate_lifetime_bounds,
+        "Higher-Ranked Trait Bounds" => demonstrate_hrtb,
+        "Variance (& vs &mut)" => demonstrate_variance,
+        "Self-Referential Structs" => demonstrate_self_reference,
+        "&'static T vs T: 'static" => demonstrate_static_bound,
+    ],
+    takeaways: [
+        "T: 'a means any references inside T outlive 'a",
+        "for<'a> lets a closure accept borrows of your locals",
+        "& is covariant; &mut T is invariant in T",
+        "A struct can't borrow from its own fields - store indices instead",
+        "T: 'static means no short borrows, not 'lives forever'",
+    ],
+}
@@ -1,16 +0,0 @@
-// Module declarations for ownership examples
-
-#[path = "01_ownership_basics.rs"]
-pub mod basics;
-
-#[path = "02_borrowing.rs"]
-pub mod borrowing;
-
-#[path = "03_mutable_borrowing.rs"]
-pub mod mutable_borrowing;
-
-#[path = "04_string_types.rs"]
-pub mod string_types;
-
-#[path = "05_lifetimes.rs"]
-pub mod lifetimes;
@@ -1,49 +1,407 @@
 // Ownership Examples Runner
-// Run individual examples or all at once
-
-mod ownership;
+// Run individual examples or all at once, as text or as JSON events; with no
+// arguments, the lesson browser on a terminal, or else an interactive prompt
+// for running, explaining and quizzing (`repl` starts the prompt anywhere)
 
+use learn_rust::book;
+use learn_rust::events::{self, Event};
+use learn_rust::graph;
+use learn_rust::lessons::{self, Entry};
+use learn_rust::lineedit::{closest, Editor};
+use learn_rust::progress::{self, Progress};
+use learn_rust::quiz;
+use learn_rust::tui;
 use std::env;
+use std::io::{self, IsTerminal};
+use std::process::{self, Command};
 
 fn print_menu() {
+    print!("{}", menu());
+}
+
+fn menu() -> String {
+    let mut menu = String::from("\n=== Ownership Examples ===\n");
+    for (i, entry) in lessons::ownership().iter().enumerate() {
+        menu += &format!("{}. {}\n", i + 1, entry.lesson.title);
+    }
+    menu += "all. Run all examples\n";
+    menu += &format!(
+        "\nUsage: cargo run --example ownership_runner [--format json] [1-{}|all|repl]\n",
+        lessons::ownership().len()
+    );
+    menu += "With no arguments, the lesson browser opens on a terminal, the prompt otherwise.\n";
+    menu
+}
+
+// Marks (lesson id, section) pairs as viewed for `learn status`
+fn record_views(views: &[(&str, usize)]) {
+    let result = Progress::load().and_then(|mut progress| {
+        for &(lesson, section) in views {
+            progress.view(lesson, section, progress::now());
+        }
+        progress.save()
+    });
+    if let Err(e) = result {
+        eprintln!("Couldn't save progress: {}", e);
+    }
+}
+
+// In strict mode, before a lesson whose prerequisites aren't finished
+fn warn_if_unprepared(entry: &Entry) {
+    let progress = Progress::load().unwrap_or_default();
+    if let Some(warning) = graph::warning(&progress, entry) {
+        eprintln!("{}\n", warning);
+    }
+}
+
+// Every section of the lessons
+fn record_viewed(entries: &[Entry]) {
+    let views: Vec<(&str, usize)> = entries
+        .iter()
+        .flat_map(|entry| (1..=entry.lesson.sections.len()).map(move |n| (entry.id, n)))
+        .collect();
+    record_views(&views);
+}
+
+// Sections print straight to stdout, so for JSON, quizzes and the browser
+// each one runs in a child process (this program with --section) and its
+// output is read back
+fn capture_section(entry: &Entry, section: usize) -> Result<String, String> {
+    let exe = env::current_exe().map_err(|e| e.to_string())?;
+    let output = Command::new(exe)
+        .args(["--section", entry.id, &section.to_string()])
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().lines().last().unwrap_or("failed").to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn run_json(entries: &[Entry]) {
+    let mut stdout = io::stdout().lock();
+    for entry in entries {
+        if let Err(e) = events::run_lesson(entry, &mut capture_section, &mut stdout) {
+            eprintln!("Couldn't write events: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// ============================================================================
+// Interactive Prompt
+// ============================================================================
+
+const COMMANDS: [&str; 7] = ["list", "run", "explain", "quiz", "browse", "help", "quit"];
+
+fn print_help() {
+    println!("   list            the lessons, numbered");
+    println!("   run 3           run lesson 3 (or name it: run borrowing)");
+    println!("   run 3.2         run section 2 of lesson 3 (or: run 3 2)");
+    println!("   run all         run every ownership lesson");
+    println!("   explain 5       what lesson 5's comments say, and its takeaways");
+    println!("   explain 5.1     the same for one section");
+    println!("   quiz [lesson]   questions on the ownership lessons, or on one lesson");
+    println!("   browse          the full-screen lesson browser");
+    println!("   help            this message");
+    println!("   quit            exit");
+    println!("   Tab completes commands and lesson ids; Up and Down recall earlier lines.");
+}
+
+fn lesson_ids() -> Vec<&'static str> {
+    lessons::CURRICULUM.iter().map(|entry| entry.id).collect()
+}
+
+// By number or id, forgiving typos: "lifetims" is taken as lifetimes
+fn find_lesson(name: &str) -> Result<&'static Entry, String> {
+    if let Some(entry) = lessons::find(name) {
+        return Ok(entry);
+    }
+    let id =
+        closest(name, &lesson_ids()).ok_or_else(|| format!("No lesson `{}` (try `list`)", name))?;
+    println!("   (taking `{}` as {})", name, id);
+    Ok(lessons::find(id).expect("a registered id"))
+}
+
+// "3" is lesson 3; "3.2", "3 2" or "borrowing.2" is its section 2, index 1
+fn find_target(arg: &str, next: Option<&str>) -> Result<(&'static Entry, Option<usize>), String> {
+    let (lesson, section) = match (arg.split_once('.'), next) {
+        (Some((lesson, section)), _) => (lesson, Some(section)),
+        // A completed id is followed by a space, so the section may be apart
+        (None, Some(section)) => (arg, Some(section.trim_start_matches('.'))),
+        (None, None) => (arg, None),
+    };
+    let entry = find_lesson(lesson)?;
+    let Some(section) = section else {
+        return Ok((entry, None));
+    };
+    let sections = entry.lesson.sections.len();
+    section
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=sections).contains(n))
+        .map(|n| (entry, Some(n - 1)))
+        .ok_or_else(|| format!("{} has sections 1-{}", entry.id, sections))
+}
+
+fn list() {
+    let progress = Progress::load().unwrap_or_default();
+    for (topic, entries) in lessons::topics() {
+        println!("{}", topic);
+        for entry in entries {
+            let number = lessons::CURRICULUM
+                .iter()
+                .position(|e| e.id == entry.id)
+                .map_or(0, |i| i + 1);
+            println!(
+                "   {}. {} ({}, {}/{} sections seen)",
+                number,
+                entry.lesson.title,
+                entry.id,
+                progress.sections_viewed(entry),
+                entry.lesson.sections.len()
+            );
+        }
+    }
+}
+
+fn run(entry: &'static Entry, section: Option<usize>) {
+    warn_if_unprepared(entry);
+    match section {
+        Some(i) => {
+            entry.lesson.run_section(i);
+            record_views(&[(entry.id, i + 1)]);
+        }
+        None => {
+            entry.lesson.run();
+            record_viewed(std::slice::from_ref(entry));
+        }
+    }
+}
+
+fn explain(entry: &Entry, section: Option<usize>) {
+    let sections = match section {
+        Some(i) => i..i + 1,
+        None => 0..entry.lesson.sections.len(),
+    };
+    println!("=== {} ===", entry.lesson.title);
+    for i in sections {
+        println!("\n{}", book::section_heading(entry, i));
+        let comments = book::section_comments(entry, i);
+        if comments.is_empty() {
+            println!("   (no comments - the code speaks for itself)");
+        }
+        for comment in comments {
+            println!("   • {}", comment);
+        }
+    }
+    if !entry.lesson.takeaways.is_empty() {
+        println!("\n=== Key Takeaways ===");
+        for takeaway in entry.lesson.takeaways {
+            println!("• {}", takeaway);
+        }
+    }
+}
+
+fn ask_quiz(editor: &mut Editor, lesson: Option<&str>) -> Result<(), String> {
+    let mut questions = quiz::bundled()?;
+    let name = match lesson {
+        Some(name) => {
+            let entry = find_lesson(name)?;
+            questions.retain(|q| q.lesson == entry.id);
+            entry.id
+        }
+        None => {
+            questions.retain(|q| lessons::ownership().iter().any(|e| e.id == q.lesson));
+            "the ownership lessons"
+        }
+    };
+    if questions.is_empty() {
+        return Err(format!("No quiz questions for {} yet", name));
+    }
+
+    let mut output = |lesson: &str, section: usize| {
+        let entry = lessons::find(lesson).ok_or_else(|| format!("no lesson `{}`", lesson))?;
+        capture_section(entry, section)
+    };
+    quiz::ask(&questions, &mut output, &mut |prompt| {
+        editor.read_line(prompt)
+    })
+}
+
+fn browse() -> Result<(), String> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(String::from("The browser needs a terminal"));
+    }
+    tui::run(&mut capture_section)
+}
+
+// Commands first, then lesson ids for the commands that take one
+fn complete(before: &str) -> Vec<String> {
+    let words: Vec<&str> = before.split_whitespace().collect();
+    let position = if before.is_empty() || before.ends_with(' ') {
+        words.len()
+    } else {
+        words.len() - 1
+    };
+    match (position, words.first().copied()) {
+        (0, _) => COMMANDS.map(String::from).to_vec(),
+        (1, Some("run")) => ["all"]
+            .into_iter()
+            .chain(lesson_ids())
+            .map(String::from)
+            .collect(),
+        (1, Some("explain" | "quiz")) => lesson_ids().into_iter().map(String::from).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn repl() {
     println!("\n=== Ownership Examples ===");
-    println!("1. Ownership Basics");
-    println!("2. Borrowing");
-    println!("3. Mutable Borrowing");
-    println!("4. String Types");
-    println!("5. Lifetimes");
-    println!("all. Run all examples");
-    println!("\nUsage: cargo run --example ownership [1-5|all]");
+    println!("Type `list` to see the lessons, `help` for every command.");
+
+    let history = progress::data_dir().join("runner_history");
+    let mut editor = Editor::new(Some(history), Box::new(complete));
+
+    while let Some(line) = editor.read_line("ownership> ") {
+        if let Err(e) = editor.remember(&line) {
+            eprintln!("Couldn't save history: {}", e);
+        }
+        let mut words = line.split_whitespace();
+        let Some(word) = words.next() else {
+            continue;
+        };
+        let Some(command) = closest(word, &COMMANDS) else {
+            println!("   Unknown command `{}` (try `help`)", word);
+            continue;
+        };
+        if command != word {
+            println!("   (taking `{}` as {})", word, command);
+        }
+
+        let arg = words.next();
+        let next = words.next();
+        let result = match (command, arg) {
+            ("quit", _) => break,
+            ("help", _) => {
+                print_help();
+                Ok(())
+            }
+            ("list", _) => {
+                list();
+                Ok(())
+            }
+            ("run", Some("all")) => {
+                for entry in lessons::ownership() {
+                    run(entry, None);
+                    println!();
+                }
+                Ok(())
+            }
+            ("run", Some(arg)) => {
+                find_target(arg, next).map(|(entry, section)| run(entry, section))
+            }
+            ("explain", Some(arg)) => {
+                find_target(arg, next).map(|(entry, section)| explain(entry, section))
+            }
+            ("run" | "explain", None) => Err(format!("Usage: {} <lesson>[.<section>]", command)),
+            ("quiz", arg) => ask_quiz(&mut editor, arg),
+            _ => browse(),
+        };
+        if let Err(e) = result {
+            println!("   {}", e);
+        }
+    }
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    
-    let choice = if args.len() > 1 {
-        args[1].as_str()
-    } else {
-        print_menu();
-        return;
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    // The child side of capture_section: one section, no heading
+    if let [flag, lesson, section] = args.as_slice() {
+        if flag == "--section" {
+            let entry = lessons::find(lesson).expect("known lesson");
+            let index: usize = section.parse().expect("section number");
+            (entry.lesson.sections[index - 1].run)();
+            return;
+        }
+    }
+
+    let json = match args.iter().position(|a| a == "--format") {
+        Some(i) if args.get(i + 1).map(String::as_str) == Some("json") => {
+            args.drain(i..i + 2);
+            true
+        }
+        Some(_) => {
+            eprintln!("Only --format json is supported");
+            print_menu();
+            process::exit(2);
+        }
+        None => false,
+    };
+
+    let choice = match args.first() {
+        Some(choice) if choice == "repl" => {
+            repl();
+            return;
+        }
+        Some(choice) => choice.as_str(),
+        None if io::stdin().is_terminal() && io::stdout().is_terminal() => {
+            if let Err(e) = tui::run(&mut capture_section) {
+                eprintln!("Couldn't open the lesson browser: {}", e);
+                repl();
+            }
+            return;
+        }
+        None => {
+            repl();
+            return;
+        }
     };
-    
-    match choice {
-        "1" => ownership::basics::main(),
-        "2" => ownership::borrowing::main(),
-        "3" => ownership::mutable_borrowing::main(),
-        "4" => ownership::string_types::main(),
-        "5" => ownership::lifetimes::main(),
-        "all" => {
-            ownership::basics::main();
-            println!("\n{}\n", "=".repeat(50));
-            ownership::borrowing::main();
-            println!("\n{}\n", "=".repeat(50));
-            ownership::mutable_borrowing::main();
-            println!("\n{}\n", "=".repeat(50));
-            ownership::string_types::main();
-            println!("\n{}\n", "=".repeat(50));
-            ownership::lifetimes::main();
-        }
-        _ => {
+
+    if choice == "all" {
+        if json {
+            run_json(lessons::ownership());
+        } else {
+            for (i, entry) in lessons::ownership().iter().enumerate() {
+                if i > 0 {
+                    println!("\n{}\n", "=".repeat(50));
+                }
+                entry.lesson.run();
+            }
+        }
+        record_viewed(lessons::ownership());
+        return;
+    }
+
+    // "1" picks the first lesson, and so on
+    let index = choice
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .filter(|&i| i < lessons::ownership().len());
+
+    match index {
+        Some(i) => {
+            warn_if_unprepared(&lessons::ownership()[i]);
+            if json {
+                run_json(&lessons::ownership()[i..=i]);
+            } else {
+                lessons::ownership()[i].lesson.run();
+            }
+            record_viewed(&lessons::ownership()[i..=i]);
+        }
+        // JSON consumers read stdout, so they get an event and the menu
+        // goes to stderr
+        None if json => {
+            let error = Event::Error {
+                message: format!("Invalid choice: {}", choice),
+            };
+            println!("{}", error.to_json());
+            eprint!("{}", menu());
+        }
+        None => {
             println!("Invalid choice: {}", choice);
             print_menu();
         }
@@ -0,0 +1,279 @@
+// Enums and Pattern Matching in Rust
+// Enums can carry data, and match pulls it back out - checked for exhaustiveness at compile time
+
+use std::num::ParseIntError;
+
+// ============================================================================
+// 1. Data-Carrying Enums and Exhaustiveness
+// ============================================================================
+
+// result_examples' MathError, now with data attached to each variant
+#[derive(Debug)]
+enum MathError {
+    DivisionByZero,
+    NegativeSquareRoot(f64),                          // tuple variant
+    Overflow { operation: &'static str, value: i64 }, // struct variant
+}
+
+fn safe_sqrt(x: f64) -> Result<f64, MathError> {
+    if x < 0.0 {
+        Err(MathError::NegativeSquareRoot(x))
+    } else {
+        Ok(x.sqrt())
+    }
+}
+
+fn safe_divide(a: f64, b: f64) -> Result<f64, MathError> {
+    if b == 0.0 {
+        Err(MathError::DivisionByZero)
+    } else {
+        Ok(a / b)
+    }
+}
+
+fn checked_double(value: i64) -> Result<i64, MathError> {
+    value.checked_mul(2).ok_or(MathError::Overflow {
+        operation: "double",
+        value,
+    })
+}
+
+// No `_` arm: adding a new variant to MathError makes this a compile
+// error until it is handled here - that's exhaustiveness checking
+fn describe(error: &MathError) -> String {
+    match error {
+        MathError::DivisionByZero => String::from("cannot divide by zero"),
+        MathError::NegativeSquareRoot(x) => format!("cannot take sqrt of {}", x),
+        MathError::Overflow { operation, value } => {
+            format!("{} overflowed on {}", operation, value)
+        }
+    }
+}
+
+fn demonstrate_enums() {
+    let results = [safe_sqrt(-4.0), safe_divide(1.0, 0.0), safe_sqrt(9.0)];
+
+    for result in &results {
+        match result {
+            Ok(value) => println!("   Ok: {}", value),
+            Err(e) => println!("   Err: {}", describe(e)),
+        }
+    }
+
+    if let Err(e) = checked_double(i64::MAX) {
+        println!("   Err: {}", describe(&e));
+    }
+}
+
+// ============================================================================
+// 2. Match Guards - Extra Conditions on an Arm
+// ============================================================================
+
+fn classify_sqrt(x: f64) -> &'static str {
+    match safe_sqrt(x) {
+        Ok(root) if root == root.trunc() => "perfect square",
+        Ok(_) => "irrational root",
+        // The guard can inspect data the pattern bound
+        Err(MathError::NegativeSquareRoot(n)) if n > -1.0 => "slightly negative",
+        Err(_) => "negative",
+    }
+}
+
+fn demonstrate_guards() {
+    for x in [16.0, 2.0, -0.5, -9.0] {
+        println!("   {}: {}", x, classify_sqrt(x));
+    }
+}
+
+// ============================================================================
+// 3. @ Bindings - Test a Pattern AND Keep the Value
+// ============================================================================
+
+fn describe_parsed(input: &str) -> String {
+    match input.parse::<i32>() {
+        // `n @ 1..=9` checks the range and still gives us n
+        Ok(n @ 1..=9) => format!("single digit {}", n),
+        Ok(n @ (10 | 100 | 1000)) => format!("power of ten {}", n),
+        Ok(n) => format!("other number {}", n),
+        Err(e) => format!("not a number ({})", e),
+    }
+}
+
+fn demonstrate_at_bindings() {
+    for input in ["7", "100", "42", "abc"] {
+        println!("   {:?} -> {}", input, describe_parsed(input));
+    }
+
+    // @ works on whole enum variants too
+    match safe_sqrt(-2.0) {
+        Err(e @ MathError::NegativeSquareRoot(_)) => println!("   Kept whole error: {:?}", e),
+        other => println!("   Something else: {:?}", other),
+    }
+}
+
+// ============================================================================
+// 4. Slice Patterns
+// ============================================================================
+
+fn sum_all(inputs: &[&str]) -> Result<i32, ParseIntError> {
+    match inputs {
+        [] => Ok(0),
+        [only] => only.parse(),
+        // `rest @ ..` binds the remaining sub-slice
+        [first, rest @ ..] => Ok(first.parse::<i32>()? + sum_all(rest)?),
+    }
+}
+
+fn describe_list(inputs: &[&str]) -> String {
+    match inputs {
+        [] => String::from("empty"),
+        [one] => format!("just {}", one),
+        [first, .., last] => format!("{} ... {}", first, last),
+    }
+}
+
+fn demonstrate_slice_patterns() {
+    let lists: [&[&str]; 4] = [&[], &["5"], &["1", "2", "3"], &["1", "x"]];
+
+    for list in lists {
+        println!("   {} -> {:?}", describe_list(list), sum_all(list));
+    }
+}
+
+// ============================================================================
+// 5. Nested Destructuring - Option<Result<...>> and Result<Option<...>>
+// ============================================================================
+
+// Same shape as parse_optional_number from result_examples
+fn parse_optional_number(s: Option<&str>) -> Result<Option<i32>, ParseIntError> {
+    s.map(|s| s.parse::<i32>()).transpose()
+}
+
+fn demonstrate_nested() {
+    let inputs = [Some("123"), Some("abc"), None];
+
+    for input in inputs {
+        // Before transpose: Option<Result<i32, _>>
+        let before = input.map(|s| s.parse::<i32>());
+        let shape = match before {
+            Some(Ok(n)) => format!("Some(Ok({}))", n),
+            Some(Err(_)) => String::from("Some(Err(..))"),
+            None => String::from("None"),
+        };
+
+        // After transpose: Result<Option<i32>, _>
+        let message = match parse_optional_number(input) {
+            Ok(Some(n)) if n > 100 => format!("big number {}", n),
+            Ok(Some(n)) => format!("number {}", n),
+            Ok(None) => String::from("no value to parse"),
+            Err(e) => format!("parse error: {}", e),
+        };
+
+        println!("   {:<14} -> {}", shape, message);
+    }
+
+    // Struct variants destructure field by field
+    if let Err(MathError::Overflow { value, .. }) = checked_double(i64::MAX) {
+        println!("   Overflow value: {}", value);
+    }
+}
+
+// ============================================================================
+// 6. Binding Modes - ref, ref mut and Default Binding
+// ============================================================================
+
+// Full matches, to show where `ref` and `ref mut` go
+#[allow(clippy::single_match)]
+fn demonstrate_binding_modes() {
+    let error = MathError::NegativeSquareRoot(-4.0);
+
+    // Matching on a reference: bindings become references automatically
+    // (default binding mode), so nothing is moved out of `error`
+    match &error {
+        MathError::NegativeSquareRoot(x) => println!("   x is &f64: {}", x),
+        _ => {}
+    }
+
+    // The old way, matching on the value itself: `ref` borrows instead of moving
+    let message: Option<String> = Some(String::from("overflow"));
+    match message {
+        Some(ref text) => println!("   Borrowed with ref: {}", text),
+        None => {}
+    }
+    println!("   message still valid: {:?}", message);
+
+    // `ref mut` - borrow mutably inside a by-value match
+    let mut parsed: Result<i32, String> = Ok(20);
+    match parsed {
+        Ok(ref mut n) => *n += 1,
+        Err(_) => {}
+    }
+    println!("   Modified with ref mut: {:?}", parsed);
+
+    // The same thing written with default binding modes
+    if let Ok(n) = &mut parsed {
+        *n *= 2; // n is &mut i32
+    }
+    println!("   Modified through &mut: {:?}", parsed);
+}
+
+// ============================================================================
+// 7. let-else - Bind or Bail Out
+// ============================================================================
+
+fn parse_required(s: Option<&str>) -> Result<i32, String> {
+    // The else block must leave the function (return, break, panic...)
+    let Some(text) = s else {
+        return Err(String::from("no input"));
+    };
+
+    let Ok(n) = text.parse::<i32>() else {
+        return Err(format!("{:?} is not a number", text));
+    };
+
+    Ok(n)
+}
+
+fn demonstrate_let_else() {
+    println!("   {:?}", parse_required(Some("42")));
+    println!("   {:?}", parse_required(Some("abc")));
+    println!("   {:?}", parse_required(None));
+}
+
+// ============================================================================
+// Main Function - Demonstrating All Examples
+// ============================================================================
+
+fn main() {
+    println!("=== Enums and Pattern Matching ===\n");
+
+    println!("1. Data-carrying enums:");
+    demonstrate_enums();
+
+    println!("\n2. Match guards:");
+    demonstrate_guards();
+
+    println!("\n3. @ bindings:");
+    demonstrate_at_bindings();
+
+    println!("\n4. Slice patterns:");
+    demonstrate_slice_patterns();
+
+    println!("\n5. Nested destructuring:");
+    demonstrate_nested();
+
+    println!("\n6. Binding modes (ref / ref mut):");
+    demonstrate_binding_modes();
+
+    println!("\n7. let-else:");
+    demonstrate_let_else();
+
+    println!("\n=== Key Takeaways ===");
+    println!("• Enum variants can carry tuple or struct data");
+    println!("• match must cover every case - the compiler checks");
+    println!("• Guards (if ...) add conditions to an arm");
+    println!("• @ binds a value while testing it against a pattern");
+    println!("• Slice patterns match on length and position");
+    println!("• Matching on &value gives references automatically");
+    println!("• let-else binds or leaves the function");
+}
@@ -13,16 +13,17 @@
 
 // This makes error handling explicit and prevents crashes from unhandled errors.
 
+use learn_rust::lesson;
 use std::fs::File;
-use std::io::{self, Read, Write};
+use std::io::{self, Read};
 use std::num::ParseIntError;
-use std::result;
+use std::path::Path;
 
 // ============================================================================
 // 1. Basic Result Usage
 // ============================================================================
 
-fn divide(a: f64, b: f64) -> Result<f64, String> {
+pub fn divide(a: f64, b: f64) -> Result<f64, String> {
     if b == 0.0 {
         Err(String::from("Cannot divide by zero"))
     } else {
@@ -35,6 +36,7 @@ fn divide(a: f64, b: f64) -> Result<f64, String> {
 // ============================================================================
 
 #[derive(Debug)]
+#[allow(dead_code)]
 enum MathError {
     DivisionByZero,
     NegativeSquareRoot,
@@ -53,7 +55,9 @@ fn safe_sqrt(x: f64) -> Result<f64, MathError> {
 // 3. Using ? Operator for Error Propagation
 // ============================================================================
 
-fn read_file_contents(path: &str) -> Result<String, io::Error> {
+// AsRef<Path> accepts &str, String, &Path and PathBuf alike; the String
+// Types lesson passes it each of them
+pub fn read_file_contents(path: impl AsRef<Path>) -> Result<String, io::Error> {
     let mut file = File::open(path)?;
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
@@ -64,7 +68,8 @@ fn read_file_contents(path: &str) -> Result<String, io::Error> {
 // 4. Chaining Results with and_then
 // ============================================================================
 
-fn parse_and_double(s: &str) -> Result<i32, ParseIntError> {
+#[allow(clippy::bind_instead_of_map)] // shows and_then; map is the next section
+pub fn parse_and_double(s: &str) -> Result<i32, ParseIntError> {
     s.parse::<i32>().and_then(|n| Ok(n * 2))
 }
 
@@ -135,14 +140,10 @@ fn parse_optional_number(s: Option<&str>) -> Result<Option<i32>, ParseIntError>
 }
 
 // ============================================================================
-// Main Function - Demonstrating All Examples
+// Demonstrations
 // ============================================================================
 
-fn main() {
-    println!("=== Result Type Examples ===\n");
-
-    // Example 1: Basic Result
-    println!("1. Basic Result:");
+fn demonstrate_basic_result() {
     match divide(10.0, 2.0) {
         Ok(result) => println!("   10 / 2 = {}", result),
         Err(e) => println!("   Error: {}", e),
@@ -151,9 +152,9 @@ fn main() {
         Ok(result) => println!("   10 / 0 = {}", result),
         Err(e) => println!("   Error: {}", e),
     }
+}
 
-    // Example 2: Custom Error Types
-    println!("\n2. Custom Error Types:");
+fn demonstrate_custom_errors() {
     match safe_sqrt(16.0) {
         Ok(result) => println!("   sqrt(16) = {}", result),
         Err(e) => println!("   Error: {:?}", e),
@@ -161,30 +162,30 @@ fn main() {
     
     match safe_sqrt(-20.0) {
         Ok(result) => println!(" {} ", result),
-        Err(e) => println!("you fucked up big time")
+        Err(_) => println!("you fucked up big time")
     }
 
     match safe_sqrt(-4.0) {
         Ok(result) => println!("   sqrt(-4) = {}", result),
         Err(e) => println!("   Error: {:?}", e),
     }
+}
 
-    // Example 3: ? Operator
-    println!("\n3. ? Operator (file reading):");
+fn demonstrate_question_mark() {
     match read_file_contents("nonexistent.txt") {
         Ok(contents) => println!("   File contents: {}", contents),
         Err(e) => println!("   Error reading file: {}", e),
     }
+}
 
-    // Example 4: and_then
-    println!("\n4. Chaining with and_then:");
+fn demonstrate_and_then() {
     match parse_and_double("21") {
         Ok(result) => println!("   Parsed and doubled: {}", result),
         Err(e) => println!("   Error: {}", e),
     }
+}
 
-    // Example 5: map and map_err
-    println!("\n5. Using map and map_err:");
+fn demonstrate_map_and_map_err() {
     match parse_with_custom_error("42") {
         Ok(result) => println!("   Parsed + 10: {}", result),
         Err(e) => println!("   {}", e),
@@ -193,9 +194,9 @@ fn main() {
         Ok(result) => println!("   Parsed + 10: {}", result),
         Err(e) => println!("   {}", e),
     }
+}
 
-    // Example 6: unwrap_or and unwrap_or_else
-    println!("\n6. unwrap_or and unwrap_or_else:");
+fn demonstrate_unwrap_or() {
     let username = get_config_value("username").unwrap_or(String::from("guest"));
     println!("   Username: {}", username);
     let missing = get_config_value("missing").unwrap_or_else(|e| {
@@ -203,41 +204,68 @@ fn main() {
         String::from("default")
     });
     println!("   Missing key value: {}", missing);
+}
 
-    // Example 7: Combining Multiple Results
-    println!("\n7. Combining Multiple Results:");
+fn demonstrate_combining() {
     match process_two_numbers("5", "10") {
         Ok(sum) => println!("   Sum: {}", sum),
         Err(e) => println!("   Error: {}", e),
     }
+}
 
-    // Example 8: Pattern Matching
-    println!("\n8. Pattern Matching:");
+fn demonstrate_pattern_matching() {
     let value = handle_result_with_match(Ok(42));
     println!("   Handled value: {}", value);
     let error_value = handle_result_with_match(Err(String::from("Something went wrong")));
     println!("   Handled error value: {}", error_value);
+}
 
-    // Example 9: Result <-> Option Conversion
-    println!("\n9. Result and Option Conversion:");
+fn demonstrate_conversion() {
     let opt = result_to_option(Ok(100));
     println!("   Result to Option: {:?}", opt);
     let res = option_to_result(Some(200));
     println!("   Option to Result: {:?}", res);
     let res_none = option_to_result(None);
     println!("   None to Result: {:?}", res_none);
+}
 
-    // Example 10: transpose
-    println!("\n10. Using transpose:");
+fn demonstrate_transpose() {
     match parse_optional_number(Some("123")) {
         Ok(Some(n)) => println!("   Parsed optional: {}", n),
         Ok(None) => println!("   No value to parse"),
         Err(e) => println!("   Parse error: {}", e),
     }
+}
 
-    // Bonus: is_ok() and is_err()
-    println!("\n11. Checking Result state:");
+// Bonus: is_ok() and is_err()
+fn demonstrate_state() {
     let success: Result<i32, String> = Ok(42);
     println!("   Is Ok? {}", success.is_ok());
     println!("   Is Err? {}", success.is_err());
 }
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Result Type Examples",
+    sections: [
+        "Basic Result" => demonstrate_basic_result,
+        "Custom Error Types" => demonstrate_custom_errors,
+        "? Operator (file reading)" => demonstrate_question_mark,
+        "Chaining with and_then" => demonstrate_and_then,
+        "Using map and map_err" => demonstrate_map_and_map_err,
+        "unwrap_or and unwrap_or_else" => demonstrate_unwrap_or,
+        "Combining Multiple Results" => demonstrate_combining,
+        "Pattern Matching" => demonstrate_pattern_matching,
+        "Result and Option Conversion" => demonstrate_conversion,
+        "Using transpose" => demonstrate_transpose,
+        "Checking Result state" => demonstrate_state,
+    ],
+    takeaways: [],
+}
+
+fn main() {
+    LESSON.run();
+}
@@ -0,0 +1,244 @@
+// Unsafe Rust and Raw Pointers
+// Picking up where the dereference operator left off: pointers the borrow checker can't see
+
+use learn_rust::lesson;
+use std::ptr;
+
+// ============================================================================
+// 1. Raw Pointers - *const T and *mut T
+// ============================================================================
+
+fn demonstrate_raw_pointers() {
+    let mut x = 5;
+
+    // Creating raw pointers is safe - only dereferencing them is unsafe.
+    // Both come from the one &mut x: taking a second reference to x would
+    // invalidate the first pointer, even though the address is the same
+    let m = &mut x as *mut i32;
+    let r = m as *const i32;
+
+    println!("   r = {:p}", r); // same address demonstrate_basic_deref printed
+
+    // SAFETY: `r` is derived from `m`, so reading through it after writing
+    // through `m` is allowed; `x` is alive for this whole block and isn't
+    // used directly while the pointers are
+    unsafe {
+        println!("   *r = {}", *r);
+        *m = 10;
+        println!("   After *m = 10: *r = {}", *r);
+    }
+
+    // Unlike references, raw pointers can be null - so check first
+    let nothing: *const i32 = ptr::null();
+    println!("   null pointer is_null(): {}", nothing.is_null());
+}
+
+// ============================================================================
+// 2. Pointer Arithmetic Over a Vec
+// ============================================================================
+
+// Same job as demonstrate_deref_in_loops, without iter_mut
+fn double_in_place(v: &mut [i32]) {
+    let start = v.as_mut_ptr();
+
+    for i in 0..v.len() {
+        // SAFETY: i < v.len(), so start.add(i) stays inside the slice,
+        // and the &mut borrow of v means nobody else can see it
+        unsafe {
+            let p = start.add(i);
+            *p *= 2;
+        }
+    }
+}
+
+fn demonstrate_pointer_arithmetic() {
+    let mut numbers = vec![1, 2, 3, 4, 5];
+    println!("   Before: {:?}", numbers);
+
+    double_in_place(&mut numbers);
+    println!("   After doubling: {:?}", numbers);
+
+    // add() moves by whole elements, not bytes
+    let p = numbers.as_ptr();
+    // SAFETY: index 2 is in bounds of a 5-element Vec
+    let third = unsafe { *p.add(2) };
+    println!("   *p.add(2) = {}", third);
+}
+
+// ============================================================================
+// 3. ptr::read and ptr::write - Moving Without the Borrow Checker
+// ============================================================================
+
+// A hand-written std::mem::swap
+fn swap_values<T>(a: &mut T, b: &mut T) {
+    // SAFETY: a and b are valid, aligned and can't overlap (two &mut can't
+    // alias). read() leaves a bitwise copy behind, so we must write over
+    // both places before anyone could drop the duplicates
+    unsafe {
+        let tmp = ptr::read(a);
+        ptr::write(a, ptr::read(b));
+        ptr::write(b, tmp);
+    }
+}
+
+fn demonstrate_read_write() {
+    let mut first = String::from("hello");
+    let mut second = String::from("world");
+
+    swap_values(&mut first, &mut second);
+    println!("   After swap: first = {}, second = {}", first, second);
+
+    // ptr::write doesn't drop the old value - good for uninitialized
+    // memory, a leak if the old value owned something
+    let mut n = 0;
+    // SAFETY: &mut n is valid and i32 has nothing to drop
+    unsafe { ptr::write(&mut n, 42) };
+    println!("   ptr::write(&mut n, 42): n = {}", n);
+}
+
+// ============================================================================
+// 4. A Safe Abstraction - split_at_mut
+// ============================================================================
+
+// The borrow checker rejects the obvious version:
+//     (&mut v[..mid], &mut v[mid..]) // ERROR: two &mut borrows of v
+// It can't tell the halves don't overlap - but we can.
+fn split_at_mut(v: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    let len = v.len();
+    let p = v.as_mut_ptr();
+
+    // The check that makes the unsafe block below sound
+    assert!(mid <= len, "mid out of bounds");
+
+    // SAFETY: mid <= len, so both halves are inside the original slice,
+    // and [0, mid) and [mid, len) don't overlap - two &mut are fine
+    unsafe {
+        (
+            std::slice::from_raw_parts_mut(p, mid),
+            std::slice::from_raw_parts_mut(p.add(mid), len - mid),
+        )
+    }
+}
+
+fn demonstrate_safe_abstraction() {
+    let mut numbers = vec![1, 2, 3, 4, 5, 6];
+
+    let (left, right) = split_at_mut(&mut numbers, 3);
+    left[0] = 100;
+    right[0] = 400;
+    println!("   left: {:?}, right: {:?}", left, right);
+    println!("   numbers: {:?}", numbers);
+
+    // Callers never write `unsafe` - the function is safe to call
+}
+
+// ============================================================================
+// 5. Documenting Safety Invariants
+// ============================================================================
+
+/// Returns the element at `index` without a bounds check.
+///
+/// # Safety
+///
+/// `index` must be less than `v.len()`.
+unsafe fn get_unchecked(v: &[i32], index: usize) -> i32 {
+    // SAFETY: the caller promised index < v.len()
+    unsafe { *v.as_ptr().add(index) }
+}
+
+fn last_or_zero(v: &[i32]) -> i32 {
+    if v.is_empty() {
+        return 0;
+    }
+    // SAFETY: v is non-empty, so len - 1 is a valid index
+    unsafe { get_unchecked(v, v.len() - 1) }
+}
+
+fn demonstrate_safety_docs() {
+    // `unsafe fn` = the CALLER must uphold a contract (# Safety doc)
+    // `unsafe { }` = HERE is where we uphold it (// SAFETY: comment)
+    println!("   last_or_zero(&[7, 8, 9]) = {}", last_or_zero(&[7, 8, 9]));
+    println!("   last_or_zero(&[]) = {}", last_or_zero(&[]));
+}
+
+// ============================================================================
+// Lesson Definition
+// ============================================================================
+
+lesson! {
+    title: "Unsafe Rust and Raw Pointers",
+    sections: [
+        "Raw Pointers" => demonstrate_raw_pointers,
+        "Pointer Arithmetic" => demonstrate_pointer_arithmetic,
+        "ptr::read and ptr::write" => demonstrate_read_write,
+        "Safe Abstraction (split_at_mut)" => demonstrate_safe_abstraction,
+        "Documenting Safety" => demonstrate_safety_docs,
+    ],
+    takeaways: [
+        "Creating raw pointers is safe; dereferencing them is unsafe",
+        "unsafe doesn't turn off the borrow checker for references",
+        "ptr::read copies bits out - don't let both copies drop",
+        "Wrap unsafe code in a safe function that checks its inputs",
+        "unsafe fn needs a # Safety doc; unsafe {} needs a SAFETY comment",
+    ],
+}
+
+fn main() {
+    LESSON.run();
+}
+
+// These also run under Miri (see tests/miri.rs), which catches
+// out-of-bounds and aliasing mistakes in the unsafe blocks
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_pointers_section_runs() {
+        demonstrate_raw_pointers();
+    }
+
+    #[test]
+    fn double_in_place_doubles_every_element() {
+        let mut v = vec![1, 2, 3];
+        double_in_place(&mut v);
+        assert_eq!(v, [2, 4, 6]);
+    }
+
+    #[test]
+    fn swap_values_swaps_owned_data() {
+        let mut a = String::from("a");
+        let mut b = String::from("b");
+        swap_values(&mut a, &mut b);
+        assert_eq!((a.as_str(), b.as_str()), ("b", "a"));
+    }
+
+    #[test]
+    fn split_at_mut_halves_are_independent() {
+        let mut v = vec![1, 2, 3, 4];
+        let (left, right) = split_at_mut(&mut v, 1);
+        left[0] = 10;
+        right[2] = 40;
+        assert_eq!(v, [10, 2, 3, 40]);
+    }
+
+    #[test]
+    fn split_at_mut_allows_empty_halves() {
+        let mut v = vec![1, 2];
+        let (left, right) = split_at_mut(&mut v, 2);
+        assert_eq!((left.len(), right.len()), (2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "mid out of bounds")]
+    fn split_at_mut_rejects_mid_past_end() {
+        let mut v = vec![1, 2];
+        split_at_mut(&mut v, 3);
+    }
+
+    #[test]
+    fn last_or_zero_handles_empty_slice() {
+        assert_eq!(last_or_zero(&[]), 0);
+        assert_eq!(last_or_zero(&[4, 5]), 5);
+    }
+}
@@ -0,0 +1,12 @@
+// Exercise: count_long_words
+// Lesson: Borrowing (Immutable References) - Borrowing Vectors
+//
+// Count the words longer than `min_len` characters. The function only
+// borrows `words`, so the caller still owns the Vec afterwards.
+//
+// Check your answer with:  cargo run --bin learn -- check count_long_words
+
+fn count_long_words(words: &Vec<String>, min_len: usize) -> usize {
+    // TODO: count the words whose length is greater than min_len
+    todo!()
+}
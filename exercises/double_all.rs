@@ -0,0 +1,12 @@
+// Exercise: double_all
+// Lesson: Dereference Operator (*) - Dereferencing in Loops
+//
+// Double every number in the Vec in place. iter_mut() gives you &mut i32
+// references - use * to change the value they point to.
+//
+// Check your answer with:  cargo run --bin learn -- check double_all
+
+fn double_all(numbers: &mut Vec<i32>) {
+    // TODO: multiply every element by 2
+    todo!()
+}
@@ -0,0 +1,20 @@
+// Exercise: first_initial
+// Lesson: Option Type Examples - Using and_then to chain
+//
+// Look the user up with `find_user` and return the first letter of their
+// name, or None when there is no such user. Try it without match.
+//
+// Check your answer with:  cargo run --bin learn -- check first_initial
+
+fn find_user(id: u32) -> Option<String> {
+    match id {
+        1 => Some(String::from("Alice")),
+        2 => Some(String::from("Bob")),
+        _ => None,
+    }
+}
+
+fn first_initial(id: u32) -> Option<char> {
+    // TODO: chain find_user and the first char of the name
+    todo!()
+}
@@ -0,0 +1,12 @@
+// Exercise: first_word
+// Lesson: String Types - String Slicing
+//
+// Return the first word of `s` (everything before the first space) as a
+// slice of `s` - no new String. A string without spaces is one word.
+//
+// Check your answer with:  cargo run --bin learn -- check first_word
+
+fn first_word(s: &str) -> &str {
+    // TODO: slice s up to the first ' '
+    todo!()
+}
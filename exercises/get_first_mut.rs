@@ -0,0 +1,12 @@
+// Exercise: get_first_mut
+// Lesson: Mutable Borrowing - Returning Mutable References
+//
+// The lesson's version, `&mut v[0]`, panics on an empty Vec. Return
+// Option<&mut i32> instead: Some(first element) or None when v is empty.
+//
+// Check your answer with:  cargo run --bin learn -- check get_first_mut
+
+fn get_first_mut(v: &mut Vec<i32>) -> Option<&mut i32> {
+    // TODO: return a mutable reference to the first element, if there is one
+    todo!()
+}
@@ -0,0 +1,17 @@
+// Exercise: longest
+// Lesson: Lifetimes - Basic Lifetime
+//
+// The body is already right, but this doesn't compile: the compiler can't
+// tell whether the result borrows from `x` or from `y`. Add the lifetime
+// annotations that say "the result lives as long as both inputs".
+//
+// Check your answer with:  cargo run --bin learn -- check longest
+
+// TODO: add lifetime annotations
+fn longest(x: &str, y: &str) -> &str {
+    if x.len() >= y.len() {
+        x
+    } else {
+        y
+    }
+}
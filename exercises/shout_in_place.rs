@@ -0,0 +1,12 @@
+// Exercise: shout_in_place
+// Lesson: Mutable Borrowing - Basic Mutable Borrow
+//
+// Change the caller's String through the &mut borrow: make it uppercase
+// and add a "!" at the end. Don't return anything.
+//
+// Check your answer with:  cargo run --bin learn -- check shout_in_place
+
+fn shout_in_place(s: &mut String) {
+    // TODO: uppercase s and push a '!'
+    todo!()
+}
@@ -0,0 +1,15 @@
+// Exercise: sum_numbers
+// Lesson: Result Type Examples - ? Operator (file reading)
+//
+// `input` holds numbers separated by commas, like "1, 2, 3". Add them up.
+// If any part isn't a number, return the parse error with `?` instead of
+// panicking.
+//
+// Check your answer with:  cargo run --bin learn -- check sum_numbers
+
+use std::num::ParseIntError;
+
+fn sum_numbers(input: &str) -> Result<i32, ParseIntError> {
+    // TODO: split on ',', trim and parse each part with ?, and add them up
+    todo!()
+}
@@ -0,0 +1,12 @@
+// Exercise: take_and_give_back
+// Lesson: Ownership Basics - Returning Ownership
+//
+// `take_and_give_back` takes ownership of a String. Hand it back to the
+// caller together with its length, so the caller can keep using it.
+//
+// Check your answer with:  cargo run --bin learn -- check take_and_give_back
+
+fn take_and_give_back(s: String) -> (String, usize) {
+    // TODO: return the same String and its length in bytes
+    todo!()
+}
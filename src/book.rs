@@ -0,0 +1,552 @@
+// Lesson Book
+// Renders the registered lessons as a Markdown or HTML book: one chapter per
+// lesson with each section's source, its captured output and the takeaways.
+// Pages are self-contained, with the stylesheet inlined, so the book works
+// offline.
+
+use crate::lessons::{Entry, CURRICULUM};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Format, String> {
+        match name {
+            "md" | "markdown" => Ok(Format::Markdown),
+            "html" => Ok(Format::Html),
+            _ => Err(format!("unknown format `{}` (use md or html)", name)),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+        }
+    }
+}
+
+/// The chapter's file name, e.g. "01-ownership-basics.html".
+pub fn file_name(index: usize, entry: &Entry, format: Format) -> String {
+    format!("{:02}-{}.{}", index + 1, entry.id, format.extension())
+}
+
+pub fn index_file_name(format: Format) -> String {
+    format!("index.{}", format.extension())
+}
+
+// ============================================================================
+// Reading the Lesson Source
+// ============================================================================
+
+// The source file split at its banners:
+//     // ====...
+//     // 1. Basic Ownership - Values Move by Default
+//     // ====...
+// The text before the first banner has an empty heading.
+fn blocks(source: &str) -> Vec<(String, String)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let is_rule = |line: &str| line.starts_with("// ====");
+    let mut blocks = vec![(String::new(), String::new())];
+
+    let mut i = 0;
+    while i < lines.len() {
+        let banner = is_rule(lines[i]) && i + 2 < lines.len() && is_rule(lines[i + 2]);
+        if let (true, Some(heading)) =
+            (banner, lines.get(i + 1).and_then(|l| l.strip_prefix("// ")))
+        {
+            blocks.push((heading.to_string(), String::new()));
+            i += 3;
+            continue;
+        }
+        if let Some((_, body)) = blocks.last_mut() {
+            body.push_str(lines[i]);
+            body.push('\n');
+        }
+        i += 1;
+    }
+
+    for (_, body) in &mut blocks {
+        *body = body.trim_matches('\n').to_string();
+    }
+    blocks
+}
+
+// `fn name(...) { ... }` with the comment lines just above it
+fn function_source(source: &str, name: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with(&format!("fn {}(", name)))?;
+    let end = start + lines[start..].iter().position(|l| *l == "}")?;
+    let first = (0..start)
+        .rev()
+        .take_while(|&i| lines[i].starts_with("//") && !lines[i].starts_with("// ===="))
+        .last()
+        .unwrap_or(start);
+    Some(lines[first..=end].join("\n"))
+}
+
+/// The heading for section `index`: its banner when the file has one,
+/// otherwise the section title.
+pub fn section_heading(entry: &Entry, index: usize) -> String {
+    let number = format!("{}. ", index + 1);
+    blocks(entry.source)
+        .into_iter()
+        .map(|(heading, _)| heading)
+        .find(|heading| heading.starts_with(&number))
+        .unwrap_or_else(|| format!("{}{}", number, entry.lesson.sections[index].title))
+}
+
+/// The code behind section `index`: everything under its numbered banner,
+/// plus the function that runs it if that lives elsewhere.
+pub fn section_source(entry: &Entry, index: usize) -> String {
+    let number = format!("{}. ", index + 1);
+    let function = entry.lesson.sections[index].function;
+    let mut code = blocks(entry.source)
+        .into_iter()
+        .find(|(heading, _)| heading.starts_with(&number))
+        .map(|(_, body)| body)
+        .unwrap_or_default();
+
+    if !code.contains(&format!("fn {}(", function)) {
+        if let Some(extra) = function_source(entry.source, function) {
+            if !code.is_empty() {
+                code += "\n\n";
+            }
+            code += &extra;
+        }
+    }
+    code
+}
+
+/// The comments in section `index`, each followed by the code it sits on
+/// when it ends a line.
+pub fn section_comments(entry: &Entry, index: usize) -> Vec<String> {
+    let mut comments = Vec::new();
+    for line in section_source(entry, index).lines() {
+        let Some(at) = comment_start(line) else {
+            continue;
+        };
+        let (code, comment) = (line[..at].trim(), line[at + 2..].trim());
+        if comment.is_empty() {
+            continue;
+        }
+        if code.is_empty() {
+            comments.push(comment.to_string());
+        } else {
+            comments.push(format!("{}  ({})", comment, code));
+        }
+    }
+    comments
+}
+
+// Where the line's `//` comment starts; a "//" in a string or char literal,
+// like a URL, is code. Literals are read the way highlight() reads them.
+pub(crate) fn comment_start(line: &str) -> Option<usize> {
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let at = |i: usize| chars.get(i).map(|&(_, c)| c);
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i].1 {
+            '/' if at(i + 1) == Some('/') => return Some(chars[i].0),
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i].1 != '"' {
+                    i += if chars[i].1 == '\\' { 2 } else { 1 };
+                }
+            }
+            '\'' => {
+                let char_end = if at(i + 1) == Some('\\') {
+                    i + 3
+                } else {
+                    i + 2
+                };
+                if at(char_end) == Some('\'') {
+                    i = char_end;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// The file's opening comment minus its first line, which repeats the title
+fn introduction(entry: &Entry) -> String {
+    entry
+        .source
+        .lines()
+        .skip(1)
+        .map_while(|line| line.strip_prefix("//"))
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============================================================================
+// Markdown
+// ============================================================================
+
+fn markdown_chapter(index: usize, entry: &Entry, outputs: &[String]) -> String {
+    let mut md = format!("# {}\n\n", entry.lesson.title);
+    let intro = introduction(entry);
+    if !intro.is_empty() {
+        md += &format!("{}\n\n", escape(&intro));
+    }
+    md += &format!("Source: `{}`\n", entry.file);
+
+    for i in 0..entry.lesson.sections.len() {
+        md += &format!("\n## {}\n\n", section_heading(entry, i));
+        md += &format!("```rust\n{}\n```\n", section_source(entry, i));
+        if let Some(output) = outputs.get(i) {
+            md += &format!("\nOutput:\n\n```text\n{}\n```\n", output.trim_end());
+        }
+    }
+
+    if !entry.lesson.takeaways.is_empty() {
+        md += "\n## Key Takeaways\n\n";
+        for takeaway in entry.lesson.takeaways {
+            md += &format!("- {}\n", escape(takeaway));
+        }
+    }
+    md + &navigation(index, Format::Markdown)
+}
+
+// ============================================================================
+// HTML
+// ============================================================================
+
+const STYLE: &str = "\
+body { max-width: 50em; margin: 2em auto; padding: 0 1em; font-family: sans-serif; line-height: 1.5; color: #222; }
+pre { background: #f6f8fa; padding: 0.8em; overflow-x: auto; border-radius: 4px; }
+pre.output { background: #1e1e1e; color: #ddd; }
+.kw { color: #a626a4; } .ty { color: #c18401; } .str { color: #50a14f; }
+.com { color: #8a8a8a; font-style: italic; } .num { color: #986801; }
+.mac { color: #4078f2; } .lt { color: #e45649; }
+nav { margin-top: 2em; border-top: 1px solid #ddd; padding-top: 1em; }
+";
+
+const KEYWORDS: [&str; 38] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "dyn", "async", "await",
+];
+
+/// Escapes text for HTML, which Markdown prose also accepts.
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn span(class: &str, text: &str) -> String {
+    format!("<span class=\"{}\">{}</span>", class, escape(text))
+}
+
+/// Rust source as HTML with keywords, types, strings, comments, numbers,
+/// macros and lifetimes wrapped in classed spans.
+pub fn highlight(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let text = |from: usize, to: usize| chars[from..to].iter().collect::<String>();
+    let mut html = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            html += &span("com", &text(start, i));
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(chars.len());
+            html += &span("str", &text(start, i));
+        } else if c == '\'' {
+            // 'x' and '\n' are chars, 'a without a closing quote is a lifetime
+            let char_end = if chars.get(i + 1) == Some(&'\\') {
+                i + 3
+            } else {
+                i + 2
+            };
+            if chars.get(char_end) == Some(&'\'') {
+                i = char_end + 1;
+                html += &span("str", &text(start, i));
+            } else {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                html += &span("lt", &text(start, i));
+            }
+        } else if c.is_ascii_digit() {
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                // 1..5 is a range, not a number
+                if chars[i] == '.' && chars.get(i + 1) == Some(&'.') {
+                    break;
+                }
+                i += 1;
+            }
+            html += &span("num", &text(start, i));
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word = text(start, i);
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                html += &span("mac", &text(start, i));
+            } else if KEYWORDS.contains(&word.as_str()) {
+                html += &span("kw", &word);
+            } else if word.starts_with(char::is_uppercase) {
+                html += &span("ty", &word);
+            } else {
+                html += &word;
+            }
+        } else {
+            i += 1;
+            html += &escape(&c.to_string());
+        }
+    }
+    html
+}
+
+pub(crate) fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+fn html_chapter(index: usize, entry: &Entry, outputs: &[String]) -> String {
+    let mut body = format!("<h1>{}</h1>\n", escape(entry.lesson.title));
+    let intro = introduction(entry);
+    if !intro.is_empty() {
+        body += &format!("<p>{}</p>\n", escape(&intro));
+    }
+    body += &format!("<p>Source: <code>{}</code></p>\n", escape(entry.file));
+
+    for i in 0..entry.lesson.sections.len() {
+        body += &format!("<h2>{}</h2>\n", escape(&section_heading(entry, i)));
+        body += &format!(
+            "<pre><code>{}</code></pre>\n",
+            highlight(&section_source(entry, i))
+        );
+        if let Some(output) = outputs.get(i) {
+            body += &format!(
+                "<p>Output:</p>\n<pre class=\"output\">{}</pre>\n",
+                escape(output.trim_end())
+            );
+        }
+    }
+
+    if !entry.lesson.takeaways.is_empty() {
+        body += "<h2>Key Takeaways</h2>\n<ul>\n";
+        for takeaway in entry.lesson.takeaways {
+            body += &format!("<li>{}</li>\n", escape(takeaway));
+        }
+        body += "</ul>\n";
+    }
+    body += &navigation(index, Format::Html);
+    html_page(entry.lesson.title, &body)
+}
+
+// ============================================================================
+// Book
+// ============================================================================
+
+// Links to the previous chapter, the contents and the next chapter
+fn navigation(index: usize, format: Format) -> String {
+    let mut links = Vec::new();
+    if let Some(prev) = index.checked_sub(1).and_then(|i| CURRICULUM.get(i)) {
+        links.push((
+            format!("← {}", prev.lesson.title),
+            file_name(index - 1, prev, format),
+        ));
+    }
+    links.push((String::from("Contents"), index_file_name(format)));
+    if let Some(next) = CURRICULUM.get(index + 1) {
+        links.push((
+            format!("{} →", next.lesson.title),
+            file_name(index + 1, next, format),
+        ));
+    }
+
+    match format {
+        Format::Markdown => {
+            let links: Vec<String> = links
+                .iter()
+                .map(|(text, href)| format!("[{}]({})", text, href))
+                .collect();
+            format!("\n---\n\n{}\n", links.join(" | "))
+        }
+        Format::Html => {
+            let links: Vec<String> = links
+                .iter()
+                .map(|(text, href)| format!("<a href=\"{}\">{}</a>", href, escape(text)))
+                .collect();
+            format!("<nav>{}</nav>\n", links.join(" | "))
+        }
+    }
+}
+
+/// The chapter for the lesson at `index` in the curriculum. `outputs` holds
+/// what each section printed.
+pub fn chapter(index: usize, outputs: &[String], format: Format) -> String {
+    let entry = &CURRICULUM[index];
+    match format {
+        Format::Markdown => markdown_chapter(index, entry, outputs),
+        Format::Html => html_chapter(index, entry, outputs),
+    }
+}
+
+/// The table of contents, grouped by topic.
+pub fn contents(format: Format) -> String {
+    let mut topic = "";
+    let mut out = String::new();
+    for (i, entry) in CURRICULUM.iter().enumerate() {
+        let href = file_name(i, entry, format);
+        match format {
+            Format::Markdown => {
+                if entry.topic != topic {
+                    out += &format!("\n## {}\n\n", entry.topic);
+                }
+                out += &format!("{}. [{}]({})\n", i + 1, entry.lesson.title, href);
+            }
+            Format::Html => {
+                if entry.topic != topic {
+                    if !topic.is_empty() {
+                        out += "</ul>\n";
+                    }
+                    out += &format!("<h2>{}</h2>\n<ul>\n", escape(entry.topic));
+                }
+                out += &format!(
+                    "<li>{}. <a href=\"{}\">{}</a></li>\n",
+                    i + 1,
+                    href,
+                    escape(entry.lesson.title)
+                );
+            }
+        }
+        topic = entry.topic;
+    }
+
+    match format {
+        Format::Markdown => format!("# Learn Rust\n{}", out),
+        Format::Html => html_page(
+            "Learn Rust",
+            &format!("<h1>Learn Rust</h1>\n{}</ul>\n", out),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::find;
+
+    #[test]
+    fn sections_get_their_banner_and_code() {
+        let basics = find("ownership-basics").unwrap();
+        assert_eq!(
+            section_heading(basics, 0),
+            "1. Basic Ownership - Values Move by Default"
+        );
+        let code = section_source(basics, 0);
+        assert!(code.starts_with("fn take_ownership(s: String) {"));
+        assert!(code.contains("fn demonstrate_move() {"));
+        assert!(!code.contains("// ===="));
+
+        // Option's demonstrations sit apart from the helpers they call
+        let option = find("option").unwrap();
+        let code = section_source(option, 0);
+        assert!(code.contains("fn find_user(id: u32)"));
+        assert!(code.contains("fn demonstrate_basic_option() {"));
+
+        // ... and the later sections have no banner of their own
+        assert_eq!(section_heading(option, 6), "7. Checking Option state");
+        assert!(section_source(option, 6).starts_with("// Bonus: is_some() and is_none()\nfn"));
+        let result = find("result").unwrap();
+        assert!(section_source(result, 10).starts_with("// Bonus: is_ok() and is_err()\nfn"));
+    }
+
+    #[test]
+    fn every_section_has_code() {
+        for entry in &CURRICULUM {
+            for i in 0..entry.lesson.sections.len() {
+                let function = entry.lesson.sections[i].function;
+                assert!(
+                    section_source(entry, i).contains(&format!("fn {}(", function)),
+                    "{} section {}",
+                    entry.id,
+                    i + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_comments_outside_literals() {
+        assert_eq!(comment_start("let x = 1; // one"), Some(11));
+        assert_eq!(comment_start("// whole line"), Some(0));
+        assert_eq!(
+            comment_start("println!(\"https://doc.rust-lang.org\");"),
+            None
+        );
+        assert_eq!(comment_start("let s = \"a \\\" //\"; // after"), Some(19));
+        assert_eq!(comment_start("let q = '\"'; // quote"), Some(13));
+        assert_eq!(comment_start("fn f<'a>(s: &'a str) // x"), Some(21));
+    }
+
+    #[test]
+    fn highlights_rust() {
+        assert_eq!(
+            highlight("let s: &'a str = \"<hi>\"; // done"),
+            "<span class=\"kw\">let</span> s: &amp;<span class=\"lt\">'a</span> str = \
+             <span class=\"str\">&quot;&lt;hi&gt;&quot;</span>; <span class=\"com\">// done</span>"
+        );
+        assert_eq!(
+            highlight("println!(\"{}\", 'x'); Some(1..5)"),
+            "<span class=\"mac\">println!</span>(<span class=\"str\">&quot;{}&quot;</span>, \
+             <span class=\"str\">'x'</span>); <span class=\"ty\">Some</span>(\
+             <span class=\"num\">1</span>..<span class=\"num\">5</span>)"
+        );
+    }
+
+    #[test]
+    fn chapters_hold_code_output_and_takeaways() {
+        let outputs = vec![String::from("   Inside function: hello\n")];
+        let md = chapter(0, &outputs, Format::Markdown);
+        assert!(md.starts_with("# Ownership Basics\n\n"));
+        assert!(md.contains(
+            "## 1. Basic Ownership - Values Move by Default\n\n```rust\nfn take_ownership"
+        ));
+        assert!(md.contains("Output:\n\n```text\n   Inside function: hello\n```"));
+        assert!(md.contains("- Each value has exactly one owner\n"));
+        assert!(md.contains("[Borrowing (Immutable References) →](02-borrowing.md)"));
+
+        let html = chapter(0, &outputs, Format::Html);
+        assert!(html.contains("<style>"));
+        assert!(!html.contains("http"), "pages must not load anything");
+        assert!(html.contains("<li>Each value has exactly one owner</li>"));
+        assert!(
+            contents(Format::Html).contains("<a href=\"09-result.html\">Result Type Examples</a>")
+        );
+    }
+}
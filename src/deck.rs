@@ -0,0 +1,246 @@
+// Flashcard Decks
+// The lessons' takeaways and quiz questions as Anki-importable TSV or CSV,
+// one deck file per topic. Each card names the file and section it came from.
+
+use crate::book::{escape, section_heading};
+use crate::lessons::{topics, Entry};
+use crate::quiz::Question;
+use crate::review::Card;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Separator {
+    Tab,
+    Comma,
+}
+
+impl Separator {
+    pub fn parse(name: &str) -> Option<Separator> {
+        match name {
+            "tsv" => Some(Separator::Tab),
+            "csv" => Some(Separator::Comma),
+            _ => None,
+        }
+    }
+
+    fn char(self) -> char {
+        match self {
+            Separator::Tab => '\t',
+            Separator::Comma => ',',
+        }
+    }
+}
+
+/// One card. Fields hold HTML, which Anki renders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    pub front: String,
+    pub back: String,
+    pub source: String, // "examples/option_examples.rs:54, 6. Using filter ..."
+    pub tags: String,
+}
+
+// ============================================================================
+// Building Cards
+// ============================================================================
+
+// 1-based line of the first line containing `needle`
+fn line_of(entry: &Entry, needle: &str) -> usize {
+    entry
+        .source
+        .lines()
+        .position(|line| line.contains(needle))
+        .map_or(1, |i| i + 1)
+}
+
+/// Where section `index` starts: its banner, or the function that runs it.
+pub fn section_source_ref(entry: &Entry, index: usize) -> String {
+    let heading = section_heading(entry, index);
+    let banner = format!("// {}", heading);
+    let line = if entry.source.contains(&banner) {
+        line_of(entry, &banner)
+    } else {
+        line_of(
+            entry,
+            &format!("fn {}(", entry.lesson.sections[index].function),
+        )
+    };
+    format!("{}:{}, {}", entry.file, line, heading)
+}
+
+fn pre(code: &str) -> String {
+    format!("<pre>{}</pre>", escape(code))
+}
+
+/// The lesson's takeaways as fill-in-the-blank cards.
+pub fn takeaway_notes(entry: &Entry) -> Vec<Note> {
+    entry
+        .lesson
+        .takeaways
+        .iter()
+        .map(|takeaway| {
+            let card = Card::new(entry, takeaway);
+            let (front, term) = card.cloze();
+            Note {
+                front: format!("{}<br>{}", escape(entry.lesson.title), escape(&front)),
+                back: format!("{}<br><b>{}</b>", escape(takeaway), escape(term)),
+                source: format!(
+                    "{}:{}, Key Takeaways",
+                    entry.file,
+                    line_of(entry, &format!("{:?}", takeaway))
+                ),
+                tags: format!("learn-rust {} takeaway", entry.id),
+            }
+        })
+        .collect()
+}
+
+/// A quiz question with its answer, and a remark such as rustc's error.
+pub fn question_note(entry: &Entry, question: &Question, answer: &str, remark: &str) -> Note {
+    let mut front = format!("{}<br>{}", escape(&question.ask), pre(&question.snippet));
+    if !question.choices.is_empty() {
+        let choices: Vec<String> = question.choices.iter().map(|c| escape(c)).collect();
+        front += &format!("Choices: {}", choices.join(" | "));
+    }
+    let mut back = escape(answer);
+    if !remark.is_empty() {
+        back += &format!("<br>{}", escape(remark));
+    }
+    Note {
+        front,
+        back,
+        source: section_source_ref(entry, question.section - 1),
+        tags: format!("learn-rust {} quiz", entry.id),
+    }
+}
+
+// ============================================================================
+// Deck Files
+// ============================================================================
+
+/// Every topic in curriculum order with its lessons, as deck names.
+pub fn decks() -> Vec<(String, Vec<&'static Entry>)> {
+    topics()
+        .into_iter()
+        .map(|(topic, entries)| (format!("Learn Rust::{}", topic), entries))
+        .collect()
+}
+
+/// "Learn Rust::Option" becomes "option.tsv".
+pub fn file_name(deck: &str, separator: Separator) -> String {
+    let topic = deck.rsplit("::").next().unwrap_or(deck);
+    let extension = match separator {
+        Separator::Tab => "tsv",
+        Separator::Comma => "csv",
+    };
+    format!("{}.{}", topic.to_lowercase().replace(' ', "-"), extension)
+}
+
+// Quoted when needed, with newlines turned into <br> so a card stays on one line
+fn field(text: &str, separator: Separator) -> String {
+    let text = text.replace("\r\n", "\n").replace('\n', "<br>");
+    if text.contains([separator.char(), '"']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+/// A deck in Anki's text import format: header lines naming the separator,
+/// columns and deck, then one card per line.
+pub fn deck_file(deck: &str, notes: &[Note], separator: Separator) -> String {
+    let sep = separator.char();
+    let name = match separator {
+        Separator::Tab => "tab",
+        Separator::Comma => "comma",
+    };
+    let mut text = format!(
+        "#separator:{}\n#html:true\n#deck:{}\n#columns:Front{}Back{}Source{}Tags\n#tags column:4\n",
+        name, deck, sep, sep, sep
+    );
+    for note in notes {
+        let fields = [&note.front, &note.back, &note.source, &note.tags];
+        let fields: Vec<String> = fields.iter().map(|f| field(f, separator)).collect();
+        text += &fields.join(&sep.to_string());
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::find;
+
+    #[test]
+    fn takeaways_point_back_at_their_line() {
+        let entry = find("mutable-borrowing").unwrap();
+        let notes = takeaway_notes(entry);
+        assert_eq!(notes.len(), entry.lesson.takeaways.len());
+
+        let note = &notes[1];
+        assert_eq!(
+            note.front,
+            "Mutable Borrowing<br>Only ____ mutable borrow at a time"
+        );
+        assert_eq!(note.back, "Only ONE mutable borrow at a time<br><b>ONE</b>");
+        let (file, rest) = note.source.split_once(':').unwrap();
+        assert_eq!(file, "examples/ownership/03_mutable_borrowing.rs");
+        let line: usize = rest.split(',').next().unwrap().parse().unwrap();
+        assert!(entry
+            .source
+            .lines()
+            .nth(line - 1)
+            .unwrap()
+            .contains("Only ONE"));
+    }
+
+    #[test]
+    fn sections_are_referenced_by_banner_or_function() {
+        let option = find("option").unwrap();
+        let banner = section_source_ref(option, 5);
+        assert!(banner.starts_with("examples/option_examples.rs:"));
+        assert!(banner.ends_with(", 6. Using filter to Conditionally Keep Values"));
+        assert!(section_source_ref(option, 6).ends_with(", 7. Checking Option state"));
+    }
+
+    #[test]
+    fn one_deck_per_topic() {
+        let names: Vec<String> = decks().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            names,
+            [
+                "Learn Rust::Ownership",
+                "Learn Rust::Borrowing",
+                "Learn Rust::Lifetimes",
+                "Learn Rust::Dereference",
+                "Learn Rust::Option",
+                "Learn Rust::Result"
+            ]
+        );
+        assert_eq!(file_name(&names[4], Separator::Comma), "option.csv");
+    }
+
+    #[test]
+    fn writes_anki_text_files() {
+        let note = Note {
+            front: String::from("Say \"hi\",\nplease"),
+            back: String::from("hi\tthere"),
+            source: String::from("a.rs:1, 1. Hello"),
+            tags: String::from("learn-rust hello"),
+        };
+        let csv = deck_file(
+            "Learn Rust::Hello",
+            std::slice::from_ref(&note),
+            Separator::Comma,
+        );
+        assert_eq!(
+            csv,
+            "#separator:comma\n#html:true\n#deck:Learn Rust::Hello\n#columns:Front,Back,Source,Tags\n#tags column:4\n\
+             \"Say \"\"hi\"\",<br>please\",hi\tthere,\"a.rs:1, 1. Hello\",learn-rust hello\n"
+        );
+        let tsv = deck_file("Learn Rust::Hello", &[note], Separator::Tab);
+        assert!(tsv.ends_with(
+            "\"Say \"\"hi\"\",<br>please\"\t\"hi\tthere\"\ta.rs:1, 1. Hello\tlearn-rust hello\n"
+        ));
+    }
+}
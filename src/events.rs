@@ -0,0 +1,264 @@
+// Lesson Run Events
+// A lesson run as a stream of JSON objects, one per line, for tools that
+// want structure rather than text:
+//
+//     {"event":"lesson_start","lesson":"borrowing","title":"Borrowing ...","sections":5}
+//     {"event":"section_start","lesson":"borrowing","section":1,"title":"Basic Borrowing"}
+//     {"event":"output","lesson":"borrowing","section":1,"line":"   String: hello"}
+//     {"event":"section_end","lesson":"borrowing","section":1,"status":"ok","elapsed_ms":2}
+//     {"event":"takeaway","lesson":"borrowing","text":"Use & to borrow ..."}
+//     {"event":"lesson_end","lesson":"borrowing","elapsed_ms":11}
+//     {"event":"error","message":"Invalid choice: 9"}    instead of a run
+//
+// A section's elapsed_ms is the time its capture took. The ownership runner
+// captures each section in a child process, so that includes starting it.
+
+use crate::lessons::Entry;
+use std::io::{self, Write};
+use std::time::Instant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    LessonStart {
+        lesson: String,
+        title: String,
+        sections: usize,
+    },
+    SectionStart {
+        lesson: String,
+        section: usize, // numbered from 1
+        title: String,
+    },
+    Output {
+        lesson: String,
+        section: usize,
+        line: String,
+    },
+    SectionEnd {
+        lesson: String,
+        section: usize,
+        error: Option<String>, // why the section failed
+        elapsed_ms: u128,      // including whatever capture does to run it
+    },
+    Takeaway {
+        lesson: String,
+        text: String,
+    },
+    LessonEnd {
+        lesson: String,
+        elapsed_ms: u128,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// `text` as a JSON string literal.
+pub fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if (c as u32) < 0x20 => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+impl Event {
+    /// The event as one line of JSON.
+    pub fn to_json(&self) -> String {
+        let s = |text: &str| json_string(text);
+        match self {
+            Event::LessonStart {
+                lesson,
+                title,
+                sections,
+            } => format!(
+                "{{\"event\":\"lesson_start\",\"lesson\":{},\"title\":{},\"sections\":{}}}",
+                s(lesson),
+                s(title),
+                sections
+            ),
+            Event::SectionStart {
+                lesson,
+                section,
+                title,
+            } => format!(
+                "{{\"event\":\"section_start\",\"lesson\":{},\"section\":{},\"title\":{}}}",
+                s(lesson),
+                section,
+                s(title)
+            ),
+            Event::Output {
+                lesson,
+                section,
+                line,
+            } => format!(
+                "{{\"event\":\"output\",\"lesson\":{},\"section\":{},\"line\":{}}}",
+                s(lesson),
+                section,
+                s(line)
+            ),
+            Event::SectionEnd {
+                lesson,
+                section,
+                error,
+                elapsed_ms,
+            } => {
+                let status = match error {
+                    None => String::from("\"status\":\"ok\""),
+                    Some(e) => format!("\"status\":\"failed\",\"error\":{}", s(e)),
+                };
+                format!(
+                    "{{\"event\":\"section_end\",\"lesson\":{},\"section\":{},{},\"elapsed_ms\":{}}}",
+                    s(lesson),
+                    section,
+                    status,
+                    elapsed_ms
+                )
+            }
+            Event::Takeaway { lesson, text } => format!(
+                "{{\"event\":\"takeaway\",\"lesson\":{},\"text\":{}}}",
+                s(lesson),
+                s(text)
+            ),
+            Event::LessonEnd { lesson, elapsed_ms } => format!(
+                "{{\"event\":\"lesson_end\",\"lesson\":{},\"elapsed_ms\":{}}}",
+                s(lesson),
+                elapsed_ms
+            ),
+            Event::Error { message } => {
+                format!("{{\"event\":\"error\",\"message\":{}}}", s(message))
+            }
+        }
+    }
+}
+
+/// Runs a lesson, writing its events to `out` as they happen. `capture`
+/// runs one section (numbered from 1) and returns what it printed; the
+/// caller decides how, since sections print straight to stdout.
+pub fn run_lesson(
+    entry: &Entry,
+    capture: &mut dyn FnMut(&Entry, usize) -> Result<String, String>,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let lesson = entry.id.to_string();
+    let started = Instant::now();
+    let mut emit = |event: Event| writeln!(out, "{}", event.to_json());
+
+    emit(Event::LessonStart {
+        lesson: lesson.clone(),
+        title: entry.lesson.title.to_string(),
+        sections: entry.lesson.sections.len(),
+    })?;
+
+    for (i, section) in entry.lesson.sections.iter().enumerate() {
+        emit(Event::SectionStart {
+            lesson: lesson.clone(),
+            section: i + 1,
+            title: section.title.to_string(),
+        })?;
+
+        let section_started = Instant::now();
+        let result = capture(entry, i + 1);
+        let elapsed_ms = section_started.elapsed().as_millis();
+
+        let (output, error) = match result {
+            Ok(output) => (output, None),
+            Err(e) => (String::new(), Some(e)),
+        };
+        for line in output.lines() {
+            emit(Event::Output {
+                lesson: lesson.clone(),
+                section: i + 1,
+                line: line.to_string(),
+            })?;
+        }
+        emit(Event::SectionEnd {
+            lesson: lesson.clone(),
+            section: i + 1,
+            error,
+            elapsed_ms,
+        })?;
+    }
+
+    for takeaway in entry.lesson.takeaways {
+        emit(Event::Takeaway {
+            lesson: lesson.clone(),
+            text: takeaway.to_string(),
+        })?;
+    }
+    emit(Event::LessonEnd {
+        lesson,
+        elapsed_ms: started.elapsed().as_millis(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::find;
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(
+            json_string("say \"hi\"\\\n\tnow\u{1}"),
+            "\"say \\\"hi\\\"\\\\\\n\\tnow\\u0001\""
+        );
+        assert_eq!(json_string("• ünïcode"), "\"• ünïcode\"");
+
+        let error = Event::Error {
+            message: String::from("Invalid choice: \"9\""),
+        };
+        assert_eq!(
+            error.to_json(),
+            "{\"event\":\"error\",\"message\":\"Invalid choice: \\\"9\\\"\"}"
+        );
+    }
+
+    #[test]
+    fn streams_a_lesson_as_events() {
+        let entry = find("ownership-basics").unwrap();
+        let mut capture = |_: &Entry, section: usize| match section {
+            1 => Ok(String::from("   Inside function: hello\n")),
+            2 => Err(String::from("section 2 panicked")),
+            _ => Ok(String::new()),
+        };
+        let mut out = Vec::new();
+        run_lesson(entry, &mut capture, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            "{\"event\":\"lesson_start\",\"lesson\":\"ownership-basics\",\"title\":\"Ownership Basics\",\"sections\":5}"
+        );
+        assert_eq!(
+            lines[1],
+            "{\"event\":\"section_start\",\"lesson\":\"ownership-basics\",\"section\":1,\"title\":\"Move Semantics\"}"
+        );
+        assert_eq!(
+            lines[2],
+            "{\"event\":\"output\",\"lesson\":\"ownership-basics\",\"section\":1,\"line\":\"   Inside function: hello\"}"
+        );
+        assert!(lines[3].starts_with(
+            "{\"event\":\"section_end\",\"lesson\":\"ownership-basics\",\"section\":1,\"status\":\"ok\",\"elapsed_ms\":"
+        ));
+        assert!(lines[5].contains("\"status\":\"failed\",\"error\":\"section 2 panicked\""));
+
+        let takeaways = lines.iter().filter(|l| l.contains("\"takeaway\"")).count();
+        assert_eq!(takeaways, entry.lesson.takeaways.len());
+        assert!(lines
+            .last()
+            .unwrap()
+            .starts_with("{\"event\":\"lesson_end\""));
+        assert_eq!(lines.len(), 1 + 5 * 2 + 1 + takeaways + 1);
+    }
+}
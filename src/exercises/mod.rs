@@ -0,0 +1,438 @@
+// Exercises
+// Each lesson has stubs in exercises/ for trainees to fill in. The tests that
+// grade them live here, out of sight, and are appended at check time.
+
+use crate::lessons::Entry;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// Exercise Registry
+// ============================================================================
+
+/// One exercise: the stub in exercises/NAME.rs plus its hidden tests.
+pub struct Exercise {
+    pub name: &'static str,
+    pub lesson: &'static str,  // lesson title
+    pub section: &'static str, // section title within the lesson
+    pub task: &'static str,
+    pub tests: &'static str,
+    pub solution: &'static str,
+    pub hints: &'static str,
+}
+
+/// A nudge towards the answer, pointing at the lesson section that teaches it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hint {
+    pub lesson: String,
+    pub section: String,      // "2. Lifetime Annotations"
+    pub code: Option<String>, // suggested when rustc reports this error
+    pub text: String,
+}
+
+macro_rules! exercise {
+    ($name:literal, $lesson:literal, $section:literal, $task:literal) => {
+        Exercise {
+            name: $name,
+            lesson: $lesson,
+            section: $section,
+            task: $task,
+            tests: include_str!(concat!("tests/", $name, ".rs")),
+            solution: include_str!(concat!("solutions/", $name, ".rs")),
+            hints: include_str!(concat!("hints/", $name, ".txt")),
+        }
+    };
+}
+
+/// Every exercise, in curriculum order.
+pub static EXERCISES: [Exercise; 9] = [
+    exercise!(
+        "take_and_give_back",
+        "Ownership Basics",
+        "Returning Ownership",
+        "Return a moved String to the caller along with its length"
+    ),
+    exercise!(
+        "count_long_words",
+        "Borrowing (Immutable References)",
+        "Borrowing Vectors",
+        "Count long words in a borrowed Vec<String>"
+    ),
+    exercise!(
+        "shout_in_place",
+        "Mutable Borrowing",
+        "Basic Mutable Borrow",
+        "Change a String through &mut String"
+    ),
+    exercise!(
+        "get_first_mut",
+        "Mutable Borrowing",
+        "Returning Mutable References",
+        "Return Option<&mut i32> instead of panicking on an empty Vec"
+    ),
+    exercise!(
+        "first_word",
+        "String Types",
+        "String Slicing",
+        "Return the first word as a &str slice"
+    ),
+    exercise!(
+        "longest",
+        "Lifetimes",
+        "Basic Lifetime",
+        "Implement longest with the right lifetimes"
+    ),
+    exercise!(
+        "first_initial",
+        "Option Type Examples",
+        "Using and_then to chain",
+        "Chain Option lookups with and_then"
+    ),
+    exercise!(
+        "sum_numbers",
+        "Result Type Examples",
+        "? Operator (file reading)",
+        "Propagate parse errors with ?"
+    ),
+    exercise!(
+        "double_all",
+        "Dereference Operator (*)",
+        "Dereferencing in Loops",
+        "Double every element through iter_mut and *"
+    ),
+];
+
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|e| e.name == name)
+}
+
+/// The exercises that practise a lesson.
+pub fn for_lesson(entry: &Entry) -> Vec<&'static Exercise> {
+    EXERCISES
+        .iter()
+        .filter(|e| e.lesson == entry.lesson.title)
+        .collect()
+}
+
+impl Exercise {
+    /// The trainee's copy, exercises/NAME.rs in the repository.
+    pub fn path(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("exercises")
+            .join(format!("{}.rs", self.name))
+    }
+
+    /// The trainee's source with the hidden tests appended as a test module.
+    pub fn with_hidden_tests(&self, source: &str) -> String {
+        let tests: String = self
+            .tests
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::from("\n")
+                } else {
+                    format!("    {}\n", line)
+                }
+            })
+            .collect();
+        format!(
+            "{}\n\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n\n{}}}\n",
+            source.trim_end(),
+            tests
+        )
+    }
+
+    /// The hints in the order `learn hint` reveals them.
+    pub fn hints(&self) -> Vec<Hint> {
+        parse_hints(self.hints)
+    }
+
+    /// The first hint for an error code rustc reported, with its index.
+    pub fn hint_for_errors(&self, compiler_output: &str) -> Option<(usize, Hint)> {
+        let codes = error_codes(compiler_output);
+        self.hints()
+            .into_iter()
+            .enumerate()
+            .find(|(_, hint)| hint.code.as_ref().is_some_and(|code| codes.contains(code)))
+    }
+}
+
+// "# comment" lines, then blocks starting "== Lesson / N. Section", an
+// optional "error: E0xxx" line and the hint text
+fn parse_hints(text: &str) -> Vec<Hint> {
+    let mut hints: Vec<Hint> = Vec::new();
+
+    for line in text.lines() {
+        if let Some(title) = line.strip_prefix("== ") {
+            let (lesson, section) = title.split_once(" / ").unwrap_or((title, ""));
+            hints.push(Hint {
+                lesson: lesson.to_string(),
+                section: section.to_string(),
+                code: None,
+                text: String::new(),
+            });
+        } else if let Some(hint) = hints.last_mut() {
+            if let Some(code) = line
+                .strip_prefix("error: ")
+                .filter(|_| hint.text.is_empty())
+            {
+                hint.code = Some(code.trim().to_string());
+            } else if !(line.is_empty() && hint.text.is_empty()) {
+                hint.text.push_str(line);
+                hint.text.push('\n');
+            }
+        }
+    }
+
+    for hint in &mut hints {
+        hint.text = hint.text.trim_end().to_string();
+    }
+    hints
+}
+
+/// The distinct error codes in rustc output, like "E0106", in order.
+pub fn error_codes(compiler_output: &str) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for part in compiler_output.split("error[").skip(1) {
+        if let Some((code, _)) = part.split_once(']') {
+            if !codes.iter().any(|c| c == code) {
+                codes.push(code.to_string());
+            }
+        }
+    }
+    codes
+}
+
+// ============================================================================
+// Checking a Trainee's File
+// ============================================================================
+
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    pub message: String, // the panic message of a failed test
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    CompileError(String), // rustc's error output
+    Tested(Vec<TestResult>),
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        match self {
+            Outcome::Tested(results) => !results.is_empty() && results.iter().all(|r| r.passed),
+            _ => false,
+        }
+    }
+}
+
+pub(crate) fn rustc() -> String {
+    env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"))
+}
+
+/// Compiles `source` with the hidden tests and runs them. `shown_path` replaces
+/// the temporary file name in compiler errors.
+pub fn check_source(
+    exercise: &Exercise,
+    source: &str,
+    shown_path: &str,
+) -> Result<Outcome, String> {
+    let dir = env::temp_dir().join(format!(
+        "learn-check-{}-{}",
+        exercise.name,
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let result = compile_and_run(exercise, source, shown_path, &dir);
+    fs::remove_dir_all(&dir).ok();
+    result
+}
+
+/// Checks the trainee's file on disk.
+pub fn check_file(exercise: &Exercise, path: &Path) -> Result<Outcome, String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    check_source(exercise, &source, &path.display().to_string())
+}
+
+fn compile_and_run(
+    exercise: &Exercise,
+    source: &str,
+    shown_path: &str,
+    dir: &Path,
+) -> Result<Outcome, String> {
+    let file = dir.join(format!("{}.rs", exercise.name));
+    let binary = dir.join(exercise.name);
+    fs::write(&file, exercise.with_hidden_tests(source)).map_err(|e| e.to_string())?;
+
+    let output = Command::new(rustc())
+        .args([
+            "--edition",
+            "2021",
+            "--test",
+            "--color",
+            "never",
+            "-A",
+            "warnings",
+            "-o",
+        ])
+        .arg(&binary)
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("failed to run rustc: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Ok(Outcome::CompileError(
+            stderr.replace(&file.display().to_string(), shown_path),
+        ));
+    }
+
+    let mut child = Command::new(&binary)
+        .args(["--test-threads", "1", "--color", "never"])
+        .env("RUST_BACKTRACE", "0")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("failed to run the tests: {}", e))?;
+
+    // Drain stdout while waiting, so a full pipe can't stall the tests
+    let mut stdout = child.stdout.take().ok_or("no test output")?;
+    let reader = thread::spawn(move || {
+        let mut text = String::new();
+        stdout.read_to_string(&mut text).ok();
+        text
+    });
+
+    // A trainee's infinite loop shouldn't hang the checker
+    let started = Instant::now();
+    while child.try_wait().map_err(|e| e.to_string())?.is_none() {
+        if started.elapsed() > TIME_LIMIT {
+            child.kill().ok();
+            child.wait().ok();
+            return Ok(Outcome::TimedOut);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let stdout = reader.join().unwrap_or_default();
+    Ok(Outcome::Tested(parse_test_output(&stdout)))
+}
+
+// Reads libtest's "test hidden_tests::NAME ... ok" lines and the
+// "---- hidden_tests::NAME stdout ----" blocks holding panic messages
+fn parse_test_output(stdout: &str) -> Vec<TestResult> {
+    let mut results: Vec<TestResult> = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test hidden_tests::"))
+        .filter_map(|rest| rest.split_once(" ... "))
+        .map(|(name, status)| TestResult {
+            name: name.to_string(),
+            passed: status == "ok",
+            message: String::new(),
+        })
+        .collect();
+
+    let mut current: Option<usize> = None;
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("---- hidden_tests::")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        {
+            current = results.iter().position(|r| r.name == name);
+        } else if line == "failures:" {
+            current = None;
+        } else if let Some(i) = current {
+            let skip = line.is_empty()
+                || line.starts_with("thread '")
+                || line.starts_with("note: run with");
+            if !skip {
+                let message = &mut results[i].message;
+                if !message.is_empty() {
+                    message.push('\n');
+                }
+                message.push_str(line);
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_exercise_has_a_stub_and_tests() {
+        for exercise in &EXERCISES {
+            assert!(
+                exercise.path().exists(),
+                "missing {}",
+                exercise.path().display()
+            );
+            assert!(
+                exercise.tests.contains("#[test]"),
+                "{} has no tests",
+                exercise.name
+            );
+        }
+    }
+
+    #[test]
+    fn hints_link_sections_and_error_codes() {
+        let hints = find("longest").unwrap().hints();
+        assert_eq!(hints[0].lesson, "Lifetimes");
+        assert_eq!(hints[1].section, "2. Lifetime Annotations");
+        assert_eq!(hints[0].code.as_deref(), Some("E0106"));
+        assert!(hints[1].code.is_none());
+        assert!(hints[1].text.starts_with("Declare a lifetime"));
+
+        let rustc = "error[E0106]: missing lifetime specifier\nerror: aborting";
+        let (index, hint) = find("longest").unwrap().hint_for_errors(rustc).unwrap();
+        assert_eq!((index, hint.section.as_str()), (0, "1. Basic Lifetime"));
+        assert!(find("longest")
+            .unwrap()
+            .hint_for_errors("error[E0499]")
+            .is_none());
+    }
+
+    #[test]
+    fn parses_libtest_output() {
+        let stdout = "\
+running 2 tests
+test hidden_tests::fine ... ok
+test hidden_tests::broken ... FAILED
+
+failures:
+
+---- hidden_tests::broken stdout ----
+
+thread 'hidden_tests::broken' panicked at /tmp/x.rs:3:5:
+not yet implemented
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    hidden_tests::broken
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+";
+        let results = parse_test_output(stdout);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].passed);
+        assert_eq!(results[1].name, "broken");
+        assert!(!results[1].passed);
+        assert_eq!(results[1].message, "not yet implemented");
+    }
+}
@@ -0,0 +1,6 @@
+// Reference solution for exercises/count_long_words.rs
+// Lesson: Borrowing (Immutable References) - Borrowing Vectors
+
+fn count_long_words(words: &Vec<String>, min_len: usize) -> usize {
+    words.iter().filter(|word| word.len() > min_len).count()
+}
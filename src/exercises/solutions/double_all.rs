@@ -0,0 +1,8 @@
+// Reference solution for exercises/double_all.rs
+// Lesson: Dereference Operator (*) - Dereferencing in Loops
+
+fn double_all(numbers: &mut Vec<i32>) {
+    for n in numbers.iter_mut() {
+        *n *= 2;
+    }
+}
@@ -0,0 +1,14 @@
+// Reference solution for exercises/first_initial.rs
+// Lesson: Option Type Examples - Using and_then to chain
+
+fn find_user(id: u32) -> Option<String> {
+    match id {
+        1 => Some(String::from("Alice")),
+        2 => Some(String::from("Bob")),
+        _ => None,
+    }
+}
+
+fn first_initial(id: u32) -> Option<char> {
+    find_user(id).and_then(|name| name.chars().next())
+}
@@ -0,0 +1,9 @@
+// Reference solution for exercises/first_word.rs
+// Lesson: String Types - String Slicing
+
+fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(end) => &s[..end],
+        None => s,
+    }
+}
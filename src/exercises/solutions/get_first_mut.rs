@@ -0,0 +1,6 @@
+// Reference solution for exercises/get_first_mut.rs
+// Lesson: Mutable Borrowing - Returning Mutable References
+
+fn get_first_mut(v: &mut Vec<i32>) -> Option<&mut i32> {
+    v.first_mut()
+}
@@ -0,0 +1,10 @@
+// Reference solution for exercises/longest.rs
+// Lesson: Lifetimes - Basic Lifetime
+
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() >= y.len() {
+        x
+    } else {
+        y
+    }
+}
@@ -0,0 +1,7 @@
+// Reference solution for exercises/shout_in_place.rs
+// Lesson: Mutable Borrowing - Basic Mutable Borrow
+
+fn shout_in_place(s: &mut String) {
+    *s = s.to_uppercase();
+    s.push('!');
+}
@@ -0,0 +1,12 @@
+// Reference solution for exercises/sum_numbers.rs
+// Lesson: Result Type Examples - ? Operator (file reading)
+
+use std::num::ParseIntError;
+
+fn sum_numbers(input: &str) -> Result<i32, ParseIntError> {
+    let mut total = 0;
+    for part in input.split(',') {
+        total += part.trim().parse::<i32>()?;
+    }
+    Ok(total)
+}
@@ -0,0 +1,7 @@
+// Reference solution for exercises/take_and_give_back.rs
+// Lesson: Ownership Basics - Returning Ownership
+
+fn take_and_give_back(s: String) -> (String, usize) {
+    let length = s.len();
+    (s, length)
+}
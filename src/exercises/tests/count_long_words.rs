@@ -0,0 +1,23 @@
+#[test]
+fn counts_only_longer_words() {
+    let words = vec![
+        String::from("a"),
+        String::from("borrow"),
+        String::from("checker"),
+    ];
+    assert_eq!(count_long_words(&words, 5), 2);
+    assert_eq!(count_long_words(&words, 6), 1);
+}
+
+#[test]
+fn equal_length_is_not_longer() {
+    let words = vec![String::from("four")];
+    assert_eq!(count_long_words(&words, 4), 0);
+}
+
+#[test]
+fn caller_still_owns_the_words() {
+    let words = vec![String::from("owned")];
+    count_long_words(&words, 0);
+    assert_eq!(words.len(), 1);
+}
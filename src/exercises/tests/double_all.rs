@@ -0,0 +1,13 @@
+#[test]
+fn doubles_every_element() {
+    let mut v = vec![1, 2, 3];
+    double_all(&mut v);
+    assert_eq!(v, [2, 4, 6]);
+}
+
+#[test]
+fn empty_vec_stays_empty() {
+    let mut v: Vec<i32> = Vec::new();
+    double_all(&mut v);
+    assert!(v.is_empty());
+}
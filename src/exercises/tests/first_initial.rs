@@ -0,0 +1,10 @@
+#[test]
+fn known_users_have_initials() {
+    assert_eq!(first_initial(1), Some('A'));
+    assert_eq!(first_initial(2), Some('B'));
+}
+
+#[test]
+fn unknown_user_has_none() {
+    assert_eq!(first_initial(99), None);
+}
@@ -0,0 +1,17 @@
+#[test]
+fn stops_at_the_first_space() {
+    assert_eq!(first_word("hello world"), "hello");
+}
+
+#[test]
+fn one_word_is_the_whole_string() {
+    assert_eq!(first_word("rust"), "rust");
+    assert_eq!(first_word(""), "");
+}
+
+#[test]
+fn returns_a_slice_of_the_input() {
+    let s = String::from("borrowed slice");
+    let word = first_word(&s);
+    assert_eq!(word.as_ptr(), s.as_ptr());
+}
@@ -0,0 +1,14 @@
+#[test]
+fn empty_vec_gives_none() {
+    let mut v: Vec<i32> = Vec::new();
+    assert!(get_first_mut(&mut v).is_none());
+}
+
+#[test]
+fn changes_the_first_element() {
+    let mut v = vec![10, 20, 30];
+    if let Some(first) = get_first_mut(&mut v) {
+        *first = 99;
+    }
+    assert_eq!(v, [99, 20, 30]);
+}
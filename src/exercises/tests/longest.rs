@@ -0,0 +1,16 @@
+#[test]
+fn returns_the_longer_string() {
+    assert_eq!(longest("long string", "short"), "long string");
+    assert_eq!(longest("a", "abc"), "abc");
+}
+
+#[test]
+fn result_usable_while_both_inputs_live() {
+    let string1 = String::from("long string is long");
+    let result;
+    {
+        let string2 = String::from("xyz");
+        result = longest(string1.as_str(), string2.as_str()).to_string();
+    }
+    assert_eq!(result, "long string is long");
+}
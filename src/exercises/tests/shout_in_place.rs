@@ -0,0 +1,13 @@
+#[test]
+fn uppercases_and_adds_exclamation() {
+    let mut s = String::from("hello");
+    shout_in_place(&mut s);
+    assert_eq!(s, "HELLO!");
+}
+
+#[test]
+fn works_on_empty_strings() {
+    let mut s = String::new();
+    shout_in_place(&mut s);
+    assert_eq!(s, "!");
+}
@@ -0,0 +1,11 @@
+#[test]
+fn adds_comma_separated_numbers() {
+    assert_eq!(sum_numbers("1, 2, 3"), Ok(6));
+    assert_eq!(sum_numbers("42"), Ok(42));
+}
+
+#[test]
+fn returns_the_parse_error() {
+    let error = sum_numbers("1, two, 3").unwrap_err();
+    assert_eq!(error.to_string(), "invalid digit found in string");
+}
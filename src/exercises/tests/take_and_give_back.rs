@@ -0,0 +1,11 @@
+#[test]
+fn gives_the_string_back() {
+    let (s, _) = take_and_give_back(String::from("hello"));
+    assert_eq!(s, "hello");
+}
+
+#[test]
+fn returns_the_length() {
+    assert_eq!(take_and_give_back(String::from("hello")).1, 5);
+    assert_eq!(take_and_give_back(String::new()).1, 0);
+}
@@ -0,0 +1,232 @@
+// Error Explanations
+// The rustc errors a trainee meets first, each explained in a few lines
+// and tied to the lesson section that demonstrates the rule, with a small
+// program that fails and the same program fixed
+
+use crate::book;
+use crate::lessons::{self, Entry};
+
+const ERRORS: &str = include_str!("errors.txt");
+
+/// One error code and what to do about it.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub code: String,    // "E0502"
+    pub message: String, // rustc's message, less the names in backticks
+    pub lesson: String,  // lesson id
+    pub item: String,    // a section's demo function, or an item it defines
+    pub text: String,
+    pub fails: String, // a whole program that fails with `code`
+    pub fix: String,   // the same program, compiling
+}
+
+impl Explanation {
+    /// The lesson and the index of the section that shows the rule.
+    pub fn section(&self) -> Result<(&'static Entry, usize), String> {
+        let entry = lessons::find(&self.lesson)
+            .ok_or_else(|| format!("{}: no lesson named `{}`", self.code, self.lesson))?;
+        let sections = entry.lesson.sections;
+        sections
+            .iter()
+            .position(|section| section.function == self.item)
+            .or_else(|| {
+                (0..sections.len()).find(|&i| defines(&book::section_source(entry, i), &self.item))
+            })
+            .map(|index| (entry, index))
+            .ok_or_else(|| {
+                format!(
+                    "{}: {} has no section with `{}`",
+                    self.code, entry.id, self.item
+                )
+            })
+    }
+}
+
+fn defines(code: &str, item: &str) -> bool {
+    code.contains(&format!("fn {}(", item)) || code.contains(&format!("fn {}<", item))
+}
+
+// ============================================================================
+// Reading the Table
+// ============================================================================
+
+/// Every bundled explanation, in the order of the table.
+pub fn bundled() -> Result<Vec<Explanation>, String> {
+    parse(ERRORS).map_err(|e| format!("errors.txt: {}", e))
+}
+
+/// The explanation for `code`, which may be written "e0502" or "0502".
+pub fn find(code: &str) -> Result<Option<Explanation>, String> {
+    let code = normalize(code);
+    Ok(bundled()?.into_iter().find(|e| e.code == code))
+}
+
+/// "e502" and "0502" are both "E0502".
+pub fn normalize(code: &str) -> String {
+    let code = code.trim();
+    let digits = code.strip_prefix(['E', 'e']).unwrap_or(code);
+    match digits.parse::<u32>() {
+        Ok(n) if digits.bytes().all(|b| b.is_ascii_digit()) => format!("E{:04}", n),
+        _ => code.to_string(),
+    }
+}
+
+/// Reads the table:
+///
+/// ```text
+/// # comment
+/// == E0502 cannot borrow as mutable because ...   code and rustc's message
+/// see: mutable-borrowing demonstrate_borrow_rules lesson id and item
+/// You can have many & borrows or one &mut ...     the explanation
+/// fails:
+///     fn main() { ... }                           indented four spaces
+/// fix:
+///     fn main() { ... }
+/// ```
+pub fn parse(text: &str) -> Result<Vec<Explanation>, String> {
+    let mut explanations: Vec<Explanation> = Vec::new();
+    let mut block: Option<Block> = None; // set while reading a program
+
+    for (i, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", i + 1, message);
+
+        if let Some(header) = line.strip_prefix("== ") {
+            if let Some(last) = explanations.last() {
+                check(last).map_err(|e| error(&e))?;
+            }
+            let (code, message) = header
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| error("expected `== <code> <message>`"))?;
+            explanations.push(Explanation {
+                code: code.to_string(),
+                message: message.trim().to_string(),
+                lesson: String::new(),
+                item: String::new(),
+                text: String::new(),
+                fails: String::new(),
+                fix: String::new(),
+            });
+            block = None;
+            continue;
+        }
+        if line.starts_with('#') || (line.trim().is_empty() && explanations.is_empty()) {
+            continue;
+        }
+
+        let explanation = explanations
+            .last_mut()
+            .ok_or_else(|| error("expected a `== <code> <message>` line"))?;
+        let program = match block {
+            Some(Block::Fails) => Some(&mut explanation.fails),
+            Some(Block::Fix) => Some(&mut explanation.fix),
+            None => None,
+        };
+        if let Some(program) =
+            program.filter(|_| line.trim().is_empty() || line.starts_with("    "))
+        {
+            *program += line.get(4..).unwrap_or("");
+            program.push('\n');
+        } else if let Some(see) = line.strip_prefix("see:") {
+            let (lesson, item) = see
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| error("expected `see: <lesson> <item>`"))?;
+            explanation.lesson = lesson.to_string();
+            explanation.item = item.trim().to_string();
+        } else if line.trim() == "fails:" {
+            block = Some(Block::Fails);
+        } else if line.trim() == "fix:" {
+            block = Some(Block::Fix);
+        } else if block.is_none() {
+            explanation.text += line.trim();
+            explanation.text.push('\n');
+        } else {
+            return Err(error(&format!("can't read `{}`", line)));
+        }
+    }
+
+    if let Some(last) = explanations.last() {
+        check(last).map_err(|e| format!("at the end: {}", e))?;
+    }
+    for explanation in &mut explanations {
+        explanation.text = explanation.text.trim_end().to_string();
+        explanation.fails = explanation.fails.trim_end().to_string();
+        explanation.fix = explanation.fix.trim_end().to_string();
+    }
+    Ok(explanations)
+}
+
+#[derive(Clone, Copy)]
+enum Block {
+    Fails,
+    Fix,
+}
+
+// Checks an explanation has every part
+fn check(explanation: &Explanation) -> Result<(), String> {
+    let missing = if explanation.lesson.is_empty() {
+        "a `see: <lesson> <item>` line"
+    } else if explanation.text.trim().is_empty() {
+        "an explanation"
+    } else if explanation.fails.trim().is_empty() {
+        "a `fails:` program"
+    } else if explanation.fix.trim().is_empty() {
+        "a `fix:` program"
+    } else {
+        return Ok(());
+    };
+    Err(format!("{} is missing {}", explanation.code, missing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_bundled_table() {
+        let explanations = bundled().unwrap();
+        let codes: Vec<&str> = explanations.iter().map(|e| e.code.as_str()).collect();
+        assert_eq!(codes, ["E0382", "E0499", "E0502", "E0106", "E0596"]);
+
+        let e0502 = &explanations[2];
+        assert!(e0502.fails.starts_with("fn main() {\n    let mut s"));
+        assert!(e0502.text.ends_with("before you take the &mut."));
+        let e0106 = &explanations[3];
+        assert!(e0106.fails.contains("}\n\nfn main() {"));
+    }
+
+    #[test]
+    fn links_to_the_demonstrating_section() {
+        for explanation in bundled().unwrap() {
+            assert!(explanation.section().is_ok(), "{}", explanation.code);
+        }
+
+        let section = |code: &str| {
+            let (entry, index) = find(code).unwrap().unwrap().section().unwrap();
+            (entry.id, index)
+        };
+        assert_eq!(section("E0502"), ("mutable-borrowing", 2));
+        // longest is defined in section 1, not run by name
+        assert_eq!(section("E0106"), ("lifetimes", 0));
+    }
+
+    #[test]
+    fn finds_codes_however_written() {
+        assert_eq!(normalize("e502"), "E0502");
+        assert_eq!(normalize("0106"), "E0106");
+        assert_eq!(normalize("E0382"), "E0382");
+        assert_eq!(normalize("borrow"), "borrow");
+        assert!(find("e0499").unwrap().is_some());
+        assert!(find("E0001").unwrap().is_none());
+    }
+
+    #[test]
+    fn reports_incomplete_entries() {
+        let text = "== E0502 cannot borrow\nsee: borrowing x\nWhy.\nfails:\n    fn main() {}\n";
+        assert_eq!(
+            parse(text).unwrap_err(),
+            "at the end: E0502 is missing a `fix:` program"
+        );
+    }
+}
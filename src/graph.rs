@@ -0,0 +1,259 @@
+// Curriculum Graph
+// Lessons build on each other: borrowing on ownership, lifetimes on
+// borrowing. Each registered lesson names the ones it requires, and those
+// links must form a DAG that runs in curriculum order. Strict mode warns a
+// trainee who opens a lesson before finishing what it requires.
+
+use crate::lessons::{self, Entry};
+use crate::progress::Progress;
+use std::env;
+
+// ============================================================================
+// Checking the Registry
+// ============================================================================
+
+/// Checks every prerequisite is a registered lesson, that no lesson
+/// requires itself through others, and that each comes after what it
+/// requires.
+pub fn check(entries: &[Entry]) -> Result<(), String> {
+    let position = |id: &str| entries.iter().position(|e| e.id == id);
+    let mut requires: Vec<Vec<usize>> = Vec::new();
+    for entry in entries {
+        let mut indices = Vec::new();
+        for id in entry.requires {
+            let index = position(id).ok_or_else(|| {
+                format!(
+                    "lesson `{}` requires `{}`, which isn't registered",
+                    entry.id, id
+                )
+            })?;
+            indices.push(index);
+        }
+        requires.push(indices);
+    }
+
+    if let Some(cycle) = find_cycle(&requires) {
+        let ids: Vec<&str> = cycle.iter().map(|&i| entries[i].id).collect();
+        return Err(format!(
+            "lesson prerequisites form a cycle: {}",
+            ids.join(" requires ")
+        ));
+    }
+
+    for (i, indices) in requires.iter().enumerate() {
+        if let Some(&later) = indices.iter().find(|&&r| r > i) {
+            return Err(format!(
+                "lesson `{}` requires `{}`, which comes after it in the curriculum",
+                entries[i].id, entries[later].id
+            ));
+        }
+    }
+    Ok(())
+}
+
+// A depth-first search; a lesson met again while its own prerequisites are
+// still being walked closes a cycle. Returns it from that lesson back round
+// to itself.
+fn find_cycle(requires: &[Vec<usize>]) -> Option<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        Walking,
+        Done,
+    }
+
+    fn walk(
+        node: usize,
+        requires: &[Vec<usize>],
+        states: &mut [State],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        states[node] = State::Walking;
+        path.push(node);
+        for &next in &requires[node] {
+            match states[next] {
+                State::Walking => {
+                    let start = path.iter().position(|&n| n == next).unwrap();
+                    let mut cycle = path[start..].to_vec();
+                    cycle.push(next);
+                    return Some(cycle);
+                }
+                State::New => {
+                    if let Some(cycle) = walk(next, requires, states, path) {
+                        return Some(cycle);
+                    }
+                }
+                State::Done => {}
+            }
+        }
+        path.pop();
+        states[node] = State::Done;
+        None
+    }
+
+    let mut states = vec![State::New; requires.len()];
+    for node in 0..requires.len() {
+        if states[node] == State::New {
+            if let Some(cycle) = walk(node, requires, &mut states, &mut Vec::new()) {
+                return Some(cycle);
+            }
+        }
+    }
+    None
+}
+
+// ============================================================================
+// Strict Mode
+// ============================================================================
+
+/// Strict mode is on when $LEARN_RUST_STRICT is set to anything but "0".
+pub fn strict() -> bool {
+    env::var_os("LEARN_RUST_STRICT").is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// The lessons `entry` requires that the trainee hasn't finished.
+pub fn unfinished(progress: &Progress, entry: &Entry) -> Vec<&'static Entry> {
+    entry
+        .requires
+        .iter()
+        .filter_map(|id| lessons::find(id))
+        .filter(|required| !progress.has_finished(required))
+        .collect()
+}
+
+/// What strict mode says on opening `entry`, if anything.
+pub fn warning(progress: &Progress, entry: &Entry) -> Option<String> {
+    let unfinished = unfinished(progress, entry);
+    if !strict() || unfinished.is_empty() {
+        return None;
+    }
+    let ids: Vec<&str> = unfinished.iter().map(|e| e.id).collect();
+    Some(format!(
+        "Warning: {} builds on {}, which you haven't finished yet (see `learn status`)",
+        entry.id,
+        ids.join(" and ")
+    ))
+}
+
+// ============================================================================
+// Drawing the Graph
+// ============================================================================
+
+/// One line per lesson, in order, with the lessons it requires.
+pub fn text(entries: &[Entry]) -> String {
+    let mut text = String::new();
+    for entry in entries {
+        let after = if entry.requires.is_empty() {
+            String::from("start here")
+        } else {
+            format!("after {}", entry.requires.join(", "))
+        };
+        text += &format!("   {:<20} {}\n", entry.id, after);
+    }
+    text
+}
+
+/// The graph in Graphviz DOT, an arrow from each lesson to those that
+/// build on it.
+pub fn dot(entries: &[Entry]) -> String {
+    let mut dot = String::from("digraph curriculum {\n    rankdir=LR;\n    node [shape=box];\n");
+    for entry in entries {
+        dot += &format!(
+            "    \"{}\" [label=\"{}\"];\n",
+            entry.id,
+            entry.lesson.title.replace('"', "\\\"")
+        );
+    }
+    for entry in entries {
+        for required in entry.requires {
+            dot += &format!("    \"{}\" -> \"{}\";\n", required, entry.id);
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::CURRICULUM;
+
+    fn entry(id: &'static str, requires: &'static [&'static str]) -> Entry {
+        Entry {
+            id,
+            topic: "",
+            lesson: CURRICULUM[0].lesson,
+            file: "",
+            source: "",
+            requires,
+        }
+    }
+
+    #[test]
+    fn the_curriculum_is_a_dag_in_order() {
+        assert_eq!(check(&CURRICULUM), Ok(()));
+        assert!(CURRICULUM[0].requires.is_empty());
+    }
+
+    #[test]
+    fn finds_cycles_and_unknown_lessons() {
+        let entries = [
+            entry("a", &[]),
+            entry("b", &["a", "d"]),
+            entry("c", &["b"]),
+            entry("d", &["c"]),
+        ];
+        assert_eq!(
+            check(&entries).unwrap_err(),
+            "lesson prerequisites form a cycle: b requires d requires c requires b"
+        );
+        assert_eq!(
+            check(&[entry("a", &["a"])]).unwrap_err(),
+            "lesson prerequisites form a cycle: a requires a"
+        );
+        assert_eq!(
+            check(&[entry("a", &["z"])]).unwrap_err(),
+            "lesson `a` requires `z`, which isn't registered"
+        );
+        assert_eq!(
+            check(&[entry("a", &["b"]), entry("b", &[])]).unwrap_err(),
+            "lesson `a` requires `b`, which comes after it in the curriculum"
+        );
+    }
+
+    #[test]
+    fn lists_unfinished_prerequisites() {
+        let lifetimes = lessons::find("lifetimes").unwrap();
+        let borrowing = lessons::find("borrowing").unwrap();
+        let mut progress = Progress::default();
+        let ids = |progress: &Progress| -> Vec<&str> {
+            unfinished(progress, lifetimes)
+                .iter()
+                .map(|e| e.id)
+                .collect()
+        };
+        assert_eq!(ids(&progress), ["borrowing"]);
+
+        for section in 1..=borrowing.lesson.sections.len() {
+            progress.view("borrowing", section, 0);
+        }
+        for exercise in crate::exercises::for_lesson(borrowing) {
+            progress.pass(exercise.name, 0);
+        }
+        assert!(ids(&progress).is_empty());
+    }
+
+    #[test]
+    fn draws_text_and_dot() {
+        let text = text(&CURRICULUM);
+        assert!(text.starts_with("   ownership-basics     start here\n"));
+        assert!(text.contains("   advanced-lifetimes   after lifetimes\n"));
+
+        let dot = dot(&CURRICULUM);
+        assert!(dot.starts_with("digraph curriculum {\n"));
+        assert!(dot.contains("    \"borrowing\" [label=\"Borrowing (Immutable References)\"];\n"));
+        assert!(dot.contains("    \"borrowing\" -> \"lifetimes\";\n"));
+        assert_eq!(dot.matches(" -> ").count(), 8);
+        assert!(dot.ends_with("}\n"));
+    }
+}
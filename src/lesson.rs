@@ -0,0 +1,97 @@
+// Lesson structure and the lesson! macro
+// Every lesson is a title, numbered sections and a list of key takeaways,
+// which may be empty
+
+// ============================================================================
+// Lesson Data
+// ============================================================================
+
+/// One numbered section: a heading and the function that demonstrates it.
+pub struct Section {
+    pub title: &'static str,
+    pub run: fn(),
+    pub function: &'static str, // the name of `run`, to find its source
+}
+
+/// A whole lesson, built by `lesson!` as a `static`.
+pub struct Lesson {
+    pub title: &'static str,
+    pub sections: &'static [Section],
+    pub takeaways: &'static [&'static str],
+}
+
+impl Lesson {
+    /// Prints the banner, every section in order, then any takeaways.
+    pub fn run(&self) {
+        println!("=== {} ===\n", self.title);
+
+        for i in 0..self.sections.len() {
+            if i > 0 {
+                println!();
+            }
+            self.run_section(i);
+        }
+
+        if !self.takeaways.is_empty() {
+            println!("\n=== Key Takeaways ===");
+            for takeaway in self.takeaways {
+                println!("• {}", takeaway);
+            }
+        }
+    }
+
+    /// Prints one section by index, numbered as in the full lesson.
+    pub fn run_section(&self, index: usize) {
+        let section = &self.sections[index];
+        println!("{}. {}:", index + 1, section.title);
+        (section.run)();
+    }
+}
+
+// ============================================================================
+// The lesson! Macro
+// ============================================================================
+
+/// Declares `pub static LESSON: Lesson` from a title, sections and takeaways.
+///
+/// ```
+/// use learn_rust::lesson;
+///
+/// fn demonstrate_hello() {
+///     println!("   hello");
+/// }
+///
+/// lesson! {
+///     title: "Hello",
+///     sections: [
+///         "Saying Hello" => demonstrate_hello,
+///     ],
+///     takeaways: [
+///         "Macros write code for you",
+///     ],
+/// }
+///
+/// assert_eq!(LESSON.sections.len(), 1);
+/// LESSON.run();
+/// ```
+#[macro_export]
+macro_rules! lesson {
+    (
+        title: $title:literal,
+        sections: [ $( $heading:literal => $demo:path ),+ $(,)? ],
+        takeaways: [ $( $takeaway:literal ),* $(,)? ] $(,)?
+    ) => {
+        // $crate always names this library, wherever the macro is used
+        pub static LESSON: $crate::lesson::Lesson = $crate::lesson::Lesson {
+            title: $title,
+            sections: &[
+                $( $crate::lesson::Section {
+                    title: $heading,
+                    run: $demo,
+                    function: stringify!($demo),
+                } ),+
+            ],
+            takeaways: &[ $( $takeaway ),* ],
+        };
+    };
+}
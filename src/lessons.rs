@@ -0,0 +1,215 @@
+// Lesson Registry
+// Every lesson in the curriculum, in the order a trainee should take them
+
+use crate::lesson::Lesson;
+
+#[path = "../examples/ownership/01_ownership_basics.rs"]
+mod basics;
+
+#[path = "../examples/ownership/02_borrowing.rs"]
+mod borrowing;
+
+#[path = "../examples/ownership/03_mutable_borrowing.rs"]
+mod mutable_borrowing;
+
+#[path = "../examples/ownership/04_string_types.rs"]
+mod string_types;
+
+#[path = "../examples/ownership/05_lifetimes.rs"]
+mod lifetimes;
+
+#[path = "../examples/ownership/06_advanced_lifetimes.rs"]
+mod advanced_lifetimes;
+
+// These three are also standalone examples; their main() is unused here
+#[path = "../examples/dereference_examples.rs"]
+#[allow(dead_code)]
+mod dereference;
+
+#[path = "../examples/option_examples.rs"]
+#[allow(dead_code)]
+mod option;
+
+#[path = "../examples/result_examples.rs"]
+#[allow(dead_code)]
+mod result;
+
+/// Lesson helpers for trainee code to call, as playground snippets do.
+pub mod prelude {
+    pub use super::borrowing::{calculate_length, sum_vector};
+    pub use super::lifetimes::{first_word, longest};
+    pub use super::mutable_borrowing::{add_world, double_values};
+    pub use super::option::{find_user, get_username};
+    pub use super::result::{divide, parse_and_double, read_file_contents};
+    pub use super::string_types::{create_greeting, get_first_word};
+}
+
+/// A lesson with the short id used on the command line, the topic it is
+/// summarised under, the file it comes from and the lessons it builds on.
+pub struct Entry {
+    pub id: &'static str,
+    pub topic: &'static str,
+    pub lesson: &'static Lesson,
+    pub file: &'static str, // relative to the crate root
+    pub source: &'static str,
+    pub requires: &'static [&'static str], // ids of the lessons to finish first
+}
+
+macro_rules! entry {
+    ($id:literal, $topic:literal, $module:ident, $file:literal, [$($requires:literal),*]) => {
+        Entry {
+            id: $id,
+            topic: $topic,
+            lesson: &$module::LESSON,
+            file: concat!("examples/", $file),
+            source: include_str!(concat!("../examples/", $file)),
+            requires: &[$($requires),*],
+        }
+    };
+}
+
+/// Curriculum order: ownership basics first, Result last. Every lesson
+/// comes after the ones it requires; `graph::check` holds it to that.
+pub static CURRICULUM: [Entry; 9] = [
+    entry!(
+        "ownership-basics",
+        "Ownership",
+        basics,
+        "ownership/01_ownership_basics.rs",
+        []
+    ),
+    entry!(
+        "borrowing",
+        "Borrowing",
+        borrowing,
+        "ownership/02_borrowing.rs",
+        ["ownership-basics"]
+    ),
+    entry!(
+        "mutable-borrowing",
+        "Borrowing",
+        mutable_borrowing,
+        "ownership/03_mutable_borrowing.rs",
+        ["borrowing"]
+    ),
+    entry!(
+        "string-types",
+        "Borrowing",
+        string_types,
+        "ownership/04_string_types.rs",
+        ["borrowing"]
+    ),
+    entry!(
+        "lifetimes",
+        "Lifetimes",
+        lifetimes,
+        "ownership/05_lifetimes.rs",
+        ["borrowing"]
+    ),
+    entry!(
+        "advanced-lifetimes",
+        "Lifetimes",
+        advanced_lifetimes,
+        "ownership/06_advanced_lifetimes.rs",
+        ["lifetimes"]
+    ),
+    entry!(
+        "dereference",
+        "Dereference",
+        dereference,
+        "dereference_examples.rs",
+        ["borrowing"]
+    ),
+    entry!(
+        "option",
+        "Option",
+        option,
+        "option_examples.rs",
+        ["ownership-basics"]
+    ),
+    entry!("result", "Result", result, "result_examples.rs", ["option"]),
+];
+
+/// The ownership lessons, numbered 1-6 by the ownership runner.
+pub fn ownership() -> &'static [Entry] {
+    &CURRICULUM[..6]
+}
+
+/// Looks a lesson up by id or by its number in the curriculum.
+pub fn find(name: &str) -> Option<&'static Entry> {
+    CURRICULUM.iter().find(|e| e.id == name).or_else(|| {
+        name.parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| CURRICULUM.get(i))
+    })
+}
+
+/// Looks a lesson up by its title, as exercises and hints refer to it.
+pub fn find_by_title(title: &str) -> Option<&'static Entry> {
+    CURRICULUM.iter().find(|e| e.lesson.title == title)
+}
+
+/// Topics in curriculum order, each with its lessons.
+pub fn topics() -> Vec<(&'static str, Vec<&'static Entry>)> {
+    let mut topics: Vec<(&'static str, Vec<&'static Entry>)> = Vec::new();
+    for entry in &CURRICULUM {
+        match topics.last_mut() {
+            Some((topic, entries)) if *topic == entry.topic => entries.push(entry),
+            _ => topics.push((entry.topic, vec![entry])),
+        }
+    }
+    topics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercises::EXERCISES;
+
+    #[test]
+    fn ids_are_unique_and_findable() {
+        for (i, entry) in CURRICULUM.iter().enumerate() {
+            assert!(std::ptr::eq(find(entry.id).unwrap(), entry));
+            assert!(std::ptr::eq(find(&(i + 1).to_string()).unwrap(), entry));
+        }
+        assert!(find("0").is_none());
+        assert_eq!(topics().len(), 6);
+    }
+
+    #[test]
+    fn exercises_and_hints_point_at_real_sections() {
+        for exercise in &EXERCISES {
+            let entry = find_by_title(exercise.lesson)
+                .unwrap_or_else(|| panic!("{}: no lesson `{}`", exercise.name, exercise.lesson));
+            assert!(
+                entry
+                    .lesson
+                    .sections
+                    .iter()
+                    .any(|s| s.title == exercise.section),
+                "{}: no section `{}`",
+                exercise.name,
+                exercise.section
+            );
+
+            for hint in exercise.hints() {
+                let entry = find_by_title(&hint.lesson)
+                    .unwrap_or_else(|| panic!("{}: no lesson `{}`", exercise.name, hint.lesson));
+                let numbered: Vec<String> = entry
+                    .lesson
+                    .sections
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| format!("{}. {}", i + 1, s.title))
+                    .collect();
+                assert!(
+                    numbered.contains(&hint.section),
+                    "{}: no section `{}`",
+                    exercise.name,
+                    hint.section
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Shared infrastructure for the lesson examples
+// The lesson! macro is taught in examples/macro_examples.rs
+
+// The lesson files say `use learn_rust::lesson;`, which must also work when
+// the registry compiles them into this crate
+extern crate self as learn_rust;
+
+pub mod book;
+pub mod deck;
+pub mod events;
+pub mod exercises;
+pub mod explain;
+pub mod graph;
+pub mod lesson;
+pub mod lessons;
+pub mod lineedit;
+pub mod minilang;
+pub mod play;
+pub mod progress;
+pub mod quiz;
+pub mod review;
+pub mod serve;
+pub mod timeline;
+pub mod tui;
+pub mod watch;
@@ -0,0 +1,439 @@
+// Line Editing
+// A prompt with history and tab completion for the interactive runners. At a
+// terminal it edits in raw mode; otherwise it reads plain lines, so piped
+// input still works.
+//
+//     ← → Home End     move            ↑ ↓        history
+//     Backspace Del    delete          Ctrl+U     delete to the start
+//     Tab              complete        Ctrl+C     abandon the line
+//     Ctrl+D           end of input, on an empty line
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+const HISTORY_LIMIT: usize = 1000;
+
+/// Offers every word that could come next, given the line so far.
+pub type Completer = Box<dyn Fn(&str) -> Vec<String>>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Edit {
+    Char(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Up,
+    Down,
+    Tab,
+    KillLine,
+    Enter,
+    Interrupt,
+    Eof,
+}
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// The edits in one read from the terminal.
+pub fn parse_edits(bytes: &[u8]) -> Vec<Edit> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut edits = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let edit = match c {
+            '\x1b' if chars.peek() == Some(&'[') || chars.peek() == Some(&'O') => {
+                chars.next();
+                // Parameters, then the final letter or ~
+                let mut sequence = String::new();
+                for c in chars.by_ref() {
+                    sequence.push(c);
+                    if c.is_ascii_alphabetic() || c == '~' {
+                        break;
+                    }
+                }
+                match sequence.as_str() {
+                    "A" => Some(Edit::Up),
+                    "B" => Some(Edit::Down),
+                    "C" => Some(Edit::Right),
+                    "D" => Some(Edit::Left),
+                    "H" | "1~" | "7~" => Some(Edit::Home),
+                    "F" | "4~" | "8~" => Some(Edit::End),
+                    "3~" => Some(Edit::Delete),
+                    _ => None,
+                }
+            }
+            '\r' | '\n' => Some(Edit::Enter),
+            '\t' => Some(Edit::Tab),
+            '\x7f' | '\x08' => Some(Edit::Backspace),
+            '\x01' => Some(Edit::Home),
+            '\x05' => Some(Edit::End),
+            '\x02' => Some(Edit::Left),
+            '\x06' => Some(Edit::Right),
+            '\x15' => Some(Edit::KillLine),
+            '\x03' => Some(Edit::Interrupt),
+            '\x04' => Some(Edit::Eof),
+            c if c.is_control() => None,
+            c => Some(Edit::Char(c)),
+        };
+        edits.extend(edit);
+    }
+    edits
+}
+
+// ============================================================================
+// The Line Being Edited
+// ============================================================================
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Line {
+    pub text: Vec<char>,
+    pub cursor: usize,
+}
+
+impl Line {
+    pub fn as_string(&self) -> String {
+        self.text.iter().collect()
+    }
+
+    fn set(&mut self, text: &str) {
+        self.text = text.chars().collect();
+        self.cursor = self.text.len();
+    }
+
+    /// Applies a movement or deletion; other edits are left to the editor.
+    pub fn apply(&mut self, edit: Edit) {
+        match edit {
+            Edit::Char(c) => {
+                self.text.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Edit::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.text.remove(self.cursor);
+            }
+            Edit::Delete if self.cursor < self.text.len() => {
+                self.text.remove(self.cursor);
+            }
+            Edit::Left => self.cursor = self.cursor.saturating_sub(1),
+            Edit::Right => self.cursor = (self.cursor + 1).min(self.text.len()),
+            Edit::Home => self.cursor = 0,
+            Edit::End => self.cursor = self.text.len(),
+            Edit::KillLine => {
+                self.text.drain(..self.cursor);
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Completes the word before the cursor from `candidates`, as far as
+    /// they agree. Returns the candidates that fit when that leaves a choice.
+    pub fn complete(&mut self, candidates: &[String]) -> Vec<String> {
+        let before: String = self.text[..self.cursor].iter().collect();
+        let start = before.rfind(' ').map_or(0, |i| i + 1);
+        let word = &before[start..];
+        let fits: Vec<String> = candidates
+            .iter()
+            .filter(|c| c.starts_with(word))
+            .cloned()
+            .collect();
+        let Some(first) = fits.first() else {
+            return Vec::new();
+        };
+
+        let mut common = first.clone();
+        for fit in &fits[1..] {
+            let len = common
+                .chars()
+                .zip(fit.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            common = common.chars().take(len).collect();
+        }
+        let mut insert: Vec<char> = common.chars().skip(word.chars().count()).collect();
+        if fits.len() == 1 {
+            insert.push(' ');
+        }
+        let added = insert.len();
+        self.text.splice(self.cursor..self.cursor, insert);
+        self.cursor += added;
+
+        if fits.len() > 1 && added == 0 {
+            fits
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+// ============================================================================
+// The Editor
+// ============================================================================
+
+pub struct Editor {
+    history: Vec<String>,
+    path: Option<PathBuf>, // where history is kept between runs
+    completer: Completer,
+}
+
+impl Editor {
+    /// An editor whose history lives in `path`, if given.
+    pub fn new(path: Option<PathBuf>, completer: Completer) -> Editor {
+        let history = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| text.lines().map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let skip = history.len().saturating_sub(HISTORY_LIMIT);
+        Editor {
+            history: history.into_iter().skip(skip).collect(),
+            path,
+            completer,
+        }
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Adds a line to the history and appends it to the history file.
+    pub fn remember(&mut self, line: &str) -> Result<(), String> {
+        let line = line.trim();
+        if line.is_empty() || self.history.last().is_some_and(|last| last == line) {
+            return Ok(());
+        }
+        self.history.push(line.to_string());
+
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Reads a line after showing `prompt`. None at the end of input.
+    pub fn read_line(&mut self, prompt: &str) -> Option<String> {
+        print!("{}", prompt);
+        io::stdout().flush().ok();
+
+        #[cfg(unix)]
+        if io::stdin().is_terminal() {
+            if let Ok(raw) = crate::tui::raw::RawMode::enable() {
+                let line = self.edit(prompt);
+                drop(raw);
+                println!();
+                return line;
+            }
+        }
+
+        let mut line = String::new();
+        match io::stdin().lock().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    // Raw mode is on, so "\n" doesn't return the carriage by itself
+    #[cfg(unix)]
+    fn edit(&mut self, prompt: &str) -> Option<String> {
+        use std::io::Read;
+
+        let mut line = Line::default();
+        let mut recall = self.history.len(); // history.len() is the new line
+        let mut draft = String::new();
+        let mut buf = [0u8; 64];
+        let mut out = io::stdout();
+
+        loop {
+            let n = io::stdin().read(&mut buf).ok()?;
+            if n == 0 {
+                return None;
+            }
+            for edit in parse_edits(&buf[..n]) {
+                match edit {
+                    Edit::Enter => return Some(line.as_string()),
+                    Edit::Eof if line.text.is_empty() => return None,
+                    Edit::Interrupt => {
+                        write!(out, "^C\r\n{}", prompt).ok();
+                        line = Line::default();
+                        recall = self.history.len();
+                    }
+                    Edit::Up if recall > 0 => {
+                        if recall == self.history.len() {
+                            draft = line.as_string();
+                        }
+                        recall -= 1;
+                        line.set(&self.history[recall]);
+                    }
+                    Edit::Down if recall < self.history.len() => {
+                        recall += 1;
+                        match self.history.get(recall) {
+                            Some(entry) => line.set(entry),
+                            None => line.set(&draft),
+                        }
+                    }
+                    Edit::Tab => {
+                        let before: String = line.text[..line.cursor].iter().collect();
+                        let choices = line.complete(&(self.completer)(&before));
+                        if !choices.is_empty() {
+                            write!(out, "\r\n{}\r\n", choices.join("  ")).ok();
+                        }
+                    }
+                    edit => line.apply(edit),
+                }
+            }
+
+            let after = line.text.len() - line.cursor;
+            write!(out, "\r{}{}\x1b[K", prompt, line.as_string()).ok();
+            if after > 0 {
+                write!(out, "\x1b[{}D", after).ok();
+            }
+            out.flush().ok();
+        }
+    }
+}
+
+// ============================================================================
+// Typos
+// ============================================================================
+
+// Levenshtein distance, counted in characters
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = previous + usize::from(ca != cb);
+            previous = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The candidate `word` was most likely meant to be: the only one it starts,
+/// or the nearest within a typo or two.
+pub fn closest<'a>(word: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let word = word.to_lowercase();
+    let prefixed: Vec<&str> = candidates
+        .iter()
+        .copied()
+        .filter(|c| c.starts_with(&word))
+        .collect();
+    if let [only] = prefixed.as_slice() {
+        return Some(only);
+    }
+
+    let allowed = (word.chars().count() / 3).clamp(1, 3);
+    candidates
+        .iter()
+        .map(|c| (distance(&word, c), *c))
+        .filter(|(d, _)| *d <= allowed)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_keys_and_escape_sequences() {
+        assert_eq!(
+            parse_edits("r\x1b[A\x1b[3~\x1b[1~é\t\r".as_bytes()),
+            [
+                Edit::Char('r'),
+                Edit::Up,
+                Edit::Delete,
+                Edit::Home,
+                Edit::Char('é'),
+                Edit::Tab,
+                Edit::Enter
+            ]
+        );
+        assert_eq!(
+            parse_edits(b"\x7f\x15\x03\x04"),
+            [Edit::Backspace, Edit::KillLine, Edit::Interrupt, Edit::Eof]
+        );
+    }
+
+    #[test]
+    fn edits_in_the_middle_of_a_line() {
+        let mut line = Line::default();
+        for c in "rn 3".chars() {
+            line.apply(Edit::Char(c));
+        }
+        line.apply(Edit::Home);
+        line.apply(Edit::Right);
+        line.apply(Edit::Char('u'));
+        assert_eq!(line.as_string(), "run 3");
+        line.apply(Edit::End);
+        line.apply(Edit::Backspace);
+        line.apply(Edit::Left);
+        line.apply(Edit::KillLine);
+        assert_eq!((line.as_string(), line.cursor), (String::from(" "), 0));
+    }
+
+    #[test]
+    fn completes_as_far_as_the_candidates_agree() {
+        let ids: Vec<String> = ["lifetimes", "advanced-lifetimes", "borrowing"]
+            .map(String::from)
+            .to_vec();
+        let mut line = Line::default();
+        line.set("run b");
+        assert!(line.complete(&ids).is_empty());
+        assert_eq!(line.as_string(), "run borrowing ");
+
+        let both: Vec<String> = ["mutable-borrowing", "move"].map(String::from).to_vec();
+        line.set("run m");
+        assert_eq!(line.complete(&both), both);
+        assert_eq!(line.as_string(), "run m");
+        line.set("run mu");
+        line.complete(&both);
+        assert_eq!(line.as_string(), "run mutable-borrowing ");
+    }
+
+    #[test]
+    fn keeps_history_in_a_file() {
+        let path = std::env::temp_dir().join(format!("learn-history-{}", std::process::id()));
+        fs::remove_file(&path).ok();
+        let mut editor = Editor::new(Some(path.clone()), Box::new(|_| Vec::new()));
+        editor.remember("run 3").unwrap();
+        editor.remember("run 3").unwrap();
+        editor.remember("  ").unwrap();
+        editor.remember("explain 5").unwrap();
+
+        let editor = Editor::new(Some(path.clone()), Box::new(|_| Vec::new()));
+        assert_eq!(editor.history(), ["run 3", "explain 5"]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn guesses_what_a_typo_meant() {
+        let ids = [
+            "lifetimes",
+            "advanced-lifetimes",
+            "borrowing",
+            "mutable-borrowing",
+        ];
+        assert_eq!(closest("lifetims", &ids), Some("lifetimes"));
+        assert_eq!(closest("Borowing", &ids), Some("borrowing"));
+        assert_eq!(closest("adv", &ids), Some("advanced-lifetimes"));
+        assert_eq!(closest("quit", &ids), None);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+}
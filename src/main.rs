@@ -1,8 +1,764 @@
-fn main() {
+// learn - command line front end for the lessons and exercises
+// Run with: cargo run --bin learn -- <command>
+
+use learn_rust::book;
+use learn_rust::deck::{self, Separator};
+use learn_rust::exercises::{self, Exercise, Hint, Outcome};
+use learn_rust::explain;
+use learn_rust::graph;
+use learn_rust::lessons::{self, Entry};
+use learn_rust::play::{self, Limits};
+use learn_rust::progress::{self, Progress};
+use learn_rust::quiz;
+use learn_rust::review::{self, Grade};
+use learn_rust::watch;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::thread;
+use std::time::Duration;
+
+fn print_usage() {
     println!("Rust Learning Examples");
     println!("======================");
     println!();
     println!("Run examples with:");
     println!("  cargo run --example result_examples");
     println!("  cargo run --example option_examples");
+    println!("  cargo run --example iterator_examples");
+    println!("  cargo run --example collection_examples");
+    println!("  cargo run --example pattern_examples");
+    println!("  cargo run --example macro_examples");
+    println!("  cargo run --example unsafe_examples");
+    println!("  cargo run --example ffi_examples");
+    println!("  cargo run --example borrow_timeline");
+    println!("  cargo run --example borrowck_repl");
+    println!();
+    println!("Lessons:");
+    println!("  cargo run --bin learn -- run <lesson> [n]    run a lesson, or just its section n");
+    println!("  cargo run --bin learn -- status              what you've done so far");
+    println!("  cargo run --bin learn -- next                what to do next");
+    println!("  cargo run --bin learn -- output <lesson> [n] print it without recording it");
+    println!("  cargo run --bin learn -- graph [--format text|dot]");
+    println!("                                               which lessons build on which");
+    println!(
+        "  cargo run --bin learn -- watch <lesson>      rebuild and re-run a lesson on every save"
+    );
+    println!("  cargo run --bin learn -- export --format md|html <dir>");
+    println!("                                               write the lessons out as a book");
+    println!("  cargo run --bin learn -- export --format tsv|csv <dir>");
+    println!("                                               Anki decks of takeaways and quizzes");
+    println!(
+        "  cargo run --bin learn -- serve [--port N]    browse and run the lessons at localhost"
+    );
+    println!("  cargo run --bin learn -- play [--limit SECONDS] <file.rs>");
+    println!("                                               run a snippet against the lessons");
+    println!();
+    println!("Set LEARN_RUST_STRICT=1 to be warned when a lesson's prerequisites aren't done.");
+    println!();
+    println!("Quizzes:");
+    println!("  cargo run --bin learn -- quiz [lesson]       predict output, spot compile errors");
+    println!(
+        "  cargo run --bin learn -- quiz --bank <file>  ask the questions in your own bank file"
+    );
+    println!(
+        "  cargo run --bin learn -- review              flashcards of the takeaways due today"
+    );
+    println!();
+    println!("Exercises:");
+    println!("  cargo run --bin learn -- list                list the exercises");
+    println!("  cargo run --bin learn -- check <exercise>    test your exercises/<exercise>.rs");
+    println!("  cargo run --bin learn -- hint <exercise>     reveal the next hint");
+    println!("  cargo run --bin learn -- explain <code>      what a rustc error like E0502 means");
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+fn list() -> Result<bool, String> {
+    let mut lesson = "";
+    for exercise in &exercises::EXERCISES {
+        if exercise.lesson != lesson {
+            lesson = exercise.lesson;
+            println!("\n=== {} ===", lesson);
+        }
+        println!("   {:<20} {}", exercise.name, exercise.task);
+    }
+    Ok(true)
+}
+
+fn check(args: &[String]) -> Result<bool, String> {
+    let name = args.first().ok_or("usage: learn check <exercise> [file]")?;
+    let exercise = exercises::find(name)
+        .ok_or_else(|| format!("no exercise named `{}` (try `learn list`)", name))?;
+    let path = args.get(1).map_or_else(|| exercise.path(), PathBuf::from);
+
+    println!(
+        "Checking {} ({} - {})",
+        path.display(),
+        exercise.lesson,
+        exercise.section
+    );
+    let outcome = exercises::check_file(exercise, &path)?;
+
+    match &outcome {
+        Outcome::CompileError(errors) => {
+            println!("\nDoesn't compile yet:\n");
+            print!("{}", errors);
+
+            // Suggest the hint written for this compiler error
+            if let Some((index, hint)) = exercise.hint_for_errors(errors) {
+                println!();
+                print_hint(exercise, index, &hint);
+                let mut progress = Progress::load()?;
+                progress.use_hints(exercise.name, index + 1);
+                progress.save()?;
+            }
+        }
+        Outcome::TimedOut => println!("\nThe tests didn't finish - is there an endless loop?"),
+        Outcome::Tested(results) => {
+            println!();
+            for result in results {
+                let mark = if result.passed { "PASS" } else { "FAIL" };
+                println!("   {} {}", mark, result.name);
+                for line in result.message.lines() {
+                    println!("        {}", line);
+                }
+            }
+            let passed = results.iter().filter(|r| r.passed).count();
+            println!("\n{}/{} tests passed", passed, results.len());
+        }
+    }
+
+    if outcome.passed() {
+        println!("Exercise complete!");
+        let mut progress = Progress::load()?;
+        progress.pass(exercise.name, progress::now());
+        progress.save()?;
+    }
+    Ok(outcome.passed())
+}
+
+fn print_hint(exercise: &Exercise, index: usize, hint: &Hint) {
+    let code = hint
+        .code
+        .as_ref()
+        .map(|c| format!(", {}", c))
+        .unwrap_or_default();
+    println!("Hint {}/{}{}:", index + 1, exercise.hints().len(), code);
+    for line in hint.text.lines() {
+        println!("   {}", line);
+    }
+    println!("   See: {}, {}", hint.lesson, hint.section);
+}
+
+fn hint(args: &[String]) -> Result<bool, String> {
+    let name = args.first().ok_or("usage: learn hint <exercise>")?;
+    let exercise = exercises::find(name)
+        .ok_or_else(|| format!("no exercise named `{}` (try `learn list`)", name))?;
+    let hints = exercise.hints();
+    if hints.is_empty() {
+        println!("There are no hints for this exercise.");
+        return Ok(true);
+    }
+
+    let mut progress = Progress::load()?;
+    let used = progress.hints_used(exercise.name);
+    if used >= hints.len() {
+        println!(
+            "No more hints for {} - here is the last one again:\n",
+            exercise.name
+        );
+    }
+
+    let index = used.min(hints.len() - 1);
+    print_hint(exercise, index, &hints[index]);
+    progress.use_hints(exercise.name, index + 1);
+    progress.save()?;
+    Ok(true)
+}
+
+fn explain(args: &[String]) -> Result<bool, String> {
+    let explanations = explain::bundled()?;
+    let codes: Vec<&str> = explanations.iter().map(|e| e.code.as_str()).collect();
+    let Some(code) = args.first() else {
+        return Err(format!(
+            "usage: learn explain <code>, one of {}",
+            codes.join(", ")
+        ));
+    };
+    let Some(explanation) = explain::find(code)? else {
+        return Err(format!(
+            "no explanation for {} (try `rustc --explain {}`); there are ones for {}",
+            code,
+            explain::normalize(code),
+            codes.join(", ")
+        ));
+    };
+    let (entry, index) = explanation.section()?;
+    let section = &entry.lesson.sections[index];
+
+    println!("{}: {}\n", explanation.code, explanation.message);
+    for line in explanation.text.lines() {
+        println!("   {}", line);
+    }
+    println!(
+        "\nSee {}, section {}. {} (`{}`):",
+        entry.lesson.title,
+        index + 1,
+        section.title,
+        explanation.item
+    );
+    println!("   cargo run --bin learn -- run {} {}", entry.id, index + 1);
+    println!("\nThis fails with {}:\n", explanation.code);
+    print_program(&explanation.fails);
+    println!("\nThis compiles:\n");
+    print_program(&explanation.fix);
+    Ok(true)
+}
+
+fn print_program(program: &str) {
+    for line in program.lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {}", line);
+        }
+    }
+}
+
+fn find_lesson(name: &str) -> Result<&'static Entry, String> {
+    lessons::find(name).ok_or_else(|| format!("no lesson named `{}` (try `learn status`)", name))
+}
+
+// "3" is the third section, index 2
+fn section_index(entry: &Entry, n: &str) -> Result<usize, String> {
+    let sections = entry.lesson.sections.len();
+    n.parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .filter(|&i| i < sections)
+        .ok_or_else(|| format!("{} has sections 1-{}", entry.id, sections))
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    let name = args.first().ok_or("usage: learn run <lesson> [section]")?;
+    let entry = find_lesson(name)?;
+    let sections = entry.lesson.sections.len();
+
+    let mut progress = Progress::load()?;
+    if let Some(warning) = graph::warning(&progress, entry) {
+        eprintln!("{}\n", warning);
+    }
+    match args.get(1) {
+        Some(n) => {
+            let index = section_index(entry, n)?;
+            entry.lesson.run_section(index);
+            progress.view(entry.id, index + 1, progress::now());
+        }
+        None => {
+            entry.lesson.run();
+            for section in 1..=sections {
+                progress.view(entry.id, section, progress::now());
+            }
+        }
+    }
+    progress.save()?;
+    Ok(true)
+}
+
+fn output(args: &[String]) -> Result<bool, String> {
+    let Some(name) = args.first() else {
+        return Err(String::from("usage: learn output <lesson> [section]"));
+    };
+    let entry = find_lesson(name)?;
+    match args.get(1) {
+        Some(n) => entry.lesson.run_section(section_index(entry, n)?),
+        None => entry.lesson.run(),
+    }
+    Ok(true)
+}
+
+fn graph(args: &[String]) -> Result<bool, String> {
+    let format = match args {
+        [] => "text",
+        [flag, format] if flag == "--format" => format.as_str(),
+        _ => return Err(String::from("usage: learn graph [--format text|dot]")),
+    };
+    match format {
+        "text" => {
+            println!(
+                "Curriculum ({} lessons, each after the ones it builds on)\n",
+                lessons::CURRICULUM.len()
+            );
+            print!("{}", graph::text(&lessons::CURRICULUM));
+        }
+        "dot" => print!("{}", graph::dot(&lessons::CURRICULUM)),
+        other => {
+            return Err(format!(
+                "unknown graph format `{}` (use text or dot)",
+                other
+            ))
+        }
+    }
+    Ok(true)
+}
+
+// Sections print straight to stdout, so their output is read by running
+// `learn output` in a child process
+fn section_output(lesson: &str, section: usize) -> Result<String, String> {
+    let exe = env::current_exe().map_err(|e| e.to_string())?;
+    let output = Command::new(exe)
+        .args(["output", lesson, &section.to_string()])
+        .output()
+        .map_err(|e| format!("failed to run {} {}: {}", lesson, section, e))?;
+    if !output.status.success() {
+        return Err(format!("{} section {} failed to run", lesson, section));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn play(args: &[String]) -> Result<bool, String> {
+    let usage = "usage: learn play [--limit SECONDS] <file.rs>";
+    let (limits, file) = match args {
+        [file] => (Limits::default(), file),
+        [flag, seconds, file] if flag == "--limit" => {
+            let seconds = seconds
+                .parse::<u64>()
+                .ok()
+                .filter(|&s| s > 0)
+                .ok_or_else(|| format!("bad limit `{}`", seconds))?;
+            (Limits::seconds(seconds), file)
+        }
+        _ => return Err(String::from(usage)),
+    };
+    let snippet = fs::read_to_string(file).map_err(|e| format!("{}: {}", file, e))?;
+
+    let run = match play::play(&snippet, file, limits)? {
+        play::Outcome::CompileError(errors) => {
+            eprint!("{}", errors);
+            for code in exercises::error_codes(&errors) {
+                if explain::find(&code)?.is_some() {
+                    eprintln!(
+                        "For {}, see: cargo run --bin learn -- explain {}",
+                        code, code
+                    );
+                }
+            }
+            return Ok(false);
+        }
+        play::Outcome::Ran(run) => run,
+    };
+    eprint!("{}", run.warnings);
+    print!("{}", run.stdout);
+    eprint!("{}", run.stderr);
+    if let Some(reason) = &run.killed {
+        eprintln!("Stopped: the snippet {}", reason);
+    }
+    Ok(run.code == Some(0))
+}
+
+// Rebuilds and re-runs the lesson after each save, showing what changed
+fn watch(args: &[String]) -> Result<bool, String> {
+    let [name] = args else {
+        return Err(String::from("usage: learn watch <lesson>"));
+    };
+    let entry = find_lesson(name)?;
+    let path = watch::source_path(entry);
+    watch::modified(&path)?;
+    println!("Watching {} (Ctrl+C to stop)", path.display());
+
+    let mut seen = None;
+    let mut previous: Option<String> = None;
+    let mut runs = 0;
+    loop {
+        // Missing for a moment while an editor renames its temp file over it
+        let Ok(modified) = watch::modified(&path) else {
+            thread::sleep(Duration::from_millis(300));
+            continue;
+        };
+        if seen == Some(modified) {
+            thread::sleep(Duration::from_millis(300));
+            continue;
+        }
+        seen = Some(modified);
+        runs += 1;
+        // Editors can write a file in several steps
+        thread::sleep(Duration::from_millis(100));
+
+        println!("\n=== Run {}: {} ===", runs, entry.lesson.title);
+        let output = watch::rebuild().and_then(|exe| watch::lesson_output(&exe, entry));
+        match (output, &previous) {
+            (Err(e), _) => println!("{}\n\nWaiting for the next save...", e),
+            (Ok(output), None) => {
+                print!("{}", output);
+                previous = Some(output);
+            }
+            (Ok(output), Some(old)) if *old == output => {
+                println!("No change in the output");
+            }
+            (Ok(output), Some(old)) => {
+                print!(
+                    "{}",
+                    watch::render(&watch::diff(old, &output), watch::CONTEXT)
+                );
+                previous = Some(output);
+            }
+        }
+        io::stdout().flush().ok();
+    }
+}
+
+fn export(args: &[String]) -> Result<bool, String> {
+    let usage = "usage: learn export --format md|html|tsv|csv <dir>";
+    let [flag, format, dir] = args else {
+        return Err(String::from(usage));
+    };
+    if flag != "--format" {
+        return Err(String::from(usage));
+    }
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    if let Some(separator) = Separator::parse(format) {
+        return export_decks(separator, &dir);
+    }
+    let format = book::Format::parse(format)?;
+
+    let write = |name: String, text: String| {
+        let path = dir.join(name);
+        fs::write(&path, text).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+    for (i, entry) in lessons::CURRICULUM.iter().enumerate() {
+        let outputs = (1..=entry.lesson.sections.len())
+            .map(|section| section_output(entry.id, section))
+            .collect::<Result<Vec<_>, _>>()?;
+        // The book has its own heading for the "N. Title:" line
+        let outputs: Vec<String> = outputs
+            .iter()
+            .map(|output| {
+                output
+                    .split_once('\n')
+                    .map_or("", |(_, rest)| rest)
+                    .to_string()
+            })
+            .collect();
+        write(
+            book::file_name(i, entry, format),
+            book::chapter(i, &outputs, format),
+        )?;
+        println!("   {}", book::file_name(i, entry, format));
+    }
+    write(book::index_file_name(format), book::contents(format))?;
+
+    println!(
+        "Wrote {} chapters; start at {}",
+        lessons::CURRICULUM.len(),
+        dir.join(book::index_file_name(format)).display()
+    );
+    Ok(true)
+}
+
+fn serve(args: &[String]) -> Result<bool, String> {
+    let port = match args {
+        [] => 7878,
+        [flag, port] if flag == "--port" => port
+            .parse::<u16>()
+            .map_err(|_| format!("bad port `{}`", port))?,
+        _ => return Err(String::from("usage: learn serve [--port N]")),
+    };
+
+    // Loopback only: the server runs lesson code on request
+    let listener =
+        TcpListener::bind(("127.0.0.1", port)).map_err(|e| format!("port {}: {}", port, e))?;
+    let address = listener.local_addr().map_err(|e| e.to_string())?;
+    println!(
+        "Serving the lessons at http://{}/ (Ctrl+C to stop)",
+        address
+    );
+    io::stdout().flush().ok();
+
+    learn_rust::serve::serve(listener).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+// ============================================================================
+// Progress
+// ============================================================================
+
+// When the trainee last viewed a section of the lesson or passed one of its exercises
+fn last_active(progress: &Progress, entry: &Entry) -> Option<u64> {
+    let viewed = progress
+        .viewed
+        .get(entry.id)
+        .and_then(|sections| sections.values().max().copied());
+    let passed = exercises::for_lesson(entry)
+        .iter()
+        .filter_map(|e| progress.passed.get(e.name).copied())
+        .max();
+    viewed.max(passed)
+}
+
+fn status() -> Result<bool, String> {
+    let progress = Progress::load()?;
+    println!("Progress ({})", Progress::path().display());
+
+    for (topic, entries) in lessons::topics() {
+        let viewed: usize = entries.iter().map(|e| progress.sections_viewed(e)).sum();
+        let sections: usize = entries.iter().map(|e| e.lesson.sections.len()).sum();
+        let exercises: Vec<&Exercise> = entries
+            .iter()
+            .flat_map(|e| exercises::for_lesson(e))
+            .collect();
+        let passed = exercises
+            .iter()
+            .filter(|e| progress.has_passed(e.name))
+            .count();
+        println!(
+            "\n=== {} === {}/{} sections, {}/{} exercises",
+            topic,
+            viewed,
+            sections,
+            passed,
+            exercises.len()
+        );
+
+        for entry in entries {
+            let mark = if progress.has_finished(entry) {
+                "done"
+            } else {
+                "    "
+            };
+            let exercises = exercises::for_lesson(entry);
+            let passed = exercises
+                .iter()
+                .filter(|e| progress.has_passed(e.name))
+                .count();
+            let when = last_active(&progress, entry)
+                .map(|t| format!("  last {}", progress::date(t)))
+                .unwrap_or_default();
+            println!(
+                "   {} {:<20} {}/{} sections, {}/{} exercises{}",
+                mark,
+                entry.id,
+                progress.sections_viewed(entry),
+                entry.lesson.sections.len(),
+                passed,
+                exercises.len(),
+                when
+            );
+        }
+    }
+    Ok(true)
+}
+
+fn next() -> Result<bool, String> {
+    let progress = Progress::load()?;
+    let Some(entry) = lessons::CURRICULUM
+        .iter()
+        .find(|e| !progress.has_finished(e))
+    else {
+        println!("You've finished every lesson and exercise - well done!");
+        return Ok(true);
+    };
+
+    println!("Next up: {} ({})", entry.lesson.title, entry.topic);
+    let unseen = (1..=entry.lesson.sections.len()).find(|&n| !progress.has_viewed(entry.id, n));
+    match unseen {
+        Some(1) => {
+            println!("   cargo run --bin learn -- run {}", entry.id);
+        }
+        Some(n) => {
+            println!("   {}. {}", n, entry.lesson.sections[n - 1].title);
+            println!("   cargo run --bin learn -- run {} {}", entry.id, n);
+        }
+        None => {
+            // Every section seen, so an exercise is left
+            let exercise = exercises::for_lesson(entry)
+                .into_iter()
+                .find(|e| !progress.has_passed(e.name))
+                .ok_or("no unfinished exercise")?;
+            println!("   Exercise {}: {}", exercise.name, exercise.task);
+            println!("   cargo run --bin learn -- check {}", exercise.name);
+        }
+    }
+    Ok(true)
+}
+
+// ============================================================================
+// Quizzes
+// ============================================================================
+
+// Flashcards of every takeaway and quiz question, one deck per topic
+fn export_decks(separator: Separator, dir: &Path) -> Result<bool, String> {
+    let questions = quiz::bundled()?;
+    let mut outputs = HashMap::new();
+    let mut cached = |lesson: &str, section: usize| -> Result<String, String> {
+        let key = (lesson.to_string(), section);
+        if !outputs.contains_key(&key) {
+            outputs.insert(key.clone(), section_output(lesson, section)?);
+        }
+        Ok(outputs[&key].clone())
+    };
+
+    for (name, entries) in deck::decks() {
+        let mut notes = Vec::new();
+        for entry in entries {
+            notes.extend(deck::takeaway_notes(entry));
+            for question in questions.iter().filter(|q| q.lesson == entry.id) {
+                let (answer, remark) = quiz::answer(question, &mut cached)?;
+                notes.push(deck::question_note(entry, question, &answer, &remark));
+            }
+        }
+
+        let path = dir.join(deck::file_name(&name, separator));
+        fs::write(&path, deck::deck_file(&name, &notes, separator))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!("   {} ({} cards)", path.display(), notes.len());
+    }
+    println!("Import each file in Anki with File > Import.");
+    Ok(true)
+}
+
+fn quiz(args: &[String]) -> Result<bool, String> {
+    let mut questions = match args {
+        [flag, file] if flag == "--bank" => {
+            let text = fs::read_to_string(file).map_err(|e| format!("{}: {}", file, e))?;
+            quiz::parse_bank(&text).map_err(|e| format!("{}: {}", file, e))?
+        }
+        _ => quiz::bundled()?,
+    };
+    if let [name] = args {
+        let entry = find_lesson(name)?;
+        questions.retain(|q| q.lesson == entry.id);
+        if questions.is_empty() {
+            return Err(format!("no quiz questions for {} yet", entry.id));
+        }
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut read = |prompt: &str| {
+        print!("{}", prompt);
+        io::stdout().flush().ok();
+        lines.next().and_then(Result::ok)
+    };
+    quiz::ask(&questions, &mut section_output, &mut read)?;
+    Ok(true)
+}
+
+// ============================================================================
+// Takeaway Review
+// ============================================================================
+
+fn review() -> Result<bool, String> {
+    let mut progress = Progress::load()?;
+    review::forget_missing(&mut progress);
+    let today = review::today();
+    let cards = review::due(&progress, today);
+
+    if cards.is_empty() {
+        match progress.cards.values().map(|s| s.due).min() {
+            Some(day) => println!(
+                "Nothing to review today. Next review: {}",
+                progress::date(day * 86_400)
+            ),
+            None => println!("No cards yet - they come from the lessons you've run."),
+        }
+        return Ok(true);
+    }
+
+    println!("{} cards to review today", cards.len());
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut prompt = |text: &str| {
+        print!("{}", text);
+        io::stdout().flush().ok();
+        lines.next().and_then(Result::ok)
+    };
+
+    for (i, card) in cards.iter().enumerate() {
+        let (front, term) = card.cloze();
+        println!("\nCard {}/{} ({})\n", i + 1, cards.len(), card.lesson);
+        println!("   {}\n", front);
+        let Some(guess) = prompt("Fill in the blank, then press Enter: ") else {
+            break;
+        };
+        println!("   {}", card.text);
+        if guess.trim().eq_ignore_ascii_case(term) {
+            println!("   Right - it's `{}`", term);
+        } else {
+            println!("   The blank was `{}`", term);
+        }
+
+        let grade = loop {
+            let Some(answer) =
+                prompt("How well did you remember? 1) again 2) hard 3) good 4) easy: ")
+            else {
+                println!();
+                return Ok(true);
+            };
+            match answer.trim() {
+                "1" => break Grade::Again,
+                "2" => break Grade::Hard,
+                "3" => break Grade::Good,
+                "4" => break Grade::Easy,
+                _ => {}
+            }
+        };
+
+        // Saved card by card, so stopping part way keeps what was reviewed
+        let schedule = progress
+            .cards
+            .get(&card.id)
+            .copied()
+            .unwrap_or_else(|| review::Schedule::new(today))
+            .review(grade, today);
+        progress.cards.insert(card.id.clone(), schedule);
+        progress.save()?;
+        println!("   Next time: {}", progress::date(schedule.due * 86_400));
+    }
+    Ok(true)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // A lesson registered with a bad prerequisite is caught on any command
+    let result =
+        graph::check(&lessons::CURRICULUM).and_then(|()| match args.first().map(String::as_str) {
+            Some("list") => list(),
+            Some("check") => check(&args[1..]),
+            Some("hint") => hint(&args[1..]),
+            Some("run") => run(&args[1..]),
+            Some("status") => status(),
+            Some("next") => next(),
+            Some("output") => output(&args[1..]),
+            Some("graph") => graph(&args[1..]),
+            Some("export") => export(&args[1..]),
+            Some("serve") => serve(&args[1..]),
+            Some("watch") => watch(&args[1..]),
+            Some("play") => play(&args[1..]),
+            Some("explain") => explain(&args[1..]),
+            Some("quiz") => quiz(&args[1..]),
+            Some("review") => review(),
+            Some("help") | None => {
+                print_usage();
+                Ok(true)
+            }
+            Some(other) => Err(format!("unknown command `{}`", other)),
+        });
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,936 @@
+// The borrow checker for the mini ownership language
+//
+// Programs are straight-line code (no loops or branches), so a borrow is live
+// from where it is created until the last textual use of the binding holding
+// it - the same non-lexical rule rustc uses. The checker runs twice: the first
+// pass only records where every binding is used, the second reports errors.
+
+use super::diagnostic::{Diagnostic, Span};
+use super::syntax::{Block, Expr, ExprKind, Function, Place, Program, Stmt, Type};
+
+type VarId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Ctx {
+    Move, // the value is taken: let, by-value argument, assignment
+    Read, // the value is only looked at: print
+}
+
+struct Var {
+    name: String,
+    ty: Option<Type>, // None for `let x;` until the first assignment
+    mutable: bool,
+    decl: Span,
+    init: Option<Span>,
+    moved: Option<Span>,
+}
+
+/// A borrow of `target`, held by a binding or by the current statement.
+#[derive(Debug, Clone)]
+struct Loan {
+    target: VarId,
+    mutable: bool,
+    holder: Option<VarId>, // None: a temporary that ends with the statement
+    span: Span,
+}
+
+// A borrow travelling inside a value, before a binding holds it
+#[derive(Debug, Clone)]
+struct Carried {
+    target: VarId,
+    mutable: bool,
+    span: Span,
+}
+
+struct Value {
+    ty: Type,
+    carried: Vec<Carried>,
+}
+
+impl Value {
+    fn plain(ty: Type) -> Value {
+        Value {
+            ty,
+            carried: Vec::new(),
+        }
+    }
+}
+
+struct Checker<'p> {
+    functions: &'p [Function],
+    vars: Vec<Var>,
+    scopes: Vec<Vec<VarId>>,
+    loans: Vec<Loan>,
+    pos: usize,                    // index of the statement being checked
+    uses: Vec<Vec<(usize, Span)>>, // from the first pass
+    recorded: Vec<Vec<(usize, Span)>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn compatible(expected: &Type, found: &Type) -> bool {
+    match (expected, found) {
+        // &mut T coerces to &T
+        (Type::Ref(a), Type::RefMut(b)) => a == b,
+        _ => expected == found,
+    }
+}
+
+impl<'p> Checker<'p> {
+    fn new(functions: &'p [Function], uses: Vec<Vec<(usize, Span)>>) -> Checker<'p> {
+        Checker {
+            functions,
+            vars: Vec::new(),
+            scopes: Vec::new(),
+            loans: Vec::new(),
+            pos: 0,
+            uses,
+            recorded: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    // ------------------------------------------------------------------------
+    // Bindings and liveness
+    // ------------------------------------------------------------------------
+
+    fn declare(
+        &mut self,
+        name: &str,
+        ty: Option<Type>,
+        mutable: bool,
+        span: Span,
+        init: bool,
+    ) -> VarId {
+        let id = self.vars.len();
+        self.vars.push(Var {
+            name: name.to_string(),
+            ty,
+            mutable,
+            decl: span,
+            init: if init { Some(span) } else { None },
+            moved: None,
+        });
+        self.recorded.push(Vec::new());
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(id);
+        }
+        id
+    }
+
+    fn resolve(&mut self, name: &str, span: Span) -> Option<VarId> {
+        let found = self
+            .scopes
+            .iter()
+            .rev()
+            .flatten()
+            .rev()
+            .find(|id| self.vars[**id].name == name)
+            .copied();
+        if found.is_none() {
+            self.error(Diagnostic::error(
+                "E0425",
+                &format!("cannot find value `{}` in this scope", name),
+                span,
+                "not found in this scope",
+            ));
+        }
+        found
+    }
+
+    fn note_use(&mut self, id: VarId, span: Span) {
+        self.recorded[id].push((self.pos, span));
+    }
+
+    // The first use of `holder` at or after statement `from`
+    fn use_from(&self, holder: VarId, from: usize) -> Option<Span> {
+        self.uses
+            .get(holder)?
+            .iter()
+            .find(|(pos, _)| *pos >= from)
+            .map(|(_, span)| *span)
+    }
+
+    // Loans on `target` still needed now: temporaries, or a holder used later
+    fn live_loans(&self, target: VarId) -> Vec<Loan> {
+        self.loans
+            .iter()
+            .filter(|loan| loan.target == target)
+            .filter(|loan| match loan.holder {
+                None => true,
+                Some(holder) => self.use_from(holder, self.pos).is_some(),
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn later_use(&self, loan: &Loan) -> Option<Span> {
+        loan.holder
+            .and_then(|holder| self.use_from(holder, self.pos))
+    }
+
+    fn held_by(&self, holder: VarId) -> Vec<Carried> {
+        self.loans
+            .iter()
+            .filter(|loan| loan.holder == Some(holder))
+            .map(|loan| Carried {
+                target: loan.target,
+                mutable: loan.mutable,
+                span: loan.span,
+            })
+            .collect()
+    }
+
+    fn hold(&mut self, holder: VarId, carried: Vec<Carried>) {
+        for c in carried {
+            self.loans.push(Loan {
+                target: c.target,
+                mutable: c.mutable,
+                holder: Some(holder),
+                span: c.span,
+            });
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Checks shared by every kind of access
+    // ------------------------------------------------------------------------
+
+    // Initialized and not moved away; `verb` is "used" or "borrowed"
+    fn check_available(&mut self, id: VarId, span: Span, verb: &str) -> bool {
+        let var = &self.vars[id];
+        let name = var.name.clone();
+
+        if var.init.is_none() {
+            let decl = var.decl;
+            self.error(
+                Diagnostic::error(
+                    "E0381",
+                    &format!("used binding `{}` isn't initialized", name),
+                    span,
+                    &format!("`{}` {} here but it isn't initialized", name, verb),
+                )
+                .with_label(decl, "binding declared here but left uninitialized"),
+            );
+            return false;
+        }
+
+        if let Some(moved) = var.moved {
+            let what = if verb == "borrowed" { "borrow" } else { "use" };
+            self.error(
+                Diagnostic::error(
+                    "E0382",
+                    &format!("{} of moved value: `{}`", what, name),
+                    span,
+                    &format!("value {} here after move", verb),
+                )
+                .with_label(moved, "value moved here"),
+            );
+            return false;
+        }
+
+        true
+    }
+
+    fn borrow(&mut self, id: VarId, mutable: bool, span: Span) -> Option<Carried> {
+        if !self.check_available(id, span, "borrowed") {
+            return None;
+        }
+        let name = self.vars[id].name.clone();
+
+        if mutable && !self.vars[id].mutable {
+            let decl = self.vars[id].decl;
+            self.error(
+                Diagnostic::error(
+                    "E0596",
+                    &format!(
+                        "cannot borrow `{}` as mutable, as it is not declared as mutable",
+                        name
+                    ),
+                    span,
+                    "cannot borrow as mutable",
+                )
+                .with_label(
+                    decl,
+                    &format!("help: consider changing this to be mutable: `mut {}`", name),
+                ),
+            );
+        }
+
+        for loan in self.live_loans(id) {
+            if !mutable && !loan.mutable {
+                continue;
+            }
+            let (code, message, first, second, later) = match (loan.mutable, mutable) {
+                (true, true) => (
+                    "E0499",
+                    format!(
+                        "cannot borrow `{}` as mutable more than once at a time",
+                        name
+                    ),
+                    "first mutable borrow occurs here",
+                    "second mutable borrow occurs here",
+                    "first borrow later used here",
+                ),
+                (false, _) => (
+                    "E0502",
+                    format!(
+                        "cannot borrow `{}` as mutable because it is also borrowed as immutable",
+                        name
+                    ),
+                    "immutable borrow occurs here",
+                    "mutable borrow occurs here",
+                    "immutable borrow later used here",
+                ),
+                (true, false) => (
+                    "E0502",
+                    format!(
+                        "cannot borrow `{}` as immutable because it is also borrowed as mutable",
+                        name
+                    ),
+                    "mutable borrow occurs here",
+                    "immutable borrow occurs here",
+                    "mutable borrow later used here",
+                ),
+            };
+            let mut diagnostic =
+                Diagnostic::error(code, &message, span, second).with_label(loan.span, first);
+            if let Some(later_span) = self.later_use(&loan) {
+                diagnostic = diagnostic.with_label(later_span, later);
+            }
+            self.error(diagnostic);
+            break;
+        }
+
+        self.loans.push(Loan {
+            target: id,
+            mutable,
+            holder: None,
+            span,
+        });
+        Some(Carried {
+            target: id,
+            mutable,
+            span,
+        })
+    }
+
+    // Reading a binding's value: copying it, moving it or printing it
+    fn read_var(&mut self, id: VarId, span: Span, ctx: Ctx) -> Option<Value> {
+        let verb = if ctx == Ctx::Read { "borrowed" } else { "used" };
+        if !self.check_available(id, span, verb) {
+            return None;
+        }
+        let ty = self.vars[id].ty.clone()?;
+        let name = self.vars[id].name.clone();
+        let moving = ctx == Ctx::Move && !ty.is_copy();
+
+        for loan in self.live_loans(id) {
+            let later = self.later_use(&loan);
+            let diagnostic = if moving {
+                Diagnostic::error(
+                    "E0505",
+                    &format!("cannot move out of `{}` because it is borrowed", name),
+                    span,
+                    &format!("move out of `{}` occurs here", name),
+                )
+                .with_label(loan.span, &format!("borrow of `{}` occurs here", name))
+            } else if !loan.mutable {
+                continue;
+            } else if ctx == Ctx::Read {
+                Diagnostic::error(
+                    "E0502",
+                    &format!(
+                        "cannot borrow `{}` as immutable because it is also borrowed as mutable",
+                        name
+                    ),
+                    span,
+                    "immutable borrow occurs here",
+                )
+                .with_label(loan.span, "mutable borrow occurs here")
+            } else {
+                Diagnostic::error(
+                    "E0503",
+                    &format!("cannot use `{}` because it was mutably borrowed", name),
+                    span,
+                    &format!("use of borrowed `{}`", name),
+                )
+                .with_label(loan.span, &format!("`{}` is borrowed here", name))
+            };
+            let diagnostic = match later {
+                Some(later) => diagnostic.with_label(later, "borrow later used here"),
+                None => diagnostic,
+            };
+            self.error(diagnostic);
+            break;
+        }
+
+        let carried = self.held_by(id);
+        if moving {
+            self.vars[id].moved = Some(span);
+        }
+        Some(Value { ty, carried })
+    }
+
+    fn mismatch(&mut self, expected: &Type, found: &Type, span: Span) {
+        self.error(Diagnostic::error(
+            "E0308",
+            "mismatched types",
+            span,
+            &format!("expected `{}`, found `{}`", expected, found),
+        ));
+    }
+
+    // ------------------------------------------------------------------------
+    // Expressions
+    // ------------------------------------------------------------------------
+
+    fn expr(&mut self, expr: &Expr, ctx: Ctx) -> Option<Value> {
+        let span = expr.span;
+        match &expr.kind {
+            ExprKind::Int(_) => Some(Value::plain(Type::Int)),
+            ExprKind::Str(_) => Some(Value::plain(Type::Str)),
+            ExprKind::StringFrom(_) => Some(Value::plain(Type::String)),
+
+            ExprKind::Var(name) => {
+                let id = self.resolve(name, span)?;
+                self.note_use(id, span);
+                self.read_var(id, span, ctx)
+            }
+
+            ExprKind::Ref { mutable, name } => {
+                let id = self.resolve(name, span)?;
+                self.note_use(id, span);
+                let ty = self.vars[id].ty.clone();
+                let carried = self.borrow(id, *mutable, span)?;
+                let inner = Box::new(ty?);
+                let ty = if *mutable {
+                    Type::RefMut(inner)
+                } else {
+                    Type::Ref(inner)
+                };
+                Some(Value {
+                    ty,
+                    carried: vec![carried],
+                })
+            }
+
+            ExprKind::Deref(name) => {
+                let id = self.resolve(name, span)?;
+                self.note_use(id, span);
+                let reference = self.read_var(id, span, Ctx::Read)?;
+                let Some(inner) = reference.ty.pointee().cloned() else {
+                    self.error(Diagnostic::error(
+                        "E0614",
+                        &format!("type `{}` cannot be dereferenced", reference.ty),
+                        span,
+                        "can't be dereferenced",
+                    ));
+                    return None;
+                };
+                if ctx == Ctx::Move && !inner.is_copy() {
+                    let kind = if matches!(reference.ty, Type::RefMut(_)) {
+                        "mutable"
+                    } else {
+                        "shared"
+                    };
+                    self.error(Diagnostic::error(
+                        "E0507",
+                        &format!("cannot move out of `*{}` which is behind a {} reference", name, kind),
+                        span,
+                        &format!(
+                            "move occurs because `*{}` has type `{}`, which does not implement the `Copy` trait",
+                            name, inner
+                        ),
+                    ));
+                }
+                let carried = if inner.is_reference() {
+                    reference.carried
+                } else {
+                    Vec::new()
+                };
+                Some(Value { ty: inner, carried })
+            }
+
+            ExprKind::Add(left, right) => {
+                let l = self.expr(left, Ctx::Move);
+                let r = self.expr(right, Ctx::Move);
+                let (l, r) = (l?, r?);
+                if l.ty != Type::Int || r.ty != Type::Int {
+                    self.error(Diagnostic::error(
+                        "E0369",
+                        &format!("cannot add `{}` to `{}`", r.ty, l.ty),
+                        span,
+                        "only i32 values can be added",
+                    ));
+                    return None;
+                }
+                Some(Value::plain(Type::Int))
+            }
+
+            ExprKind::Call { name, args } => self.call(name, args, span),
+
+            ExprKind::Method {
+                receiver,
+                method,
+                args,
+            } => self.method(receiver, method, args, span),
+        }
+    }
+
+    fn call(&mut self, name: &str, args: &[Expr], span: Span) -> Option<Value> {
+        match name {
+            // print(..) borrows its arguments like println!
+            "print" => {
+                for arg in args {
+                    self.expr(arg, Ctx::Read);
+                }
+                return Some(Value::plain(Type::Unit));
+            }
+            "drop" if args.len() == 1 => {
+                self.expr(&args[0], Ctx::Move)?;
+                return Some(Value::plain(Type::Unit));
+            }
+            _ => {}
+        }
+
+        let functions = self.functions;
+        let Some(function) = functions.iter().find(|f| f.name == name) else {
+            self.error(Diagnostic::error(
+                "E0425",
+                &format!("cannot find function `{}` in this scope", name),
+                span,
+                "not found in this scope",
+            ));
+            return None;
+        };
+
+        if args.len() != function.params.len() {
+            self.error(Diagnostic::error(
+                "E0061",
+                &format!(
+                    "this function takes {} argument{} but {} {} supplied",
+                    function.params.len(),
+                    if function.params.len() == 1 { "" } else { "s" },
+                    args.len(),
+                    if args.len() == 1 {
+                        "argument was"
+                    } else {
+                        "arguments were"
+                    }
+                ),
+                span,
+                "",
+            ));
+            return None;
+        }
+
+        // The result borrows from every reference argument with the return
+        // type's lifetime (or the only reference argument, when elided)
+        let mut carried = Vec::new();
+        let mut failed = false;
+        for (arg, param) in args.iter().zip(&function.params) {
+            // Passing a reference binding to a reference parameter reborrows
+            // it instead of moving it
+            let ctx = match &arg.kind {
+                ExprKind::Var(_) if param.ty.is_reference() => Ctx::Read,
+                _ => Ctx::Move,
+            };
+            let Some(value) = self.expr(arg, ctx) else {
+                failed = true;
+                continue;
+            };
+            if !compatible(&param.ty, &value.ty) {
+                self.mismatch(&param.ty, &value.ty, arg.span);
+                failed = true;
+            }
+            let linked = function.ret_lifetime.is_none() || param.lifetime == function.ret_lifetime;
+            if function.ret.is_reference() && param.ty.is_reference() && linked {
+                carried.extend(value.carried);
+            }
+        }
+
+        if failed {
+            return None;
+        }
+        Some(Value {
+            ty: function.ret.clone(),
+            carried,
+        })
+    }
+
+    fn method(&mut self, receiver: &str, method: &str, args: &[Expr], span: Span) -> Option<Value> {
+        let receiver_span = Span::new(span.line, span.start, span.start + receiver.len());
+        let id = self.resolve(receiver, receiver_span)?;
+        self.note_use(id, receiver_span);
+
+        let ty = self.vars[id].ty.clone()?;
+        let target = ty.pointee().unwrap_or(&ty).clone();
+        let mutating = method == "push_str";
+
+        let known = match method {
+            "push_str" | "len" => target == Type::String,
+            "clone" => target == Type::String || target == Type::Int,
+            _ => false,
+        };
+        if !known {
+            self.error(Diagnostic::error(
+                "E0599",
+                &format!(
+                    "no method named `{}` found for type `{}` in this scope",
+                    method, ty
+                ),
+                span,
+                "method not found (try push_str, len or clone on a String)",
+            ));
+            return None;
+        }
+
+        // Methods take &self or &mut self: owned receivers are borrowed
+        // automatically, references are reborrowed
+        match &ty {
+            Type::Ref(_) if mutating => {
+                self.error(Diagnostic::error(
+                    "E0596",
+                    &format!("cannot borrow `*{}` as mutable, as it is behind a `&` reference", receiver),
+                    span,
+                    &format!(
+                        "`{}` is a `&` reference, so the data it refers to cannot be borrowed as mutable",
+                        receiver
+                    ),
+                ));
+            }
+            Type::Ref(_) | Type::RefMut(_) => {
+                self.read_var(id, receiver_span, Ctx::Read)?;
+            }
+            _ => {
+                self.borrow(id, mutating, span)?;
+            }
+        }
+
+        let expected_args = if mutating { 1 } else { 0 };
+        if args.len() != expected_args {
+            self.error(Diagnostic::error(
+                "E0061",
+                &format!(
+                    "this method takes {} argument{} but {} supplied",
+                    expected_args,
+                    if expected_args == 1 { "" } else { "s" },
+                    args.len()
+                ),
+                span,
+                "",
+            ));
+            return None;
+        }
+        for arg in args {
+            let value = self.expr(arg, Ctx::Move)?;
+            let string_ref = Type::Ref(Box::new(Type::String));
+            if value.ty != Type::Str && !compatible(&string_ref, &value.ty) {
+                self.mismatch(&Type::Str, &value.ty, arg.span);
+            }
+        }
+
+        Some(Value::plain(match method {
+            "push_str" => Type::Unit,
+            "len" => Type::Int,
+            _ => target,
+        }))
+    }
+
+    // ------------------------------------------------------------------------
+    // Statements and scopes
+    // ------------------------------------------------------------------------
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        self.pos += 1;
+        match stmt {
+            Stmt::Let {
+                name,
+                mutable,
+                ty,
+                value,
+                span,
+            } => {
+                let value = value.as_ref().map(|v| (self.expr(v, Ctx::Move), v.span));
+                let mut var_ty = ty.clone();
+                let mut carried = Vec::new();
+                if let Some((Some(value), value_span)) = value.as_ref() {
+                    match ty {
+                        Some(ty) if !compatible(ty, &value.ty) => {
+                            self.mismatch(ty, &value.ty, *value_span)
+                        }
+                        Some(_) => {}
+                        None => var_ty = Some(value.ty.clone()),
+                    }
+                    carried = value.carried.clone();
+                }
+                let id = self.declare(name, var_ty, *mutable, *span, value.is_some());
+                self.hold(id, carried);
+            }
+
+            Stmt::Assign { place, value, span } => self.assign(place, value, *span),
+
+            Stmt::Expr(expr) => {
+                self.expr(expr, Ctx::Move);
+            }
+
+            Stmt::Block(block) => {
+                self.scopes.push(Vec::new());
+                self.block(block);
+                self.end_scope(block.close);
+            }
+        }
+        // Temporary borrows end with their statement
+        self.loans.retain(|loan| loan.holder.is_some());
+    }
+
+    fn block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            self.stmt(stmt);
+        }
+        if let Some(tail) = &block.tail {
+            self.stmt(&Stmt::Expr(tail.clone()));
+        }
+    }
+
+    fn assign(&mut self, place: &Place, value: &Expr, span: Span) {
+        let value = self.expr(value, Ctx::Move);
+
+        match place {
+            Place::Var(name) => {
+                let Some(id) = self.resolve(name, span) else {
+                    return;
+                };
+                let var = &self.vars[id];
+                if let (Some(first), false) = (var.init, var.mutable) {
+                    self.error(
+                        Diagnostic::error(
+                            "E0384",
+                            &format!("cannot assign twice to immutable variable `{}`", name),
+                            span,
+                            "cannot assign twice to immutable variable",
+                        )
+                        .with_label(first, &format!("first assignment to `{}`", name)),
+                    );
+                }
+
+                if let Some(loan) = self.live_loans(id).first() {
+                    let mut diagnostic = Diagnostic::error(
+                        "E0506",
+                        &format!("cannot assign to `{}` because it is borrowed", name),
+                        span,
+                        &format!("`{}` is assigned to here but it was already borrowed", name),
+                    )
+                    .with_label(loan.span, &format!("`{}` is borrowed here", name));
+                    if let Some(later) = self.later_use(loan) {
+                        diagnostic = diagnostic.with_label(later, "borrow later used here");
+                    }
+                    self.error(diagnostic);
+                }
+
+                let Some(value) = value else {
+                    return;
+                };
+                match self.vars[id].ty.clone() {
+                    Some(ty) if !compatible(&ty, &value.ty) => self.mismatch(&ty, &value.ty, span),
+                    Some(_) => {}
+                    None => self.vars[id].ty = Some(value.ty.clone()),
+                }
+
+                // The old value (and any borrows it held) is replaced
+                let var = &mut self.vars[id];
+                var.init = Some(span);
+                var.moved = None;
+                self.loans.retain(|loan| loan.holder != Some(id));
+                self.hold(id, value.carried);
+            }
+
+            Place::Deref(name) => {
+                let Some(id) = self.resolve(name, span) else {
+                    return;
+                };
+                self.note_use(id, span);
+                let Some(reference) = self.read_var(id, span, Ctx::Read) else {
+                    return;
+                };
+                match &reference.ty {
+                    Type::RefMut(inner) => {
+                        if let Some(value) = value {
+                            if !compatible(inner, &value.ty) {
+                                self.mismatch(inner, &value.ty, span);
+                            }
+                        }
+                    }
+                    Type::Ref(_) => self.error(Diagnostic::error(
+                        "E0594",
+                        &format!(
+                            "cannot assign to `*{}`, which is behind a `&` reference",
+                            name
+                        ),
+                        span,
+                        &format!(
+                            "`{}` is a `&` reference, so the data it refers to cannot be written",
+                            name
+                        ),
+                    )),
+                    other => self.error(Diagnostic::error(
+                        "E0614",
+                        &format!("type `{}` cannot be dereferenced", other),
+                        span,
+                        "can't be dereferenced",
+                    )),
+                }
+            }
+        }
+    }
+
+    // Locals are dropped at `close`; a borrow of one must not be used later
+    fn end_scope(&mut self, close: Span) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+
+        for &id in scope.iter().rev() {
+            let escaping = self.loans.iter().find(|loan| {
+                loan.target == id
+                    && loan.holder.is_some_and(|holder| {
+                        !scope.contains(&holder) && self.use_from(holder, self.pos + 1).is_some()
+                    })
+            });
+            if let Some(loan) = escaping.cloned() {
+                let name = self.vars[id].name.clone();
+                let mut diagnostic = Diagnostic::error(
+                    "E0597",
+                    &format!("`{}` does not live long enough", name),
+                    loan.span,
+                    "borrowed value does not live long enough",
+                )
+                .with_label(
+                    close,
+                    &format!("`{}` dropped here while still borrowed", name),
+                );
+                if let Some(later) = loan.holder.and_then(|h| self.use_from(h, self.pos + 1)) {
+                    diagnostic = diagnostic.with_label(later, "borrow later used here");
+                }
+                self.error(diagnostic);
+            }
+        }
+
+        self.loans.retain(|loan| {
+            !scope.contains(&loan.target)
+                && !loan.holder.is_some_and(|holder| scope.contains(&holder))
+        });
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.scopes.push(Vec::new());
+        for param in &function.params {
+            self.declare(
+                &param.name,
+                Some(param.ty.clone()),
+                param.mutable,
+                param.span,
+                true,
+            );
+        }
+
+        // Lifetime elision only works with exactly one reference parameter
+        let ref_params = function
+            .params
+            .iter()
+            .filter(|p| p.ty.is_reference())
+            .count();
+        let missing_lifetime =
+            function.ret.is_reference() && function.ret_lifetime.is_none() && ref_params != 1;
+        if missing_lifetime {
+            let help = if ref_params == 0 {
+                "expected named lifetime parameter (this function has no references to borrow from)"
+            } else {
+                "expected named lifetime parameter (which parameter does it borrow from?)"
+            };
+            self.error(Diagnostic::error(
+                "E0106",
+                "missing lifetime specifier",
+                function.ret_span,
+                help,
+            ));
+        }
+
+        for stmt in &function.body.stmts {
+            self.stmt(stmt);
+        }
+
+        match &function.body.tail {
+            Some(tail) => {
+                self.pos += 1;
+                if let Some(value) = self.expr(tail, Ctx::Move) {
+                    if !compatible(&function.ret, &value.ty) {
+                        self.mismatch(&function.ret, &value.ty, tail.span);
+                    } else if let (Some(local), false) = (value.carried.first(), missing_lifetime) {
+                        // Every binding in here is dropped when we return
+                        let name = self.vars[local.target].name.clone();
+                        let message = if matches!(tail.kind, ExprKind::Ref { .. }) {
+                            format!("cannot return reference to local variable `{}`", name)
+                        } else {
+                            format!("cannot return value referencing local variable `{}`", name)
+                        };
+                        self.error(Diagnostic::error(
+                            "E0515",
+                            &message,
+                            tail.span,
+                            "returns a value referencing data owned by the current function",
+                        ));
+                    }
+                }
+                self.loans.retain(|loan| loan.holder.is_some());
+            }
+            None if function.ret != Type::Unit => self.error(Diagnostic::error(
+                "E0308",
+                "mismatched types",
+                function.ret_span,
+                &format!("expected `{}`, found `()`", function.ret),
+            )),
+            None => {}
+        }
+
+        self.end_scope(function.body.close);
+    }
+
+    fn program(&mut self, program: &Program) {
+        for (i, function) in program.functions.iter().enumerate() {
+            if program.functions[..i]
+                .iter()
+                .any(|f| f.name == function.name)
+            {
+                self.error(Diagnostic::error(
+                    "E0428",
+                    &format!("the name `{}` is defined multiple times", function.name),
+                    function.ret_span,
+                    "redefined here",
+                ));
+            }
+            self.function(function);
+        }
+
+        self.scopes.push(Vec::new());
+        self.block(&program.body);
+        self.end_scope(program.body.close);
+    }
+}
+
+/// Borrow checks a parsed program, returning every error found.
+pub fn check_program(program: &Program) -> Vec<Diagnostic> {
+    let mut first = Checker::new(&program.functions, Vec::new());
+    first.program(program);
+
+    let mut second = Checker::new(&program.functions, first.recorded);
+    second.program(program);
+
+    let mut diagnostics = second.diagnostics;
+    diagnostics.sort_by_key(Diagnostic::line);
+    diagnostics
+}
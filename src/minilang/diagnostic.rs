@@ -0,0 +1,128 @@
+// rustc-style error messages for the mini ownership language
+
+/// A piece of source text: a 1-based line and a byte range within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(line: usize, start: usize, end: usize) -> Span {
+        Span { line, start, end }
+    }
+
+    /// From the start of `self` to the end of `other` (same line), else just `self`.
+    pub fn to(self, other: Span) -> Span {
+        if other.line == self.line && other.end > self.start {
+            Span::new(self.line, self.start, other.end)
+        } else {
+            self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: Span,
+    pub text: String,
+    pub primary: bool,
+}
+
+/// One error: a code like E0502, a message and labelled spans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub code: &'static str,
+    pub message: String,
+    pub labels: Vec<Label>,
+}
+
+impl Diagnostic {
+    pub fn error(code: &'static str, message: &str, span: Span, label: &str) -> Diagnostic {
+        Diagnostic {
+            code,
+            message: message.to_string(),
+            labels: vec![Label {
+                span,
+                text: label.to_string(),
+                primary: true,
+            }],
+        }
+    }
+
+    /// Adds a secondary (`---`) label.
+    pub fn with_label(mut self, span: Span, text: &str) -> Diagnostic {
+        self.labels.push(Label {
+            span,
+            text: text.to_string(),
+            primary: false,
+        });
+        self
+    }
+
+    /// The line of the primary label.
+    pub fn line(&self) -> usize {
+        self.labels
+            .iter()
+            .find(|l| l.primary)
+            .map_or(0, |l| l.span.line)
+    }
+
+    /// Renders the error the way rustc does, quoting lines from `source`.
+    pub fn render(&self, source: &str, file_name: &str) -> String {
+        let lines: Vec<&str> = source.lines().collect();
+        let primary = self
+            .labels
+            .iter()
+            .find(|l| l.primary)
+            .unwrap_or(&self.labels[0]);
+
+        // In source order, as rustc prints them, whichever is primary
+        let mut labels: Vec<&Label> = self.labels.iter().collect();
+        labels.sort_by_key(|l| (l.span.line, l.span.start));
+
+        let max_line = labels.iter().map(|l| l.span.line).max().unwrap_or(1);
+        let width = max_line.to_string().len();
+        let pad = " ".repeat(width);
+
+        let mut out = String::new();
+        if self.code == "E0000" {
+            out += &format!("error: {}\n", self.message);
+        } else {
+            out += &format!("error[{}]: {}\n", self.code, self.message);
+        }
+        out += &format!(
+            "{}--> {}:{}:{}\n",
+            pad,
+            file_name,
+            primary.span.line,
+            primary.span.start + 1
+        );
+        out += &format!("{} |\n", pad);
+
+        let mut last_line = None;
+        for label in labels {
+            let line_no = label.span.line;
+            if last_line != Some(line_no) {
+                if last_line.is_some_and(|last| line_no > last + 1) {
+                    out += "...\n";
+                }
+                let text = lines.get(line_no - 1).copied().unwrap_or("");
+                out += &format!("{:>width$} | {}\n", line_no, text, width = width);
+                last_line = Some(line_no);
+            }
+
+            let marker = if label.primary { "^" } else { "-" };
+            let len = label.span.end.saturating_sub(label.span.start).max(1);
+            let underline = format!("{}{}", " ".repeat(label.span.start), marker.repeat(len));
+            if label.text.is_empty() {
+                out += &format!("{} | {}\n", pad, underline);
+            } else {
+                out += &format!("{} | {} {}\n", pad, underline, label.text);
+            }
+        }
+
+        out
+    }
+}
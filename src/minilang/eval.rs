@@ -0,0 +1,224 @@
+// Runs a checked program and collects what it prints
+//
+// The checker has already ruled out dangling and aliased references, so the
+// interpreter can keep every binding in one flat list of slots and represent
+// a reference as the index of the slot it points to.
+
+use super::syntax::{Block, Expr, ExprKind, Function, Place, Program, Stmt};
+
+const MAX_CALL_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(i64),
+    Text(String), // both String and &str
+    Ref(usize),   // index of the slot borrowed
+    Unit,
+    Uninit,
+}
+
+struct Machine<'p> {
+    functions: &'p [Function],
+    slots: Vec<Value>,
+    scopes: Vec<Vec<(String, usize)>>,
+    depth: usize,
+    output: Vec<String>,
+}
+
+impl<'p> Machine<'p> {
+    fn slot(&self, name: &str) -> Result<usize, String> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(n, _)| n == name)
+            .map(|(_, slot)| *slot)
+            .ok_or_else(|| format!("unknown variable `{}`", name))
+    }
+
+    // Follows references to the slot that holds the data
+    fn target(&self, mut slot: usize) -> usize {
+        while let Value::Ref(next) = self.slots[slot] {
+            slot = next;
+        }
+        slot
+    }
+
+    fn display(&self, value: &Value) -> String {
+        match value {
+            Value::Int(n) => n.to_string(),
+            Value::Text(s) => s.clone(),
+            Value::Ref(slot) => self.display(&self.slots[*slot]),
+            Value::Unit => String::from("()"),
+            Value::Uninit => String::from("<uninitialized>"),
+        }
+    }
+
+    fn bind(&mut self, name: &str, value: Value) {
+        self.slots.push(value);
+        let slot = self.slots.len() - 1;
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), slot));
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) -> Result<Value, String> {
+        match &expr.kind {
+            ExprKind::Int(n) => Ok(Value::Int(*n)),
+            ExprKind::Str(s) | ExprKind::StringFrom(s) => Ok(Value::Text(s.clone())),
+            ExprKind::Var(name) => Ok(self.slots[self.slot(name)?].clone()),
+            ExprKind::Ref { name, .. } => Ok(Value::Ref(self.slot(name)?)),
+            ExprKind::Deref(name) => match &self.slots[self.slot(name)?] {
+                Value::Ref(slot) => Ok(self.slots[*slot].clone()),
+                other => Err(format!("cannot dereference {:?}", other)),
+            },
+            ExprKind::Add(left, right) => {
+                let l = self.expr(left)?;
+                let r = self.expr(right)?;
+                match (l, r) {
+                    (Value::Int(a), Value::Int(b)) => a
+                        .checked_add(b)
+                        .filter(|sum| i32::try_from(*sum).is_ok())
+                        .map(Value::Int)
+                        .ok_or_else(|| String::from("attempt to add with overflow")),
+                    _ => Err(String::from("can only add integers")),
+                }
+            }
+            ExprKind::Call { name, args } => self.call(name, args),
+            ExprKind::Method {
+                receiver,
+                method,
+                args,
+            } => {
+                let slot = self.target(self.slot(receiver)?);
+                match (method.as_str(), args.first()) {
+                    ("push_str", Some(arg)) => {
+                        let extra = self.expr(arg)?;
+                        let extra = self.display(&extra);
+                        if let Value::Text(s) = &mut self.slots[slot] {
+                            s.push_str(&extra);
+                        }
+                        Ok(Value::Unit)
+                    }
+                    ("len", _) => match &self.slots[slot] {
+                        Value::Text(s) => Ok(Value::Int(s.len() as i64)),
+                        _ => Err(String::from("len() needs a String")),
+                    },
+                    ("clone", _) => Ok(self.slots[slot].clone()),
+                    _ => Err(format!("unknown method `{}`", method)),
+                }
+            }
+        }
+    }
+
+    fn call(&mut self, name: &str, args: &[Expr]) -> Result<Value, String> {
+        if name == "print" {
+            let mut parts = Vec::new();
+            for arg in args {
+                let value = self.expr(arg)?;
+                parts.push(self.display(&value));
+            }
+            self.output.push(parts.join(" "));
+            return Ok(Value::Unit);
+        }
+        if name == "drop" {
+            for arg in args {
+                self.expr(arg)?;
+            }
+            return Ok(Value::Unit);
+        }
+
+        let functions = self.functions;
+        let function = functions
+            .iter()
+            .find(|f| f.name == name)
+            .ok_or_else(|| format!("unknown function `{}`", name))?;
+
+        let mut values = Vec::new();
+        for arg in args {
+            values.push(self.expr(arg)?);
+        }
+
+        if self.depth == MAX_CALL_DEPTH {
+            return Err(format!(
+                "stack overflow: more than {} nested calls",
+                MAX_CALL_DEPTH
+            ));
+        }
+        self.depth += 1;
+
+        // A call sees only its own parameters
+        let saved = std::mem::replace(&mut self.scopes, vec![Vec::new()]);
+        let base = self.slots.len();
+        for (param, value) in function.params.iter().zip(values) {
+            self.bind(&param.name, value);
+        }
+        let result = self.block(&function.body);
+
+        self.slots.truncate(base);
+        self.scopes = saved;
+        self.depth -= 1;
+        result
+    }
+
+    // Runs a block in a new scope, returning its tail value
+    fn block(&mut self, block: &Block) -> Result<Value, String> {
+        let base = self.slots.len();
+        self.scopes.push(Vec::new());
+
+        for stmt in &block.stmts {
+            self.stmt(stmt)?;
+        }
+        let result = match &block.tail {
+            Some(tail) => self.expr(tail)?,
+            None => Value::Unit,
+        };
+
+        self.scopes.pop();
+        self.slots.truncate(base);
+        Ok(result)
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) -> Result<(), String> {
+        match stmt {
+            Stmt::Let { name, value, .. } => {
+                let value = match value {
+                    Some(expr) => self.expr(expr)?,
+                    None => Value::Uninit,
+                };
+                self.bind(name, value);
+            }
+            Stmt::Assign { place, value, .. } => {
+                let value = self.expr(value)?;
+                let slot = match place {
+                    Place::Var(name) => self.slot(name)?,
+                    Place::Deref(name) => match self.slots[self.slot(name)?] {
+                        Value::Ref(slot) => slot,
+                        _ => return Err(format!("`{}` is not a reference", name)),
+                    },
+                };
+                self.slots[slot] = value;
+            }
+            Stmt::Expr(expr) => {
+                self.expr(expr)?;
+            }
+            Stmt::Block(block) => {
+                self.block(block)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs a program that passed the borrow checker and returns its printed lines.
+pub fn run_program(program: &Program) -> Result<Vec<String>, String> {
+    let mut machine = Machine {
+        functions: &program.functions,
+        slots: Vec::new(),
+        scopes: Vec::new(),
+        depth: 0,
+        output: Vec::new(),
+    };
+    machine.block(&program.body)?;
+    Ok(machine.output)
+}
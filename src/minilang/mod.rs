@@ -0,0 +1,330 @@
+// Mini Ownership Language
+// A toy language with Rust's ownership rules, a borrow checker and an interpreter
+//
+//     fn first<'a>(a: &'a String, b: &String) -> &'a String { a }
+//     let mut s = String::from("hello");   // String moves, i32 and &T copy
+//     let r = &s;                          // shared borrow
+//     s.push_str("!");                     // error[E0502] - r is used below
+//     print(r);
+//     { let t = s; }                       // blocks drop their locals
+//     drop(s);                             // error[E0382] - s moved into t
+//
+// Builtins: print(..) (borrows its arguments), drop(x), String::from("..")
+// and the methods push_str, len and clone.
+
+mod check;
+mod diagnostic;
+mod eval;
+mod syntax;
+
+pub use check::check_program;
+pub use diagnostic::{Diagnostic, Label, Span};
+pub use eval::run_program;
+pub use syntax::{parse, Program, Type};
+
+/// Parses and borrow checks `source`.
+pub fn check(source: &str) -> Result<Program, Vec<Diagnostic>> {
+    let program = parse(source).map_err(|e| vec![e])?;
+    let diagnostics = check_program(&program);
+    if diagnostics.is_empty() {
+        Ok(program)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+/// Renders a list of errors followed by rustc's summary line.
+pub fn render_errors(diagnostics: &[Diagnostic], source: &str, file_name: &str) -> String {
+    let mut out = String::new();
+    for diagnostic in diagnostics {
+        out += &diagnostic.render(source, file_name);
+        out += "\n";
+    }
+    let count = diagnostics.len();
+    if count == 1 {
+        out += "error: aborting due to 1 previous error\n";
+    } else {
+        out += &format!("error: aborting due to {} previous errors\n", count);
+    }
+    out
+}
+
+/// True while `input` has unclosed `{` or `(` - the REPL keeps reading.
+pub fn is_incomplete(input: &str) -> bool {
+    let mut depth = 0i32;
+    for c in input.chars() {
+        match c {
+            '{' | '(' => depth += 1,
+            '}' | ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    depth > 0
+}
+
+// ============================================================================
+// REPL Session
+// ============================================================================
+
+/// The program typed so far; a new entry is kept only if it still checks.
+#[derive(Debug, Default)]
+pub struct Session {
+    entries: Vec<String>,
+    printed: usize,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    /// Every accepted entry, in order.
+    pub fn source(&self) -> String {
+        self.entries.iter().map(|e| format!("{}\n", e)).collect()
+    }
+
+    /// Checks the program with `input` added. On success the entry is kept
+    /// and the newly printed lines are returned; otherwise the rendered
+    /// errors are returned and the entry is discarded.
+    pub fn submit(&mut self, input: &str) -> Result<Vec<String>, String> {
+        let source = format!("{}{}\n", self.source(), input.trim_end());
+        let program = check(&source).map_err(|errors| render_errors(&errors, &source, "repl"))?;
+        let output = run_program(&program).map_err(|e| format!("runtime error: {}\n", e))?;
+
+        self.entries.push(input.trim_end().to_string());
+        let new_lines = output[self.printed.min(output.len())..].to_vec();
+        self.printed = output.len();
+        Ok(new_lines)
+    }
+
+    /// Removes the last accepted entry, returning it.
+    pub fn undo(&mut self) -> Option<String> {
+        let entry = self.entries.pop()?;
+        let source = self.source();
+        self.printed = check(&source)
+            .ok()
+            .and_then(|program| run_program(&program).ok())
+            .map_or(0, |output| output.len());
+        Some(entry)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Session::new();
+    }
+}
+
+/// Sample programs following the ownership lessons, for `:load` in the REPL.
+pub const SAMPLES: [(&str, &str); 3] = [
+    (
+        "ownership",
+        include_str!("../../examples/minilang/ownership.own"),
+    ),
+    (
+        "borrowing",
+        include_str!("../../examples/minilang/borrowing.own"),
+    ),
+    (
+        "lifetimes",
+        include_str!("../../examples/minilang/lifetimes.own"),
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(source: &str) -> Vec<&'static str> {
+        match check(source) {
+            Ok(_) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.code).collect(),
+        }
+    }
+
+    fn output(source: &str) -> Vec<String> {
+        let program = check(source).expect("program should check");
+        run_program(&program).expect("program should run")
+    }
+
+    #[test]
+    fn moves_strings_and_copies_integers() {
+        let source = "let s1 = String::from(\"hi\");\nlet s2 = s1;\nlet x = 5;\nlet y = x;\nprint(s2, x, y);\n";
+        assert_eq!(output(source), ["hi 5 5"]);
+        assert_eq!(
+            codes("let s1 = String::from(\"hi\");\nlet s2 = s1;\nprint(s1);\n"),
+            ["E0382"]
+        );
+        assert_eq!(
+            codes("let s = String::from(\"hi\");\ndrop(s);\nlet t = s;\n"),
+            ["E0382"]
+        );
+    }
+
+    #[test]
+    fn one_mutable_borrow_at_a_time() {
+        let source = "let mut s = String::from(\"a\");\nlet r1 = &mut s;\nlet r2 = &mut s;\nprint(r1, r2);\n";
+        assert_eq!(codes(source), ["E0499"]);
+
+        // Non-lexical lifetimes: r1 is finished before r2 starts
+        let source = "let mut s = String::from(\"a\");\nlet r1 = &mut s;\nr1.push_str(\"b\");\nlet r2 = &mut s;\nr2.push_str(\"c\");\nprint(s);\n";
+        assert_eq!(output(source), ["abc"]);
+    }
+
+    #[test]
+    fn shared_and_mutable_borrows_dont_mix() {
+        let source =
+            "let mut s = String::from(\"a\");\nlet r1 = &s;\nlet r2 = &mut s;\nprint(r1);\n";
+        assert_eq!(codes(source), ["E0502"]);
+        let source =
+            "let mut s = String::from(\"a\");\nlet r = &s;\ns.push_str(\"b\");\nprint(r);\n";
+        assert_eq!(codes(source), ["E0502"]);
+        let source = "let mut s = String::from(\"a\");\nlet r = &mut s;\nprint(s);\nprint(r);\n";
+        assert_eq!(codes(source), ["E0502"]);
+    }
+
+    #[test]
+    fn borrows_block_moves_and_assignments() {
+        let source = "let s = String::from(\"a\");\nlet r = &s;\nlet t = s;\nprint(r);\n";
+        assert_eq!(codes(source), ["E0505"]);
+        let source = "let mut x = 1;\nlet r = &x;\nx = 2;\nprint(r);\n";
+        assert_eq!(codes(source), ["E0506"]);
+    }
+
+    #[test]
+    fn mutability_is_checked() {
+        assert_eq!(
+            codes("let s = String::from(\"a\");\ns.push_str(\"b\");\n"),
+            ["E0596"]
+        );
+        assert_eq!(codes("let x = 1;\nx = 2;\n"), ["E0384"]);
+        assert_eq!(codes("let x;\nx = 2;\nprint(x);\n"), Vec::<&str>::new());
+        assert_eq!(codes("let x: i32;\nprint(x);\n"), ["E0381"]);
+        let source = "let s = String::from(\"a\");\nlet r = &s;\n*r = String::from(\"b\");\n";
+        assert_eq!(codes(source), ["E0594"]);
+    }
+
+    #[test]
+    fn blocks_drop_their_locals() {
+        let source = "let r;\n{\n    let x = 5;\n    r = &x;\n}\nprint(r);\n";
+        let errors = check(source).unwrap_err();
+        assert_eq!(errors[0].code, "E0597");
+        let rendered = errors[0].render(source, "test");
+        assert!(rendered.contains("`x` dropped here while still borrowed"));
+        assert!(rendered.contains("borrow later used here"));
+    }
+
+    #[test]
+    fn functions_move_borrow_and_return() {
+        let source = "fn takes(s: String) {\n    print(s);\n}\nlet s = String::from(\"a\");\ntakes(s);\nprint(s);\n";
+        assert_eq!(codes(source), ["E0382"]);
+
+        let source = "fn change(s: &mut String) {\n    s.push_str(\", world\");\n}\nlet mut s = String::from(\"hello\");\nchange(&mut s);\nprint(s, s.len());\n";
+        assert_eq!(output(source), ["hello, world 12"]);
+
+        assert_eq!(
+            codes("fn dangle() -> &String {\n    let s = String::from(\"a\");\n    &s\n}\n"),
+            ["E0106"]
+        );
+        assert_eq!(
+            codes("fn dangle<'a>() -> &'a String {\n    let s = String::from(\"a\");\n    &s\n}\n"),
+            ["E0515"]
+        );
+        assert_eq!(
+            codes("fn pick(a: &String, b: &String) -> &String {\n    a\n}\n"),
+            ["E0106"]
+        );
+        assert_eq!(
+            codes("fn f(x: i32) {\n}\nf(String::from(\"a\"));\n"),
+            ["E0308"]
+        );
+    }
+
+    #[test]
+    fn returned_references_keep_arguments_borrowed() {
+        let function = "fn first<'a>(a: &'a String, b: &String) -> &'a String {\n    a\n}\n";
+
+        let source = format!(
+            "{}let long = String::from(\"long\");\nlet result;\n{{\n    let short = String::from(\"s\");\n    result = first(&long, &short);\n}}\nprint(result);\n",
+            function
+        );
+        assert_eq!(output(&source), ["long"]);
+
+        let source = format!(
+            "{}let long = String::from(\"long\");\nlet result;\n{{\n    let short = String::from(\"s\");\n    result = first(&short, &long);\n}}\nprint(result);\n",
+            function
+        );
+        assert_eq!(codes(&source), ["E0597"]);
+    }
+
+    #[test]
+    fn errors_render_like_rustc() {
+        let source =
+            "let mut s = String::from(\"a\");\nlet r1 = &s;\nlet r2 = &mut s;\nprint(r1);\n";
+        let rendered = check(source).unwrap_err()[0].render(source, "main.own");
+        let expected = "\
+error[E0502]: cannot borrow `s` as mutable because it is also borrowed as immutable
+ --> main.own:3:10
+  |
+2 | let r1 = &s;
+  |          -- immutable borrow occurs here
+3 | let r2 = &mut s;
+  |          ^^^^^^ mutable borrow occurs here
+4 | print(r1);
+  |       -- immutable borrow later used here
+";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn labels_on_one_line_render_in_source_order() {
+        let source = "let s = String::from(\"a\"); let r = &s; let t = s; print(r);\n";
+        let rendered = render_errors(&check(source).unwrap_err(), source, "main.own");
+        let expected = "\
+error[E0505]: cannot move out of `s` because it is borrowed
+ --> main.own:1:48
+  |
+1 | let s = String::from(\"a\"); let r = &s; let t = s; print(r);
+  |                                    -- borrow of `s` occurs here
+  |                                                ^ move out of `s` occurs here
+  |                                                         - borrow later used here
+
+error: aborting due to 1 previous error
+";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn session_keeps_only_entries_that_check() {
+        let mut session = Session::new();
+        assert_eq!(
+            session.submit("let mut s = String::from(\"hi\");"),
+            Ok(Vec::new())
+        );
+        assert_eq!(session.submit("let r = &s;"), Ok(Vec::new()));
+        assert_eq!(session.submit("print(r);"), Ok(vec![String::from("hi")]));
+
+        let error = session.submit("let t = s; print(r);").unwrap_err();
+        assert!(error.starts_with("error[E0505]"));
+        assert_eq!(session.source().lines().count(), 3);
+
+        assert_eq!(
+            session.submit("s.push_str(\"!\");\nprint(s);"),
+            Ok(vec![String::from("hi!")])
+        );
+        assert!(session.undo().is_some());
+        assert_eq!(session.submit("print(s);"), Ok(vec![String::from("hi")]));
+    }
+
+    #[test]
+    fn bundled_samples_check_and_run() {
+        for (name, source) in SAMPLES {
+            let program = check(source).unwrap_or_else(|errors| {
+                panic!("{}:\n{}", name, render_errors(&errors, source, name))
+            });
+            assert!(
+                !run_program(&program).unwrap().is_empty(),
+                "{} prints nothing",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,707 @@
+// Lexer, parser and syntax tree for the mini ownership language
+
+use super::diagnostic::{Diagnostic, Span};
+
+// ============================================================================
+// Syntax Tree
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Int,               // i32 - Copy
+    Str,               // &str - string literals, Copy
+    String,            // String - owned, moves
+    Ref(Box<Type>),    // &T - Copy
+    RefMut(Box<Type>), // &mut T - moves
+    Unit,              // ()
+}
+
+impl Type {
+    /// Copy types are duplicated on use instead of moved.
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Type::Int | Type::Str | Type::Ref(_) | Type::Unit)
+    }
+
+    pub fn is_reference(&self) -> bool {
+        matches!(self, Type::Ref(_) | Type::RefMut(_))
+    }
+
+    /// The type behind a reference.
+    pub fn pointee(&self) -> Option<&Type> {
+        match self {
+            Type::Ref(inner) | Type::RefMut(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Type::Int => write!(f, "i32"),
+            Type::Str => write!(f, "&str"),
+            Type::String => write!(f, "String"),
+            Type::Ref(inner) => write!(f, "&{}", inner),
+            Type::RefMut(inner) => write!(f, "&mut {}", inner),
+            Type::Unit => write!(f, "()"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Int(i64),
+    Str(String),
+    StringFrom(String),
+    Var(String),
+    Ref {
+        mutable: bool,
+        name: String,
+    },
+    Deref(String),
+    Add(Box<Expr>, Box<Expr>),
+    Call {
+        name: String,
+        args: Vec<Expr>,
+    },
+    Method {
+        receiver: String,
+        method: String,
+        args: Vec<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Place {
+    Var(String),
+    Deref(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stmt {
+    Let {
+        name: String,
+        mutable: bool,
+        ty: Option<Type>,
+        value: Option<Expr>,
+        span: Span, // the name
+    },
+    Assign {
+        place: Place,
+        value: Expr,
+        span: Span, // the place
+    },
+    Expr(Expr),
+    Block(Block),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub tail: Option<Expr>,
+    pub close: Span, // the closing brace, where locals are dropped
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub mutable: bool,
+    pub ty: Type,
+    pub lifetime: Option<String>, // 'a in &'a T
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub ret: Type,
+    pub ret_lifetime: Option<String>,
+    pub ret_span: Span,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub functions: Vec<Function>,
+    pub body: Block,
+}
+
+// ============================================================================
+// Lexer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Int(i64),
+    Str(String),
+    Lifetime(String),
+    Punct(&'static str),
+    Eof,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+const PUNCTS: [&str; 16] = [
+    "->", "::", "{", "}", "(", ")", ";", ",", "=", "&", "*", "+", ":", ".", "<", ">",
+];
+
+fn lex(source: &str) -> Result<Vec<Token>, Diagnostic> {
+    let mut tokens = Vec::new();
+
+    for (line_index, line) in source.lines().enumerate() {
+        let line_no = line_index + 1;
+        let bytes = line.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let c = bytes[i] as char;
+            let span = |end: usize| Span::new(line_no, i, end);
+
+            if c.is_whitespace() {
+                i += 1;
+            } else if line[i..].starts_with("//") {
+                break;
+            } else if c.is_ascii_digit() {
+                let end = i + line[i..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(line.len() - i);
+                let value = line[i..end]
+                    .parse()
+                    .map_err(|_| Diagnostic::error("E0000", "number too large", span(end), ""))?;
+                tokens.push(Token {
+                    tok: Tok::Int(value),
+                    span: span(end),
+                });
+                i = end;
+            } else if c.is_ascii_alphabetic() || c == '_' {
+                let end = i + line[i..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(line.len() - i);
+                tokens.push(Token {
+                    tok: Tok::Ident(line[i..end].to_string()),
+                    span: span(end),
+                });
+                i = end;
+            } else if c == '\'' {
+                let end = i
+                    + 1
+                    + line[i + 1..]
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(line.len() - i - 1);
+                tokens.push(Token {
+                    tok: Tok::Lifetime(line[i + 1..end].to_string()),
+                    span: span(end),
+                });
+                i = end;
+            } else if c == '"' {
+                let Some(len) = line[i + 1..].find('"') else {
+                    return Err(Diagnostic::error(
+                        "E0765",
+                        "unterminated double quote string",
+                        span(line.len()),
+                        "",
+                    ));
+                };
+                let end = i + 1 + len + 1;
+                tokens.push(Token {
+                    tok: Tok::Str(line[i + 1..end - 1].to_string()),
+                    span: span(end),
+                });
+                i = end;
+            } else if let Some(p) = PUNCTS.iter().find(|p| line[i..].starts_with(**p)) {
+                tokens.push(Token {
+                    tok: Tok::Punct(p),
+                    span: span(i + p.len()),
+                });
+                i += p.len();
+            } else {
+                return Err(Diagnostic::error(
+                    "E0000",
+                    &format!("unknown start of token: {}", c),
+                    span(i + c.len_utf8()),
+                    "",
+                ));
+            }
+        }
+    }
+
+    let last_line = source.lines().count().max(1);
+    let last_col = source.lines().last().map_or(0, str::len);
+    tokens.push(Token {
+        tok: Tok::Eof,
+        span: Span::new(last_line, last_col, last_col + 1),
+    });
+    Ok(tokens)
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+type ParseResult<T> = Result<T, Diagnostic>;
+
+impl Parser {
+    fn peek(&self) -> &Tok {
+        &self.tokens[self.pos].tok
+    }
+
+    fn peek_at(&self, offset: usize) -> &Tok {
+        let i = (self.pos + offset).min(self.tokens.len() - 1);
+        &self.tokens[i].tok
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].span
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn is_punct(&self, p: &str) -> bool {
+        matches!(self.peek(), Tok::Punct(q) if *q == p)
+    }
+
+    fn is_keyword(&self, k: &str) -> bool {
+        matches!(self.peek(), Tok::Ident(name) if name == k)
+    }
+
+    fn unexpected(&self, expected: &str) -> Diagnostic {
+        let found = match self.peek() {
+            Tok::Ident(name) => format!("`{}`", name),
+            Tok::Int(n) => format!("`{}`", n),
+            Tok::Str(s) => format!("\"{}\"", s),
+            Tok::Lifetime(l) => format!("`'{}`", l),
+            Tok::Punct(p) => format!("`{}`", p),
+            Tok::Eof => String::from("end of input"),
+        };
+        Diagnostic::error(
+            "E0000",
+            &format!("expected {}, found {}", expected, found),
+            self.span(),
+            &format!("expected {}", expected),
+        )
+    }
+
+    fn expect_punct(&mut self, p: &str) -> ParseResult<Span> {
+        if self.is_punct(p) {
+            Ok(self.next().span)
+        } else {
+            Err(self.unexpected(&format!("`{}`", p)))
+        }
+    }
+
+    fn expect_ident(&mut self) -> ParseResult<(String, Span)> {
+        match self.peek().clone() {
+            Tok::Ident(name) if !["let", "mut", "fn"].contains(&name.as_str()) => {
+                Ok((name, self.next().span))
+            }
+            _ => Err(self.unexpected("identifier")),
+        }
+    }
+
+    fn eat_keyword(&mut self, k: &str) -> bool {
+        if self.is_keyword(k) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_type(&mut self) -> ParseResult<Type> {
+        Ok(self.parse_type_with_lifetime()?.0)
+    }
+
+    // A type plus the lifetime on its outermost reference, if written
+    fn parse_type_with_lifetime(&mut self) -> ParseResult<(Type, Option<String>)> {
+        if self.is_punct("&") {
+            self.next();
+            let lifetime = match self.peek().clone() {
+                Tok::Lifetime(name) => {
+                    self.next();
+                    Some(name)
+                }
+                _ => None,
+            };
+            let mutable = self.eat_keyword("mut");
+            if !mutable && self.eat_keyword("str") {
+                return Ok((Type::Str, lifetime));
+            }
+            let inner = Box::new(self.parse_type()?);
+            let ty = if mutable {
+                Type::RefMut(inner)
+            } else {
+                Type::Ref(inner)
+            };
+            return Ok((ty, lifetime));
+        }
+        if self.is_punct("(") && self.peek_at(1) == &Tok::Punct(")") {
+            self.next();
+            self.next();
+            return Ok((Type::Unit, None));
+        }
+        match self.expect_ident()?.0.as_str() {
+            "i32" => Ok((Type::Int, None)),
+            "String" => Ok((Type::String, None)),
+            other => Err(Diagnostic::error(
+                "E0412",
+                &format!("cannot find type `{}` in this scope", other),
+                self.tokens[self.pos - 1].span,
+                "not found in this scope (try i32, String, &T or &mut T)",
+            )),
+        }
+    }
+
+    fn parse_function(&mut self) -> ParseResult<Function> {
+        self.next(); // fn
+        let (name, _) = self.expect_ident()?;
+
+        // Lifetime parameters like <'a, 'b> only need to be skipped
+        if self.is_punct("<") {
+            self.next();
+            while matches!(self.peek(), Tok::Lifetime(_) | Tok::Punct(",")) {
+                self.next();
+            }
+            self.expect_punct(">")?;
+        }
+        self.expect_punct("(")?;
+
+        let mut params = Vec::new();
+        while !self.is_punct(")") {
+            let mutable = self.eat_keyword("mut");
+            let (param, span) = self.expect_ident()?;
+            self.expect_punct(":")?;
+            let (ty, lifetime) = self.parse_type_with_lifetime()?;
+            params.push(Param {
+                name: param,
+                mutable,
+                ty,
+                lifetime,
+                span,
+            });
+            if !self.is_punct(")") {
+                self.expect_punct(",")?;
+            }
+        }
+        let close = self.expect_punct(")")?;
+
+        let (ret, ret_lifetime, ret_span) = if self.is_punct("->") {
+            self.next();
+            let start = self.span();
+            let (ty, lifetime) = self.parse_type_with_lifetime()?;
+            let end = self.tokens[self.pos - 1].span;
+            (ty, lifetime, Span::new(start.line, start.start, end.end))
+        } else {
+            (Type::Unit, None, close)
+        };
+
+        self.expect_punct("{")?;
+        let body = self.parse_block_contents()?;
+        Ok(Function {
+            name,
+            params,
+            ret,
+            ret_lifetime,
+            ret_span,
+            body,
+        })
+    }
+
+    // Statements up to and including the closing `}`
+    fn parse_block_contents(&mut self) -> ParseResult<Block> {
+        let mut stmts = Vec::new();
+        loop {
+            if self.is_punct("}") {
+                let close = self.next().span;
+                return Ok(Block {
+                    stmts,
+                    tail: None,
+                    close,
+                });
+            }
+            if self.peek() == &Tok::Eof {
+                return Err(self.unexpected("`}`"));
+            }
+            match self.parse_stmt()? {
+                (stmt, true) => stmts.push(stmt),
+                (Stmt::Expr(tail), false) => {
+                    let close = self.expect_punct("}")?;
+                    return Ok(Block {
+                        stmts,
+                        tail: Some(tail),
+                        close,
+                    });
+                }
+                (stmt, false) => stmts.push(stmt),
+            }
+        }
+    }
+
+    // Returns the statement and whether it ended with `;` (or was a block)
+    fn parse_stmt(&mut self) -> ParseResult<(Stmt, bool)> {
+        if self.is_keyword("let") {
+            self.next();
+            let mutable = self.eat_keyword("mut");
+            let (name, span) = self.expect_ident()?;
+            let ty = if self.is_punct(":") {
+                self.next();
+                Some(self.parse_type()?)
+            } else {
+                None
+            };
+            let value = if self.is_punct("=") {
+                self.next();
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            self.expect_punct(";")?;
+            return Ok((
+                Stmt::Let {
+                    name,
+                    mutable,
+                    ty,
+                    value,
+                    span,
+                },
+                true,
+            ));
+        }
+
+        if self.is_punct("{") {
+            self.next();
+            return Ok((Stmt::Block(self.parse_block_contents()?), true));
+        }
+
+        let expr = self.parse_expr()?;
+        if self.is_punct("=") {
+            let place = match &expr.kind {
+                ExprKind::Var(name) => Place::Var(name.clone()),
+                ExprKind::Deref(name) => Place::Deref(name.clone()),
+                _ => {
+                    return Err(Diagnostic::error(
+                        "E0070",
+                        "invalid left-hand side of assignment",
+                        expr.span,
+                        "cannot assign to this expression",
+                    ))
+                }
+            };
+            self.next();
+            let value = self.parse_expr()?;
+            self.expect_punct(";")?;
+            return Ok((
+                Stmt::Assign {
+                    place,
+                    value,
+                    span: expr.span,
+                },
+                true,
+            ));
+        }
+
+        if self.is_punct(";") {
+            self.next();
+            Ok((Stmt::Expr(expr), true))
+        } else if self.is_punct("}") || self.peek() == &Tok::Eof {
+            Ok((Stmt::Expr(expr), false))
+        } else {
+            Err(self.unexpected("`;`"))
+        }
+    }
+
+    fn parse_expr(&mut self) -> ParseResult<Expr> {
+        let mut left = self.parse_unary()?;
+        while self.is_punct("+") {
+            self.next();
+            let right = self.parse_unary()?;
+            let span = left.span.to(right.span);
+            left = Expr {
+                kind: ExprKind::Add(Box::new(left), Box::new(right)),
+                span,
+            };
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> ParseResult<Expr> {
+        let start = self.span();
+        if self.is_punct("&") || self.is_punct("*") {
+            let is_ref = self.is_punct("&");
+            self.next();
+            let mutable = is_ref && self.eat_keyword("mut");
+            let (name, end) = self.expect_ident().map_err(|_| {
+                Diagnostic::error(
+                    "E0000",
+                    "this toy language can only borrow or dereference a variable",
+                    self.span(),
+                    "expected a variable name",
+                )
+            })?;
+            let kind = if is_ref {
+                ExprKind::Ref { mutable, name }
+            } else {
+                ExprKind::Deref(name)
+            };
+            return Ok(Expr {
+                kind,
+                span: start.to(end),
+            });
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_args(&mut self) -> ParseResult<(Vec<Expr>, Span)> {
+        self.expect_punct("(")?;
+        let mut args = Vec::new();
+        while !self.is_punct(")") {
+            args.push(self.parse_expr()?);
+            if !self.is_punct(")") {
+                self.expect_punct(",")?;
+            }
+        }
+        Ok((args, self.next().span))
+    }
+
+    fn parse_postfix(&mut self) -> ParseResult<Expr> {
+        let start = self.span();
+        let expr = match self.peek().clone() {
+            Tok::Int(n) => {
+                self.next();
+                Expr {
+                    kind: ExprKind::Int(n),
+                    span: start,
+                }
+            }
+            Tok::Str(s) => {
+                self.next();
+                Expr {
+                    kind: ExprKind::Str(s),
+                    span: start,
+                }
+            }
+            Tok::Punct("(") => {
+                self.next();
+                let inner = self.parse_expr()?;
+                self.expect_punct(")")?;
+                inner
+            }
+            Tok::Ident(name) if name == "String" && self.peek_at(1) == &Tok::Punct("::") => {
+                self.next();
+                self.next();
+                let (func, _) = self.expect_ident()?;
+                if func != "from" {
+                    return Err(self.unexpected("`from`"));
+                }
+                let (args, end) = self.parse_args()?;
+                let span = start.to(end);
+                match args.as_slice() {
+                    [Expr {
+                        kind: ExprKind::Str(s),
+                        ..
+                    }] => Expr {
+                        kind: ExprKind::StringFrom(s.clone()),
+                        span,
+                    },
+                    _ => {
+                        return Err(Diagnostic::error(
+                            "E0308",
+                            "mismatched types",
+                            span,
+                            "String::from takes one string literal",
+                        ))
+                    }
+                }
+            }
+            Tok::Ident(_) => {
+                let (name, span) = self.expect_ident()?;
+                if self.is_punct("(") {
+                    let (args, end) = self.parse_args()?;
+                    Expr {
+                        kind: ExprKind::Call { name, args },
+                        span: span.to(end),
+                    }
+                } else {
+                    Expr {
+                        kind: ExprKind::Var(name),
+                        span,
+                    }
+                }
+            }
+            _ => return Err(self.unexpected("an expression")),
+        };
+
+        // Method calls: only directly on a variable, like s.push_str("!")
+        if self.is_punct(".") {
+            let ExprKind::Var(receiver) = &expr.kind else {
+                return Err(self.unexpected("`;`"));
+            };
+            let receiver = receiver.clone();
+            self.next();
+            let (method, _) = self.expect_ident()?;
+            let (args, end) = self.parse_args()?;
+            return Ok(Expr {
+                kind: ExprKind::Method {
+                    receiver,
+                    method,
+                    args,
+                },
+                span: expr.span.to(end),
+            });
+        }
+
+        Ok(expr)
+    }
+}
+
+/// Parses a whole program: `fn` items anywhere, other statements in order.
+pub fn parse(source: &str) -> Result<Program, Diagnostic> {
+    let mut parser = Parser {
+        tokens: lex(source)?,
+        pos: 0,
+    };
+    let mut functions = Vec::new();
+    let mut stmts = Vec::new();
+
+    while parser.peek() != &Tok::Eof {
+        if parser.is_keyword("fn") {
+            functions.push(parser.parse_function()?);
+            continue;
+        }
+        // A trailing expression without `;` is allowed at the very end
+        let (stmt, _) = parser.parse_stmt()?;
+        stmts.push(stmt);
+    }
+
+    let close = parser.span();
+    Ok(Program {
+        functions,
+        body: Block {
+            stmts,
+            tail: None,
+            close,
+        },
+    })
+}
@@ -0,0 +1,425 @@
+// Playground
+// Runs a trainee's snippet offline: wrapped in `fn main` unless it has one,
+// compiled by the local rustc against this library, then run with limits on
+// CPU time, memory and file size, and killed if it outlives the time limit.
+// rustc's errors point at the snippet's own lines.
+//
+// Snippets can call the lesson helpers without importing them:
+//
+//     let name = find_user(1).unwrap_or_default();
+//     println!("{}", longest(&name, "Bob"));
+
+use crate::exercises::rustc;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How much of each output stream is kept; the rest is read and dropped.
+const OUTPUT_LIMIT: usize = 64 * 1024;
+
+// Brings the lesson helpers in; the snippet's own items shadow them
+const PRELUDE: &str = "#[allow(unused_imports)]\nuse learn_rust::lessons::prelude::*;\n";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    pub cpu_seconds: u64,
+    pub memory_bytes: u64,
+    pub file_bytes: u64,
+    pub wall_clock: Duration,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits::seconds(5)
+    }
+}
+
+impl Limits {
+    /// `seconds` of CPU time, twice that on the clock.
+    pub fn seconds(seconds: u64) -> Limits {
+        Limits {
+            cpu_seconds: seconds,
+            memory_bytes: 512 * 1024 * 1024,
+            file_bytes: 16 * 1024 * 1024,
+            wall_clock: Duration::from_secs(seconds * 2),
+        }
+    }
+}
+
+/// A snippet made into a whole program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub source: String,
+    pub offset: usize, // lines added above the snippet's first line
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// rustc's errors, with the snippet's file name and line numbers.
+    CompileError(String),
+    Ran(Run),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub stdout: String,
+    pub stderr: String,
+    pub warnings: String,       // from rustc, mapped like errors
+    pub code: Option<i32>,      // None when killed
+    pub killed: Option<String>, // why, when a limit was hit
+}
+
+// ============================================================================
+// Wrapping and Mapping
+// ============================================================================
+
+/// The snippet as a program: inside `fn main` unless it declares one.
+pub fn wrap(snippet: &str) -> Program {
+    let has_main = snippet
+        .lines()
+        .any(|line| line.trim_start().starts_with("fn main("));
+    if has_main {
+        Program {
+            source: format!("{}{}", PRELUDE, snippet),
+            offset: PRELUDE.lines().count(),
+        }
+    } else {
+        Program {
+            source: format!("{}fn main() {{\n{}\n}}\n", PRELUDE, snippet),
+            offset: PRELUDE.lines().count() + 1,
+        }
+    }
+}
+
+/// rustc's output for `compiled` (the file it was given) rewritten to name
+/// `shown` and count lines from the snippet's first line.
+pub fn map_lines(stderr: &str, program: &Program, compiled: &str, shown: &str) -> String {
+    let snippet_line = |n: usize| n.saturating_sub(program.offset).max(1);
+    let location = format!("{}:", compiled);
+    let mut mapped = String::new();
+    for line in stderr.lines() {
+        // "  --> /tmp/.../play.rs:5:13"
+        let line = match line.find(&location) {
+            Some(at) => {
+                let rest = &line[at + location.len()..];
+                let (number, column) = rest.split_once(':').unwrap_or((rest, ""));
+                match number.parse::<usize>() {
+                    Ok(n) if column.is_empty() => {
+                        format!("{}{}:{}", &line[..at], shown, snippet_line(n))
+                    }
+                    Ok(n) => format!("{}{}:{}:{}", &line[..at], shown, snippet_line(n), column),
+                    Err(_) => line.to_string(),
+                }
+            }
+            None => map_gutter(line, &snippet_line),
+        };
+        mapped += &line;
+        mapped.push('\n');
+    }
+    mapped
+}
+
+// "5 |     let r = &s;" keeps its width with the number changed
+fn map_gutter(line: &str, snippet_line: &dyn Fn(usize) -> usize) -> String {
+    let Some((gutter, rest)) = line.split_once(" |") else {
+        return line.to_string();
+    };
+    match gutter.trim().parse::<usize>() {
+        Ok(n) if !gutter.trim().is_empty() => {
+            format!(
+                "{:>width$} |{}",
+                snippet_line(n),
+                rest,
+                width = gutter.len()
+            )
+        }
+        _ => line.to_string(),
+    }
+}
+
+// ============================================================================
+// Compiling and Running
+// ============================================================================
+
+// The newest build of this library, next to the running executable or
+// one directory up (examples live in target/debug/examples)
+fn library() -> Option<PathBuf> {
+    let exe = env::current_exe().ok()?;
+    let dir = exe.parent()?;
+    [dir.join("deps"), dir.parent()?.join("deps")]
+        .iter()
+        .filter_map(|deps| fs::read_dir(deps).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.starts_with("liblearn_rust-") && name.ends_with(".rlib")
+        })
+        .max_by_key(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+}
+
+/// Compiles and runs `snippet`, reporting errors against `shown`, the name
+/// the trainee knows it by.
+pub fn play(snippet: &str, shown: &str, limits: Limits) -> Result<Outcome, String> {
+    // A directory per call, as tests play snippets in parallel
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let dir = env::temp_dir().join(format!(
+        "learn-play-{}-{}",
+        std::process::id(),
+        CALLS.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let result = compile_and_run(snippet, shown, limits, &dir);
+    fs::remove_dir_all(&dir).ok();
+    result
+}
+
+fn compile_and_run(
+    snippet: &str,
+    shown: &str,
+    limits: Limits,
+    dir: &Path,
+) -> Result<Outcome, String> {
+    let program = wrap(snippet);
+    let file = dir.join("play.rs");
+    let binary = dir.join(format!("play{}", env::consts::EXE_SUFFIX));
+    fs::write(&file, &program.source).map_err(|e| e.to_string())?;
+
+    let library = library().ok_or("can't find the learn_rust library; run `cargo build` first")?;
+    let deps = library.parent().unwrap_or(dir);
+    let output = Command::new(rustc())
+        .args([
+            "--edition",
+            "2021",
+            "--color",
+            "never",
+            "--crate-name",
+            "play",
+        ])
+        .arg("--extern")
+        .arg(format!("learn_rust={}", library.display()))
+        .arg("-L")
+        .arg(format!("dependency={}", deps.display()))
+        .arg("-o")
+        .arg(&binary)
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("failed to run rustc: {}", e))?;
+
+    let compiled = file.display().to_string();
+    let stderr = map_lines(
+        &String::from_utf8_lossy(&output.stderr),
+        &program,
+        &compiled,
+        shown,
+    );
+    if !output.status.success() {
+        return Ok(Outcome::CompileError(stderr));
+    }
+
+    let mut run = run(&binary, limits)?;
+    run.warnings = stderr;
+    Ok(Outcome::Ran(run))
+}
+
+// Keeps the first OUTPUT_LIMIT bytes of a stream, draining the rest
+fn collect(mut stream: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut kept = Vec::new();
+        let mut buf = [0u8; 8192];
+        while let Ok(n) = stream.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            let room = OUTPUT_LIMIT.saturating_sub(kept.len());
+            kept.extend_from_slice(&buf[..n.min(room)]);
+        }
+        let mut text = String::from_utf8_lossy(&kept).into_owned();
+        if kept.len() == OUTPUT_LIMIT {
+            text += "\n[output cut short]\n";
+        }
+        text
+    })
+}
+
+fn run(binary: &Path, limits: Limits) -> Result<Run, String> {
+    let mut command = Command::new(binary);
+    command
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    rlimits::apply(&mut command, limits);
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("failed to run the snippet: {}", e))?;
+    let stdout = collect(child.stdout.take().ok_or("no output")?);
+    let stderr = collect(child.stderr.take().ok_or("no output")?);
+
+    let started = Instant::now();
+    let mut timed_out = false;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if started.elapsed() > limits.wall_clock {
+            timed_out = true;
+            child.kill().ok();
+            break child.wait().map_err(|e| e.to_string())?;
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+
+    let killed = if timed_out {
+        Some(format!(
+            "was still running after {}s",
+            limits.wall_clock.as_secs()
+        ))
+    } else {
+        rlimits::reason(&status, limits)
+    };
+    Ok(Run {
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        warnings: String::new(),
+        code: status.code(),
+        killed,
+    })
+}
+
+#[cfg(unix)]
+mod rlimits {
+    use super::Limits;
+    use std::io;
+    use std::os::raw::c_int;
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::process::{Command, ExitStatus};
+
+    #[repr(C)]
+    struct Rlimit {
+        current: u64,
+        max: u64,
+    }
+
+    extern "C" {
+        fn setrlimit(resource: c_int, limit: *const Rlimit) -> c_int;
+    }
+
+    const RLIMIT_CPU: c_int = 0;
+    const RLIMIT_FSIZE: c_int = 1;
+    const RLIMIT_CORE: c_int = 4;
+    #[cfg(target_os = "linux")]
+    const RLIMIT_AS: c_int = 9;
+    #[cfg(not(target_os = "linux"))]
+    const RLIMIT_AS: c_int = 5;
+
+    const SIGKILL: i32 = 9;
+    const SIGSEGV: i32 = 11;
+    const SIGXCPU: i32 = 24;
+    const SIGXFSZ: i32 = 25;
+
+    /// Sets the limits in the child, between fork and exec.
+    pub fn apply(command: &mut Command, limits: Limits) {
+        let set = move |resource, soft: u64, hard: u64| {
+            let limit = Rlimit {
+                current: soft,
+                max: hard,
+            };
+            // SAFETY: setrlimit only reads the struct
+            if unsafe { setrlimit(resource, &limit) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        };
+        // SAFETY: the closure only calls setrlimit, which is safe to call
+        // between fork and exec
+        unsafe {
+            command.pre_exec(move || {
+                // SIGXCPU at the soft limit, SIGKILL a second later
+                set(RLIMIT_CPU, limits.cpu_seconds, limits.cpu_seconds + 1)?;
+                set(RLIMIT_AS, limits.memory_bytes, limits.memory_bytes)?;
+                set(RLIMIT_FSIZE, limits.file_bytes, limits.file_bytes)?;
+                set(RLIMIT_CORE, 0, 0)
+            });
+        }
+    }
+
+    /// Which limit, if any, the signal that ended the run points at.
+    pub fn reason(status: &ExitStatus, limits: Limits) -> Option<String> {
+        match status.signal()? {
+            SIGXCPU | SIGKILL => Some(format!(
+                "used more than {}s of CPU time",
+                limits.cpu_seconds
+            )),
+            SIGXFSZ => Some(format!(
+                "wrote a file bigger than {} MB",
+                limits.file_bytes / (1024 * 1024)
+            )),
+            SIGSEGV => Some(String::from("crashed (a stack overflow, or out of memory)")),
+            signal => Some(format!("was killed by signal {}", signal)),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod rlimits {
+    use super::Limits;
+    use std::process::ExitStatus;
+
+    // Only the time limit applies here
+    pub fn reason(_status: &ExitStatus, _limits: Limits) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_snippets_in_main() {
+        let program = wrap("let x = 1;\nprintln!(\"{}\", x);");
+        assert!(program
+            .source
+            .ends_with("fn main() {\nlet x = 1;\nprintln!(\"{}\", x);\n}\n"));
+        assert_eq!(
+            program.source.lines().nth(program.offset),
+            Some("let x = 1;")
+        );
+
+        let program = wrap("fn main() {\n    println!(\"hi\");\n}\n");
+        assert_eq!(
+            program.source.lines().nth(program.offset),
+            Some("fn main() {")
+        );
+    }
+
+    #[test]
+    fn maps_errors_to_snippet_lines() {
+        let program = wrap("let s = String::new();\nlet t = s;\nprintln!(\"{}\", s);");
+        let stderr = "\
+error[E0382]: borrow of moved value: `s`
+ --> /tmp/x/play.rs:6:16
+  |
+4 | let s = String::new();
+  |     - move occurs because `s` has type `String`
+5 | let t = s;
+  |         - value moved here
+6 | println!(\"{}\", s);
+  |                ^ value borrowed here after move
+";
+        let mapped = map_lines(stderr, &program, "/tmp/x/play.rs", "moved.rs");
+        assert!(mapped.contains(" --> moved.rs:3:16\n"));
+        assert!(mapped.contains("1 | let s = String::new();\n"));
+        assert!(mapped.contains("3 | println!(\"{}\", s);\n"));
+        assert!(mapped.contains("  |         - value moved here\n"));
+    }
+}
@@ -0,0 +1,321 @@
+// Progress Store
+// What a trainee has done, kept in a small text file in their data directory
+//
+//     hints longest 2          two hints revealed for the `longest` exercise
+//     viewed borrowing 3 1760745600    section 3 of `borrowing` first seen then
+//     passed first_word 1760832000     when `first_word` first passed
+//     card borrowing/85d5f095 3 2.36 15 20380
+//                                      a `borrowing` takeaway, by a hash of
+//                                      its text: reviewed 3 times in a row,
+//                                      ease 2.36, every 15 days, next on
+//                                      day 20380
+//
+// Times are seconds since the Unix epoch, days are days since it.
+
+use crate::exercises;
+use crate::lessons::Entry;
+use crate::review::Schedule;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where the progress file lives: $LEARN_RUST_DATA_DIR if set, otherwise
+/// the platform's per-user data directory.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("LEARN_RUST_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    let home = || env::var_os("HOME").map(PathBuf::from).unwrap_or_default();
+    let base = if cfg!(windows) {
+        env::var_os("APPDATA")
+            .map(PathBuf::from)
+            .unwrap_or_else(home)
+    } else if cfg!(target_os = "macos") {
+        home().join("Library/Application Support")
+    } else {
+        env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home().join(".local/share"))
+    };
+    base.join("learn-rust")
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Progress {
+    pub hints_used: BTreeMap<String, usize>,
+    pub viewed: BTreeMap<String, BTreeMap<usize, u64>>, // lesson id -> section -> time
+    pub passed: BTreeMap<String, u64>,                  // exercise -> time
+    pub cards: BTreeMap<String, Schedule>,              // takeaway card -> schedule
+}
+
+impl Progress {
+    pub fn path() -> PathBuf {
+        data_dir().join("progress.txt")
+    }
+
+    /// Loads the trainee's progress; a missing file means a fresh start.
+    pub fn load() -> Result<Progress, String> {
+        Progress::load_from(&Progress::path())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Progress, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Progress::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        self.save_to(&Progress::path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(path, self.to_text()).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Progress, String> {
+        let mut progress = Progress::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["hints", exercise, count] => {
+                    let count = count
+                        .parse()
+                        .map_err(|_| format!("line {}: bad hint count `{}`", i + 1, count))?;
+                    progress.hints_used.insert(exercise.to_string(), count);
+                }
+                ["viewed", lesson, section, time] => {
+                    let section = section
+                        .parse()
+                        .map_err(|_| format!("line {}: bad section `{}`", i + 1, section))?;
+                    let time = parse_time(time).map_err(|e| format!("line {}: {}", i + 1, e))?;
+                    progress.view(lesson, section, time);
+                }
+                ["passed", exercise, time] => {
+                    let time = parse_time(time).map_err(|e| format!("line {}: {}", i + 1, e))?;
+                    progress.pass(exercise, time);
+                }
+                ["card", card, repetitions, ease, interval, due] => {
+                    let bad = || format!("line {}: bad card schedule `{}`", i + 1, line);
+                    let schedule = Schedule {
+                        repetitions: repetitions.parse().map_err(|_| bad())?,
+                        ease: ease.parse().map_err(|_| bad())?,
+                        interval: interval.parse().map_err(|_| bad())?,
+                        due: due.parse().map_err(|_| bad())?,
+                    };
+                    progress.cards.insert(card.to_string(), schedule);
+                }
+                _ => return Err(format!("line {}: can't read `{}`", i + 1, line)),
+            }
+        }
+
+        Ok(progress)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# learn-rust progress\n");
+        for (exercise, count) in &self.hints_used {
+            text += &format!("hints {} {}\n", exercise, count);
+        }
+        for (lesson, sections) in &self.viewed {
+            for (section, time) in sections {
+                text += &format!("viewed {} {} {}\n", lesson, section, time);
+            }
+        }
+        for (exercise, time) in &self.passed {
+            text += &format!("passed {} {}\n", exercise, time);
+        }
+        for (card, s) in &self.cards {
+            text += &format!(
+                "card {} {} {:.2} {} {}\n",
+                card, s.repetitions, s.ease, s.interval, s.due
+            );
+        }
+        text
+    }
+
+    pub fn hints_used(&self, exercise: &str) -> usize {
+        self.hints_used.get(exercise).copied().unwrap_or(0)
+    }
+
+    /// Records that the first `count` hints of `exercise` have been seen.
+    pub fn use_hints(&mut self, exercise: &str, count: usize) {
+        let used = self.hints_used.entry(exercise.to_string()).or_insert(0);
+        *used = (*used).max(count);
+    }
+
+    /// Records that section `section` (numbered from 1) of a lesson was
+    /// viewed; the first time is kept.
+    pub fn view(&mut self, lesson: &str, section: usize, time: u64) {
+        let sections = self.viewed.entry(lesson.to_string()).or_default();
+        let first = sections.entry(section).or_insert(time);
+        *first = (*first).min(time);
+    }
+
+    /// How many of the lesson's sections were viewed. Numbers recorded for
+    /// sections the lesson no longer has don't count.
+    pub fn sections_viewed(&self, entry: &Entry) -> usize {
+        let sections = 1..=entry.lesson.sections.len();
+        self.viewed.get(entry.id).map_or(0, |viewed| {
+            viewed.keys().filter(|n| sections.contains(n)).count()
+        })
+    }
+
+    pub fn has_viewed(&self, lesson: &str, section: usize) -> bool {
+        self.viewed
+            .get(lesson)
+            .is_some_and(|sections| sections.contains_key(&section))
+    }
+
+    /// Records that an exercise passed; the first time is kept.
+    pub fn pass(&mut self, exercise: &str, time: u64) {
+        let first = self.passed.entry(exercise.to_string()).or_insert(time);
+        *first = (*first).min(time);
+    }
+
+    pub fn has_passed(&self, exercise: &str) -> bool {
+        self.passed.contains_key(exercise)
+    }
+
+    /// Every section of the lesson seen and every exercise for it passed.
+    pub fn has_finished(&self, entry: &Entry) -> bool {
+        self.sections_viewed(entry) == entry.lesson.sections.len()
+            && exercises::for_lesson(entry)
+                .iter()
+                .all(|e| self.has_passed(e.name))
+    }
+}
+
+fn parse_time(text: &str) -> Result<u64, String> {
+    text.parse().map_err(|_| format!("bad time `{}`", text))
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Formats a time from the progress file as a UTC date, e.g. "2025-10-18".
+pub fn date(time: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm
+    let days = (time / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    #[test]
+    fn round_trips_through_text() {
+        let mut progress = Progress::default();
+        progress.use_hints("longest", 2);
+        progress.use_hints("first_word", 1);
+        progress.use_hints("longest", 1); // never goes back down
+
+        let parsed = Progress::parse(&progress.to_text()).unwrap();
+        assert_eq!(parsed, progress);
+        assert_eq!(parsed.hints_used("longest"), 2);
+        assert_eq!(parsed.hints_used("double_all"), 0);
+    }
+
+    #[test]
+    fn keeps_first_view_and_pass_times() {
+        let mut progress = Progress::default();
+        progress.view("borrowing", 2, 200);
+        progress.view("borrowing", 2, 100);
+        progress.view("borrowing", 1, 300);
+        progress.pass("first_word", 500);
+        progress.pass("first_word", 900);
+
+        let parsed = Progress::parse(&progress.to_text()).unwrap();
+        assert_eq!(parsed, progress);
+        assert_eq!(parsed.viewed["borrowing"][&2], 100);
+        assert_eq!(
+            parsed.sections_viewed(lessons::find("borrowing").unwrap()),
+            2
+        );
+        assert!(parsed.has_viewed("borrowing", 1));
+        assert!(!parsed.has_viewed("lifetimes", 1));
+        assert_eq!(parsed.passed["first_word"], 500);
+        assert!(!parsed.has_passed("longest"));
+    }
+
+    #[test]
+    fn finishes_only_the_sections_a_lesson_has() {
+        let entry = lessons::find("dereference").unwrap();
+        let sections = entry.lesson.sections.len();
+        let mut progress = Progress::default();
+        for section in 2..=sections + 1 {
+            progress.view(entry.id, section, 0);
+        }
+        assert_eq!(progress.sections_viewed(entry), sections - 1);
+        assert!(!progress.has_finished(entry));
+
+        progress.view(entry.id, 1, 0);
+        for exercise in exercises::for_lesson(entry) {
+            progress.pass(exercise.name, 0);
+        }
+        assert_eq!(progress.sections_viewed(entry), sections);
+        assert!(progress.has_finished(entry));
+    }
+
+    #[test]
+    fn round_trips_card_schedules() {
+        let mut progress = Progress::default();
+        let schedule = Schedule {
+            repetitions: 3,
+            ease: 2.36,
+            interval: 15,
+            due: 20380,
+        };
+        progress
+            .cards
+            .insert(String::from("borrowing/85d5f095"), schedule);
+        assert_eq!(
+            progress.to_text(),
+            "# learn-rust progress\ncard borrowing/85d5f095 3 2.36 15 20380\n"
+        );
+        assert_eq!(Progress::parse(&progress.to_text()).unwrap(), progress);
+        assert!(Progress::parse("card borrowing/85d5f095 3 easy 15 20380\n").is_err());
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(1_760_745_600), "2025-10-18");
+    }
+
+    #[test]
+    fn rejects_unknown_lines() {
+        assert!(Progress::parse("hints longest two\n").is_err());
+        assert!(Progress::parse("something else\n").is_err());
+        assert!(Progress::parse("viewed borrowing one 100\n").is_err());
+        assert!(Progress::parse("passed longest yesterday\n").is_err());
+    }
+}
@@ -0,0 +1,420 @@
+// Quizzes
+// "What does this print?" and "does this compile?" questions about the
+// lessons. Answers aren't written down: output questions are marked against
+// the section's real output, and compile questions against rustc itself.
+
+use crate::exercises::{error_codes, rustc};
+use crate::lessons;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ============================================================================
+// Questions
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    /// The answer is the rest of the first output line starting with this.
+    Predict(String),
+    /// The answer is whether the snippet, a whole program, compiles.
+    Compiles,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Question {
+    pub lesson: String, // lesson id, as in `learn run`
+    pub section: usize, // numbered from 1
+    pub kind: Kind,
+    pub ask: String,
+    pub snippet: String,
+    pub choices: Vec<String>, // empty for a free-text answer
+}
+
+impl Question {
+    /// The right answer, read from the output of the question's section.
+    /// Only for `Kind::Predict` questions.
+    pub fn answer_from_output(&self, output: &str) -> Option<String> {
+        let Kind::Predict(prefix) = &self.kind else {
+            return None;
+        };
+        output
+            .lines()
+            .find_map(|line| line.trim_start().strip_prefix(prefix.as_str()))
+            .map(|rest| rest.trim().to_string())
+    }
+
+    /// Whether `given` matches `answer`. A number picks that choice, and
+    /// differences in spacing are ignored.
+    pub fn is_right(&self, answer: &str, given: &str) -> bool {
+        let given = given.trim();
+        let given = match given.parse::<usize>() {
+            Ok(n) if n >= 1 && n <= self.choices.len() => self.choices[n - 1].as_str(),
+            _ => given,
+        };
+        let given = match (&self.kind, given.to_lowercase().as_str()) {
+            (Kind::Compiles, "y") => "yes",
+            (Kind::Compiles, "n") => "no",
+            _ => given,
+        };
+        let words = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+        words(given).eq_ignore_ascii_case(&words(answer))
+    }
+}
+
+// ============================================================================
+// Question Bank Files
+// ============================================================================
+
+/// The bundled question banks, one per lesson.
+pub const BANKS: [(&str, &str); 5] = [
+    (
+        "ownership-basics.txt",
+        include_str!("questions/ownership-basics.txt"),
+    ),
+    (
+        "mutable-borrowing.txt",
+        include_str!("questions/mutable-borrowing.txt"),
+    ),
+    ("lifetimes.txt", include_str!("questions/lifetimes.txt")),
+    ("option.txt", include_str!("questions/option.txt")),
+    ("result.txt", include_str!("questions/result.txt")),
+];
+
+/// Every bundled question, bank by bank.
+pub fn bundled() -> Result<Vec<Question>, String> {
+    let mut questions = Vec::new();
+    for (name, text) in BANKS {
+        questions.extend(parse_bank(text).map_err(|e| format!("{}: {}", name, e))?);
+    }
+    Ok(questions)
+}
+
+/// Reads a question bank:
+///
+/// ```text
+/// # comment
+/// == option 6                         lesson id and section number
+/// ask: What is printed for ID 2?      optional, replaces the default question
+/// predict: Long username for ID 2:    or `compiles`
+///     println!("Long username for ID 2: {:?}", get_long_username(2));
+/// choices: Some("Bob") | None         optional; free text without it
+/// ```
+///
+/// The snippet is indented four spaces. A `compiles` snippet must be a
+/// whole program; a `predict` snippet is only shown.
+pub fn parse_bank(text: &str) -> Result<Vec<Question>, String> {
+    let mut questions: Vec<Question> = Vec::new();
+    let mut ask: Option<String> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", i + 1, message);
+
+        if let Some(header) = line.strip_prefix("== ") {
+            finish(&mut questions, ask.take()).map_err(|e| error(&e))?;
+            let (lesson, section) = header
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| error("expected `== <lesson> <section>`"))?;
+            let section = section
+                .trim()
+                .parse()
+                .map_err(|_| error("the section must be a number"))?;
+            questions.push(Question {
+                lesson: lesson.to_string(),
+                section,
+                kind: Kind::Predict(String::new()),
+                ask: String::new(),
+                snippet: String::new(),
+                choices: Vec::new(),
+            });
+            continue;
+        }
+        if line.starts_with('#') || (line.trim().is_empty() && questions.is_empty()) {
+            continue;
+        }
+
+        let question = questions
+            .last_mut()
+            .ok_or_else(|| error("expected a `== <lesson> <section>` line"))?;
+        if let Some(snippet_line) = line.strip_prefix("    ") {
+            question.snippet += snippet_line;
+            question.snippet.push('\n');
+        } else if line.trim().is_empty() {
+            if !question.snippet.is_empty() {
+                question.snippet.push('\n');
+            }
+        } else if let Some(text) = line.strip_prefix("ask:") {
+            ask = Some(text.trim().to_string());
+        } else if let Some(prefix) = line.strip_prefix("predict:") {
+            question.kind = Kind::Predict(prefix.trim().to_string());
+        } else if line.trim() == "compiles" {
+            question.kind = Kind::Compiles;
+        } else if let Some(choices) = line.strip_prefix("choices:") {
+            question.choices = choices.split('|').map(|c| c.trim().to_string()).collect();
+        } else {
+            return Err(error(&format!("can't read `{}`", line)));
+        }
+    }
+
+    finish(&mut questions, ask).map_err(|e| format!("at the end: {}", e))?;
+    Ok(questions)
+}
+
+// Fills in defaults for the last question and checks it's complete
+fn finish(questions: &mut [Question], ask: Option<String>) -> Result<(), String> {
+    let Some(question) = questions.last_mut() else {
+        return Ok(());
+    };
+    question.snippet = question.snippet.trim_end().to_string();
+    if question.snippet.is_empty() {
+        return Err(String::from("the question has no snippet"));
+    }
+
+    match &question.kind {
+        Kind::Predict(prefix) if prefix.is_empty() => {
+            return Err(String::from("expected `predict: <text>` or `compiles`"));
+        }
+        Kind::Predict(prefix) => {
+            question.ask = ask.unwrap_or_else(|| format!("What is printed after `{}`?", prefix));
+        }
+        Kind::Compiles => {
+            question.ask = ask.unwrap_or_else(|| String::from("Does this compile?"));
+            if question.choices.is_empty() {
+                question.choices = vec![String::from("yes"), String::from("no")];
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Asking the Compiler
+// ============================================================================
+
+/// Compiles a `Kind::Compiles` snippet. Returns rustc's first error line,
+/// or None when it compiles.
+pub fn compile_error(snippet: &str) -> Result<Option<String>, String> {
+    // A directory per call, as tests compile snippets in parallel
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let dir = env::temp_dir().join(format!(
+        "learn-quiz-{}-{}",
+        std::process::id(),
+        CALLS.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let file = dir.join("quiz.rs");
+    fs::write(&file, snippet).map_err(|e| e.to_string())?;
+
+    // metadata only: borrow checking runs, linking doesn't
+    let output = Command::new(rustc())
+        .args(["--edition", "2021", "--emit=metadata", "--color", "never"])
+        .args(["-A", "warnings", "--out-dir"])
+        .arg(&dir)
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("failed to run rustc: {}", e));
+    fs::remove_dir_all(&dir).ok();
+    let output = output?;
+
+    if output.status.success() {
+        return Ok(None);
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let first = stderr
+        .lines()
+        .find(|line| line.starts_with("error"))
+        .unwrap_or("error");
+    // Prefer a line with a code, e.g. "error[E0499]: ..."
+    let coded = error_codes(&stderr)
+        .first()
+        .and_then(|code| stderr.lines().find(|l| l.contains(&format!("[{}]", code))));
+    Ok(Some(coded.unwrap_or(first).to_string()))
+}
+
+// ============================================================================
+// Asking the Trainee
+// ============================================================================
+
+/// The right answer and a remark to show with it. `output` returns what a
+/// lesson's section (numbered from 1) prints.
+pub fn answer(
+    question: &Question,
+    output: &mut dyn FnMut(&str, usize) -> Result<String, String>,
+) -> Result<(String, String), String> {
+    match &question.kind {
+        Kind::Predict(prefix) => {
+            let printed = output(&question.lesson, question.section)?;
+            let answer = question.answer_from_output(&printed).ok_or_else(|| {
+                format!(
+                    "{} section {} prints no line starting `{}`",
+                    question.lesson, question.section, prefix
+                )
+            })?;
+            Ok((answer, String::new()))
+        }
+        Kind::Compiles => match compile_error(&question.snippet)? {
+            Some(error) => Ok((String::from("no"), error)),
+            None => Ok((String::from("yes"), String::from("It compiles."))),
+        },
+    }
+}
+
+/// Asks each question in turn and prints the score. `read` shows a prompt
+/// and returns the reply; running out of replies ends the quiz early.
+pub fn ask(
+    questions: &[Question],
+    output: &mut dyn FnMut(&str, usize) -> Result<String, String>,
+    read: &mut dyn FnMut(&str) -> Option<String>,
+) -> Result<(), String> {
+    // Several questions can share a section, which only needs to run once
+    let mut outputs: HashMap<(String, usize), String> = HashMap::new();
+    let mut cached = |lesson: &str, section: usize| -> Result<String, String> {
+        let key = (lesson.to_string(), section);
+        if !outputs.contains_key(&key) {
+            let printed = output(lesson, section)?;
+            outputs.insert(key.clone(), printed);
+        }
+        Ok(outputs[&key].clone())
+    };
+    let mut score = 0;
+    let mut asked = 0;
+
+    for (i, question) in questions.iter().enumerate() {
+        let entry = lessons::find(&question.lesson)
+            .ok_or_else(|| format!("no lesson named `{}`", question.lesson))?;
+        let section = question
+            .section
+            .checked_sub(1)
+            .and_then(|i| entry.lesson.sections.get(i))
+            .ok_or_else(|| format!("{} has no section {}", entry.id, question.section))?;
+        println!(
+            "\nQuestion {}/{} ({}, {}. {})\n",
+            i + 1,
+            questions.len(),
+            entry.lesson.title,
+            question.section,
+            section.title
+        );
+        for line in question.snippet.lines() {
+            if line.is_empty() {
+                println!();
+            } else {
+                println!("   {}", line);
+            }
+        }
+        println!("\n{}", question.ask);
+        for (n, choice) in question.choices.iter().enumerate() {
+            println!("   {}) {}", n + 1, choice);
+        }
+
+        let Some(given) = read("> ") else {
+            println!();
+            break;
+        };
+        asked += 1;
+        let (answer, remark) = answer(question, &mut cached)?;
+        if question.is_right(&answer, &given) {
+            score += 1;
+            println!("Right!");
+        } else {
+            println!("Not quite - the answer is: {}", answer);
+        }
+        if !remark.is_empty() {
+            println!("   {}", remark);
+        }
+    }
+
+    println!("\nScore: {}/{}", score, asked);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BANK: &str = "\
+# A test bank
+
+== option 6
+predict: Long username for ID 2:
+    println!(\"Long username for ID 2: {:?}\", get_long_username(2));
+choices: Some(\"Bob\") | None
+
+== mutable-borrowing 2
+ask: Two mutable borrows at once?
+compiles
+    fn main() {
+        let mut s = String::new();
+
+        let a = &mut s;
+    }
+";
+
+    #[test]
+    fn parses_both_kinds_of_question() {
+        let questions = parse_bank(BANK).unwrap();
+        assert_eq!(questions.len(), 2);
+
+        assert_eq!(
+            (questions[0].lesson.as_str(), questions[0].section),
+            ("option", 6)
+        );
+        assert_eq!(
+            questions[0].kind,
+            Kind::Predict(String::from("Long username for ID 2:"))
+        );
+        assert_eq!(
+            questions[0].ask,
+            "What is printed after `Long username for ID 2:`?"
+        );
+        assert_eq!(questions[0].choices, ["Some(\"Bob\")", "None"]);
+
+        assert_eq!(questions[1].kind, Kind::Compiles);
+        assert_eq!(questions[1].ask, "Two mutable borrows at once?");
+        assert_eq!(questions[1].choices, ["yes", "no"]);
+        assert_eq!(questions[1].snippet.lines().count(), 5);
+        assert!(questions[1].snippet.ends_with("    let a = &mut s;\n}"));
+    }
+
+    #[test]
+    fn rejects_incomplete_questions() {
+        assert!(parse_bank("== option six\npredict: x\n    x\n").is_err());
+        assert!(parse_bank("== option 6\n    x\n").is_err());
+        assert!(parse_bank("== option 6\npredict: x\n").is_err());
+        assert!(parse_bank("predict: x\n").is_err());
+        assert!(parse_bank("== option 6\nanswer: x\n").is_err());
+    }
+
+    #[test]
+    fn marks_answers() {
+        let questions = parse_bank(BANK).unwrap();
+        let output =
+            "   Long username for ID 1: Some(\"Alice\")\n   Long username for ID 2: None\n";
+        let answer = questions[0].answer_from_output(output).unwrap();
+        assert_eq!(answer, "None");
+        assert!(questions[0].is_right(&answer, "2"));
+        assert!(questions[0].is_right(&answer, " none "));
+        assert!(!questions[0].is_right(&answer, "1"));
+
+        assert!(questions[1].is_right("no", "N"));
+        assert!(questions[1].is_right("no", "2"));
+        assert!(!questions[1].is_right("no", "yes"));
+    }
+
+    #[test]
+    fn bundled_questions_point_at_real_sections() {
+        for question in bundled().unwrap() {
+            let entry = lessons::find(&question.lesson)
+                .unwrap_or_else(|| panic!("no lesson `{}`", question.lesson));
+            assert!(
+                (1..=entry.lesson.sections.len()).contains(&question.section),
+                "{} has no section {}",
+                question.lesson,
+                question.section
+            );
+        }
+    }
+}
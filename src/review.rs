@@ -0,0 +1,306 @@
+// Spaced Repetition
+// Every lesson's key takeaways become flashcards, scheduled with SM-2: a card
+// you remember comes back after 1 day, then 6, then growing by its ease
+// factor; one you forget starts again tomorrow.
+
+use crate::lessons::{self, Entry, CURRICULUM};
+use crate::progress::Progress;
+use std::collections::HashSet;
+
+/// How many never-seen cards one review brings in.
+pub const NEW_CARDS_PER_REVIEW: usize = 10;
+
+// ============================================================================
+// Cards
+// ============================================================================
+
+/// One takeaway as a fill-in-the-blank card.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub id: String, // "mutable-borrowing/6a3f09c2", the lesson and a hash of the text
+    pub lesson: &'static str,
+    pub text: &'static str,
+}
+
+impl Card {
+    /// The card for one of the lesson's takeaways. Its id follows the text,
+    /// not its position, so adding or reordering takeaways keeps every
+    /// schedule on the card it was made for.
+    pub fn new(entry: &Entry, text: &'static str) -> Card {
+        Card {
+            id: format!("{}/{:08x}", entry.id, fnv1a(text)),
+            lesson: entry.lesson.title,
+            text,
+        }
+    }
+
+    /// The takeaway with its key term blanked out, and the term.
+    pub fn cloze(&self) -> (String, &'static str) {
+        let term = key_term(self.text);
+        (self.text.replacen(term, "____", 1), term)
+    }
+}
+
+/// Every takeaway in curriculum order.
+pub fn cards() -> Vec<Card> {
+    let mut cards = Vec::new();
+    for entry in &CURRICULUM {
+        for takeaway in entry.lesson.takeaways {
+            cards.push(Card::new(entry, takeaway));
+        }
+    }
+    cards
+}
+
+/// Drops the schedules of cards whose takeaway was reworded or removed.
+pub fn forget_missing(progress: &mut Progress) {
+    let ids: HashSet<String> = cards().into_iter().map(|c| c.id).collect();
+    progress.cards.retain(|id, _| ids.contains(id));
+}
+
+// 32-bit FNV-1a. Ids are saved in the progress file, so the hash must not
+// change between builds the way std's DefaultHasher may
+fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+// The word worth remembering: code like `&mut`, `'static` or `.clone()`
+// first, then a SHOUTED word, then the longest word
+fn key_term(text: &str) -> &str {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .map(|w| w.trim_end_matches([',', ';', ':']))
+        .map(|w| match w.strip_prefix('(') {
+            Some(inner) => inner.trim_end_matches(')'),
+            None if !w.contains('(') => w.trim_end_matches(')'),
+            None => w,
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    // `Result<T,` is only half a type, so it can't be blanked on its own
+    let balanced = |w: &str| w.matches('<').count() == w.matches('>').count();
+    let code = words.iter().find(|w| {
+        balanced(w)
+            && (w.starts_with(['&', '*', '\'', '.', '?'])
+                || w.contains('<')
+                || w.contains("::")
+                || w.ends_with("()"))
+    });
+    let shouted = words
+        .iter()
+        .find(|w| w.len() > 1 && w.chars().all(|c| c.is_ascii_uppercase()));
+    let longest = words.iter().rev().max_by_key(|w| w.chars().count());
+
+    code.or(shouted).or(longest).copied().unwrap_or(text)
+}
+
+// ============================================================================
+// SM-2 Scheduling
+// ============================================================================
+
+/// How well a card was remembered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Grade {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl Grade {
+    // SM-2's 0-5 response quality; below 3 counts as forgotten
+    fn quality(self) -> f64 {
+        match self {
+            Grade::Again => 1.0,
+            Grade::Hard => 3.0,
+            Grade::Good => 4.0,
+            Grade::Easy => 5.0,
+        }
+    }
+}
+
+/// Where a card stands. Days are counted from the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schedule {
+    pub repetitions: u32, // successful reviews in a row
+    pub ease: f64,
+    pub interval: u64, // days
+    pub due: u64,      // day
+}
+
+impl Schedule {
+    pub fn new(today: u64) -> Schedule {
+        Schedule {
+            repetitions: 0,
+            ease: 2.5,
+            interval: 0,
+            due: today,
+        }
+    }
+
+    /// The schedule after reviewing the card on `today`.
+    pub fn review(self, grade: Grade, today: u64) -> Schedule {
+        let q = grade.quality();
+        let ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(1.3);
+
+        let (repetitions, interval) = if q < 3.0 {
+            (0, 1)
+        } else {
+            let interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * ease).round() as u64,
+            };
+            (self.repetitions + 1, interval)
+        };
+
+        Schedule {
+            repetitions,
+            ease,
+            interval,
+            due: today + interval,
+        }
+    }
+}
+
+/// Today, counted in days from the Unix epoch.
+pub fn today() -> u64 {
+    crate::progress::now() / 86_400
+}
+
+/// The cards to review on `today`: those due, then up to
+/// `NEW_CARDS_PER_REVIEW` new ones from lessons the trainee has viewed.
+pub fn due(progress: &Progress, today: u64) -> Vec<Card> {
+    let all = cards();
+    let mut due: Vec<Card> = all
+        .iter()
+        .filter(|c| progress.cards.get(&c.id).is_some_and(|s| s.due <= today))
+        .cloned()
+        .collect();
+    due.sort_by_key(|c| progress.cards[&c.id].due);
+
+    let viewed = |card: &Card| {
+        let lesson = card.id.split('/').next().unwrap_or_default();
+        lessons::find(lesson).is_some_and(|entry| progress.sections_viewed(entry) > 0)
+    };
+    due.extend(
+        all.into_iter()
+            .filter(|c| !progress.cards.contains_key(&c.id) && viewed(c))
+            .take(NEW_CARDS_PER_REVIEW),
+    );
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_grow_and_reset() {
+        let mut schedule = Schedule::new(100);
+        let mut intervals = Vec::new();
+        for _ in 0..4 {
+            schedule = schedule.review(Grade::Good, schedule.due);
+            intervals.push(schedule.interval);
+        }
+        assert_eq!(intervals, [1, 6, 15, 38]);
+        assert_eq!(schedule.ease, 2.5);
+
+        let forgotten = schedule.review(Grade::Again, 200);
+        assert_eq!((forgotten.repetitions, forgotten.interval), (0, 1));
+        assert_eq!(forgotten.due, 201);
+        assert!(forgotten.ease < 2.5);
+
+        let mut hard = Schedule::new(0);
+        for _ in 0..20 {
+            hard = hard.review(Grade::Again, 0);
+        }
+        assert_eq!(hard.ease, 1.3);
+        assert!(Schedule::new(0).review(Grade::Easy, 0).ease > 2.5);
+    }
+
+    #[test]
+    fn blanks_the_key_term() {
+        let card = |text: &'static str| Card {
+            id: String::new(),
+            lesson: "",
+            text,
+        };
+        assert_eq!(
+            card("Only ONE mutable borrow at a time").cloze(),
+            (String::from("Only ____ mutable borrow at a time"), "ONE")
+        );
+        assert_eq!(card("Use &mut to borrow and modify").cloze().1, "&mut");
+        assert_eq!(
+            card("'static lives for entire program").cloze().1,
+            "'static"
+        );
+        assert_eq!(
+            card("Each value has exactly one owner").cloze().1,
+            "exactly"
+        );
+        assert_eq!(
+            card("Use .clone() for explicit deep copies").cloze().1,
+            ".clone()"
+        );
+        assert_eq!(card("Usually borrowed -> Cow<str>").cloze().1, "Cow<str>");
+        assert_eq!(
+            card("Rust often infers lifetimes (elision)").cloze().1,
+            "lifetimes"
+        );
+    }
+
+    #[test]
+    fn every_takeaway_is_a_card() {
+        let cards = cards();
+        let takeaways: usize = CURRICULUM.iter().map(|e| e.lesson.takeaways.len()).sum();
+        assert_eq!(cards.len(), takeaways);
+        for card in &cards {
+            let (front, term) = card.cloze();
+            assert!(front.contains("____") && !term.is_empty(), "{}", card.text);
+        }
+    }
+
+    #[test]
+    fn card_ids_follow_the_text() {
+        let cards = cards();
+        let ids: HashSet<&str> = cards.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids.len(), cards.len());
+
+        let borrowing = lessons::find("borrowing").unwrap();
+        let card = Card::new(borrowing, "Use & to borrow without taking ownership");
+        assert_eq!(card.id, format!("borrowing/{:08x}", fnv1a(card.text)));
+        assert_eq!(fnv1a(""), 0x811c_9dc5);
+        assert_eq!(fnv1a("a"), 0xe40c_292c);
+
+        let mut progress = Progress::default();
+        progress.cards.insert(card.id.clone(), Schedule::new(0));
+        progress
+            .cards
+            .insert(String::from("borrowing/2"), Schedule::new(0));
+        forget_missing(&mut progress);
+        assert_eq!(progress.cards.keys().collect::<Vec<_>>(), [&card.id]);
+    }
+
+    #[test]
+    fn new_cards_come_from_viewed_lessons() {
+        let mut progress = Progress::default();
+        assert!(due(&progress, 10).is_empty());
+
+        progress.view("mutable-borrowing", 1, 0);
+        let new = due(&progress, 10);
+        assert_eq!(new.len(), 5);
+        assert!(new.iter().all(|c| c.id.starts_with("mutable-borrowing/")));
+
+        // Reviewed cards wait until they're due
+        for card in &new[1..] {
+            let schedule = Schedule::new(10).review(Grade::Good, 10);
+            progress.cards.insert(card.id.clone(), schedule);
+        }
+        assert_eq!(due(&progress, 10).len(), 1);
+        assert_eq!(due(&progress, 11).len(), 5);
+    }
+}
@@ -0,0 +1,119 @@
+// Running a section in this process while reading what it prints
+//
+// Sections print with println!, so on Unix stdout's file descriptor is
+// pointed at a pipe for the length of the run and a thread passes whatever
+// arrives on to the caller. Elsewhere the section runs in a child process.
+
+use crate::lessons::Entry;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+// fd 1 is shared by the whole process, so only one section runs at a time
+static RUNNING: Mutex<()> = Mutex::new(());
+
+/// Receives a section's output as it arrives.
+pub type Sink = Box<dyn FnMut(&[u8]) + Send>;
+
+/// Runs section `index` of `entry`, handing its output to `sink` in chunks
+/// as it is printed. Returns an error message if the section panicked.
+pub fn run_section(entry: &'static Entry, index: usize, sink: Sink) -> Result<(), String> {
+    let _running = RUNNING.lock().unwrap_or_else(|e| e.into_inner());
+    io::stdout().flush().map_err(|e| e.to_string())?;
+    imp::run(entry, index, sink)
+}
+
+#[cfg(unix)]
+mod imp {
+    use super::*;
+    use std::fs::File;
+    use std::io::Read;
+    use std::os::fd::FromRawFd;
+    use std::os::raw::c_int;
+    use std::panic;
+    use std::thread;
+
+    extern "C" {
+        fn pipe(fds: *mut c_int) -> c_int;
+        fn dup(fd: c_int) -> c_int;
+        fn dup2(from: c_int, to: c_int) -> c_int;
+        fn close(fd: c_int) -> c_int;
+    }
+
+    const STDOUT: c_int = 1;
+
+    pub fn run(entry: &'static Entry, index: usize, mut sink: Sink) -> Result<(), String> {
+        let mut fds: [c_int; 2] = [0; 2];
+        // SAFETY: pipe writes two new descriptors into the array
+        if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error().to_string());
+        }
+        let [read_fd, write_fd] = fds;
+
+        // SAFETY: plain descriptor juggling; stdout was flushed by the caller
+        // and is put back below before anything else can print
+        let saved = unsafe {
+            let saved = dup(STDOUT);
+            dup2(write_fd, STDOUT);
+            close(write_fd);
+            saved
+        };
+
+        // SAFETY: read_fd is a fresh descriptor that nothing else owns
+        let mut pipe_out = unsafe { File::from_raw_fd(read_fd) };
+        let reader = thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            while let Ok(n) = pipe_out.read(&mut buf) {
+                if n == 0 {
+                    break;
+                }
+                sink(&buf[..n]);
+            }
+        });
+
+        let result = panic::catch_unwind(entry.lesson.sections[index].run);
+        io::stdout().flush().ok();
+
+        // SAFETY: restores stdout, closing the pipe's last write end so the
+        // reader sees end of file
+        unsafe {
+            dup2(saved, STDOUT);
+            close(saved);
+        }
+        reader.join().ok();
+
+        result.map_err(|panic| {
+            panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("the section panicked"))
+        })
+    }
+}
+
+#[cfg(not(unix))]
+mod imp {
+    use super::*;
+    use std::env;
+    use std::process::Command;
+
+    // `learn output <lesson> <n>` prints the section with its heading line
+    pub fn run(entry: &'static Entry, index: usize, mut sink: Sink) -> Result<(), String> {
+        let exe = env::current_exe().map_err(|e| e.to_string())?;
+        let output = Command::new(exe)
+            .args(["output", entry.id, &(index + 1).to_string()])
+            .output()
+            .map_err(|e| e.to_string())?;
+        let stdout = output.stdout;
+        let body = match stdout.iter().position(|&b| b == b'\n') {
+            Some(i) => &stdout[i + 1..],
+            None => &stdout[..],
+        };
+        sink(body);
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from("the section failed"))
+        }
+    }
+}
@@ -0,0 +1,271 @@
+// Lesson Server
+// A small HTTP/1.1 server on std::net for browsing and running the lessons
+//
+//     GET  /                   the lessons, by topic
+//     GET  /lesson/<id>        a lesson's sections and takeaways
+//     GET  /lesson/<id>/<n>    section n's source and output, with a Run button
+//     POST /run/<id>/<n>       runs section n, streaming its output as it prints
+
+mod capture;
+
+use crate::book::{escape, highlight, html_page, section_heading, section_source};
+use crate::lessons::{self, Entry};
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const MAX_REQUEST: usize = 8 * 1024;
+
+/// Answers requests on `listener` until the process is stopped, one thread
+/// per connection.
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            // A client that goes away mid-response is its own problem
+            handle(stream).ok();
+        });
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Requests and Responses
+// ============================================================================
+
+// "GET /lesson/option HTTP/1.1" -> ("GET", "/lesson/option")
+fn read_request(stream: &mut TcpStream) -> io::Result<Option<(String, String)>> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf)?;
+        if n == 0 || request.len() > MAX_REQUEST {
+            return Ok(None);
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+
+    let text = String::from_utf8_lossy(&request);
+    let mut parts = text.lines().next().unwrap_or_default().split(' ');
+    match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => {
+            // The query string isn't used
+            let path = path.split('?').next().unwrap_or(path);
+            Ok(Some((method.to_string(), path.to_string())))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
+
+fn not_found(stream: &mut TcpStream) -> io::Result<()> {
+    let body = html_page(
+        "Not found",
+        "<h1>Not found</h1>\n<p><a href=\"/\">Back to the lessons</a></p>\n",
+    );
+    respond(stream, "404 Not Found", "text/html; charset=utf-8", &body)
+}
+
+fn handle(mut stream: TcpStream) -> io::Result<()> {
+    let Some((method, path)) = read_request(&mut stream)? else {
+        return respond(
+            &mut stream,
+            "400 Bad Request",
+            "text/plain",
+            "bad request\n",
+        );
+    };
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+
+    match (method.as_str(), parts.as_slice()) {
+        ("GET", []) => {
+            let body = index_page();
+            respond(&mut stream, "200 OK", "text/html; charset=utf-8", &body)
+        }
+        ("GET", ["lesson", id]) => match lessons::find(id) {
+            Some(entry) => {
+                let body = lesson_page(entry);
+                respond(&mut stream, "200 OK", "text/html; charset=utf-8", &body)
+            }
+            None => not_found(&mut stream),
+        },
+        ("GET", ["lesson", id, n]) => match section(id, n) {
+            Some((entry, index)) => {
+                let body = section_page(entry, index);
+                respond(&mut stream, "200 OK", "text/html; charset=utf-8", &body)
+            }
+            None => not_found(&mut stream),
+        },
+        ("POST", ["run", id, n]) => match section(id, n) {
+            Some((entry, index)) => stream_run(stream, entry, index),
+            None => not_found(&mut stream),
+        },
+        ("GET" | "POST", _) => not_found(&mut stream),
+        _ => respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n",
+        ),
+    }
+}
+
+// "option", "3" -> the option lesson and section index 2
+fn section(id: &str, n: &str) -> Option<(&'static Entry, usize)> {
+    let entry = lessons::find(id)?;
+    let index = n.parse::<usize>().ok()?.checked_sub(1)?;
+    (index < entry.lesson.sections.len()).then_some((entry, index))
+}
+
+// ============================================================================
+// Running Sections
+// ============================================================================
+
+/// Runs a section and returns everything it printed.
+pub fn section_output(entry: &'static Entry, index: usize) -> Result<String, String> {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&output);
+    capture::run_section(
+        entry,
+        index,
+        Box::new(move |chunk| sink.lock().unwrap().extend_from_slice(chunk)),
+    )?;
+    let bytes = output.lock().unwrap().clone();
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+// The output goes out as HTTP chunks while the section is still running
+fn stream_run(mut stream: TcpStream, entry: &'static Entry, index: usize) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nTransfer-Encoding: chunked\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n"
+    )?;
+
+    let mut client = stream.try_clone()?;
+    let sink = move |chunk: &[u8]| {
+        write!(client, "{:x}\r\n", chunk.len()).ok();
+        client.write_all(chunk).ok();
+        client.write_all(b"\r\n").ok();
+        client.flush().ok();
+    };
+    if let Err(message) = capture::run_section(entry, index, Box::new(sink)) {
+        let text = format!("\nerror: {}\n", message);
+        write!(stream, "{:x}\r\n{}\r\n", text.len(), text)?;
+    }
+    stream.write_all(b"0\r\n\r\n")?;
+    stream.flush()
+}
+
+// ============================================================================
+// Pages
+// ============================================================================
+
+fn index_page() -> String {
+    let mut body = String::from("<h1>Learn Rust</h1>\n");
+    for (topic, entries) in lessons::topics() {
+        body += &format!("<h2>{}</h2>\n<ul>\n", escape(topic));
+        for entry in entries {
+            body += &format!(
+                "<li><a href=\"/lesson/{}\">{}</a></li>\n",
+                entry.id,
+                escape(entry.lesson.title)
+            );
+        }
+        body += "</ul>\n";
+    }
+    html_page("Learn Rust", &body)
+}
+
+fn lesson_page(entry: &Entry) -> String {
+    let mut body = format!(
+        "<p><a href=\"/\">Lessons</a></p>\n<h1>{}</h1>\n<ol>\n",
+        escape(entry.lesson.title)
+    );
+    for (i, section) in entry.lesson.sections.iter().enumerate() {
+        body += &format!(
+            "<li><a href=\"/lesson/{}/{}\">{}</a></li>\n",
+            entry.id,
+            i + 1,
+            escape(section.title)
+        );
+    }
+    body += "</ol>\n";
+    if !entry.lesson.takeaways.is_empty() {
+        body += "<h2>Key Takeaways</h2>\n<ul>\n";
+        for takeaway in entry.lesson.takeaways {
+            body += &format!("<li>{}</li>\n", escape(takeaway));
+        }
+        body += "</ul>\n";
+    }
+    html_page(entry.lesson.title, &body)
+}
+
+// Reads the streamed response piece by piece into the output block
+const RUN_SCRIPT: &str = "\
+<script>
+document.getElementById('run').onclick = async (event) => {
+  const out = document.getElementById('output');
+  out.textContent = '';
+  const response = await fetch(event.target.dataset.url, { method: 'POST' });
+  const reader = response.body.getReader();
+  const decoder = new TextDecoder();
+  for (;;) {
+    const { done, value } = await reader.read();
+    if (done) break;
+    out.textContent += decoder.decode(value, { stream: true });
+  }
+};
+</script>
+";
+
+fn section_page(entry: &'static Entry, index: usize) -> String {
+    let output = section_output(entry, index).unwrap_or_else(|e| format!("error: {}", e));
+    let mut body = format!(
+        "<p><a href=\"/\">Lessons</a> › <a href=\"/lesson/{}\">{}</a></p>\n",
+        entry.id,
+        escape(entry.lesson.title)
+    );
+    body += &format!("<h1>{}</h1>\n", escape(&section_heading(entry, index)));
+    body += &format!(
+        "<pre><code>{}</code></pre>\n",
+        highlight(&section_source(entry, index))
+    );
+    body += &format!(
+        "<h2>Output</h2>\n<pre class=\"output\" id=\"output\">{}</pre>\n",
+        escape(output.trim_end())
+    );
+    body += &format!(
+        "<button id=\"run\" data-url=\"/run/{}/{}\">Run again</button>\n",
+        entry.id,
+        index + 1
+    );
+
+    let mut links = Vec::new();
+    if index > 0 {
+        links.push(format!(
+            "<a href=\"/lesson/{}/{}\">← Previous</a>",
+            entry.id, index
+        ));
+    }
+    if index + 1 < entry.lesson.sections.len() {
+        links.push(format!(
+            "<a href=\"/lesson/{}/{}\">Next →</a>",
+            entry.id,
+            index + 2
+        ));
+    }
+    body += &format!("<nav>{}</nav>\n", links.join(" | "));
+    body += RUN_SCRIPT;
+    html_page(&section_heading(entry, index), &body)
+}
@@ -0,0 +1,754 @@
+// Borrow Timeline Visualiser
+// Turns an annotated lesson snippet into an ASCII chart of scopes and borrows
+//
+// Each source line may end with `//@` followed by directives, separated by `;`
+//
+//     let NAME                  binding comes into scope
+//     let NAME = &X             binding that holds a shared borrow of X
+//     let NAME = &mut X         ...a mutable borrow of X
+//     let NAME = &X, &Y         ...borrows of several values (like longest)
+//     borrow NAME = &X          an existing binding starts holding a borrow
+//     use A, B                  A and B are read on this line
+//     write X                   X is changed directly (X.push_str(..))
+//     move X                    X is moved away
+//     drop A, B                 A and B go out of scope
+//
+// A borrow is live from its `let` (or `borrow`) until the last `use` of the binding
+// holding it - the same rule the borrow checker (NLL) uses.
+
+// ============================================================================
+// Snippet Data
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Borrow {
+    pub target: String,
+    pub mutable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Let { name: String, borrows: Vec<Borrow> },
+    Borrow { name: String, borrows: Vec<Borrow> },
+    Use(Vec<String>),
+    Write(String),
+    Move(String),
+    Drop(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    pub source: String,
+    pub events: Vec<Event>,
+}
+
+/// One annotated snippet, e.g. a single `demonstrate_*` function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagram {
+    pub title: String,
+    pub lines: Vec<Line>,
+}
+
+/// A borrow rule broken on a specific line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub line: usize, // 0-based index into Diagram::lines
+    pub code: &'static str,
+    pub message: String,
+    pub bindings: Vec<String>,
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+fn split_names(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn parse_borrow(text: &str) -> Result<Borrow, String> {
+    let text = text.trim();
+    if let Some(target) = text.strip_prefix("&mut ") {
+        Ok(Borrow {
+            target: target.trim().to_string(),
+            mutable: true,
+        })
+    } else if let Some(target) = text.strip_prefix('&') {
+        Ok(Borrow {
+            target: target.trim().to_string(),
+            mutable: false,
+        })
+    } else {
+        Err(format!("expected `&X` or `&mut X`, found `{}`", text))
+    }
+}
+
+fn parse_directive(directive: &str) -> Result<Event, String> {
+    let directive = directive.trim();
+    let (keyword, rest) = directive.split_once(' ').unwrap_or((directive, ""));
+    let rest = rest.trim();
+
+    if rest.is_empty() {
+        return Err(format!("`{}` needs at least one name", keyword));
+    }
+
+    match keyword {
+        "let" => match rest.split_once('=') {
+            Some((name, borrows)) => Ok(Event::Let {
+                name: name.trim().to_string(),
+                borrows: borrows
+                    .split(',')
+                    .map(parse_borrow)
+                    .collect::<Result<_, _>>()?,
+            }),
+            None => Ok(Event::Let {
+                name: rest.to_string(),
+                borrows: Vec::new(),
+            }),
+        },
+        "borrow" => match rest.split_once('=') {
+            Some((name, borrows)) => Ok(Event::Borrow {
+                name: name.trim().to_string(),
+                borrows: borrows
+                    .split(',')
+                    .map(parse_borrow)
+                    .collect::<Result<_, _>>()?,
+            }),
+            None => Err(String::from("expected `borrow NAME = &X`")),
+        },
+        "use" => Ok(Event::Use(split_names(rest))),
+        "write" => Ok(Event::Write(rest.to_string())),
+        "move" => Ok(Event::Move(rest.to_string())),
+        "drop" => Ok(Event::Drop(split_names(rest))),
+        other => Err(format!("unknown directive `{}`", other)),
+    }
+}
+
+fn parse_line(text: &str) -> Result<Line, String> {
+    match text.split_once("//@") {
+        Some((source, directives)) => Ok(Line {
+            source: source.trim_end().to_string(),
+            events: directives
+                .split(';')
+                .filter(|d| !d.trim().is_empty())
+                .map(parse_directive)
+                .collect::<Result<_, _>>()?,
+        }),
+        None => Ok(Line {
+            source: text.trim_end().to_string(),
+            events: Vec::new(),
+        }),
+    }
+}
+
+/// Parses one annotated snippet.
+pub fn parse_snippet(title: &str, text: &str) -> Result<Diagram, String> {
+    let lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let diagram = Diagram {
+        title: title.to_string(),
+        lines,
+    };
+    check_names(&diagram)?;
+    Ok(diagram)
+}
+
+/// Parses a diagram file: `#` comments, then snippets each starting with
+/// a `== Title` line.
+pub fn parse_file(text: &str) -> Result<Vec<Diagram>, String> {
+    let mut diagrams = Vec::new();
+    let mut current: Option<(String, usize, String)> = None; // title, first line, body
+
+    let mut finish = |current: Option<(String, usize, String)>| -> Result<(), String> {
+        if let Some((title, start, body)) = current {
+            let diagram = parse_snippet(&title, body.trim_end_matches('\n'))
+                .map_err(|e| format!("{} (snippet starting on line {})", e, start))?;
+            diagrams.push(diagram);
+        }
+        Ok(())
+    };
+
+    for (i, line) in text.lines().enumerate() {
+        if let Some(title) = line.strip_prefix("== ") {
+            finish(current.take())?;
+            current = Some((title.trim().to_string(), i + 2, String::new()));
+        } else if let Some((_, _, body)) = current.as_mut() {
+            body.push_str(line);
+            body.push('\n');
+        } else if !line.trim().is_empty() && !line.starts_with('#') {
+            return Err(format!(
+                "line {}: expected `== Title` before the first snippet",
+                i + 1
+            ));
+        }
+    }
+    finish(current)?;
+
+    Ok(diagrams)
+}
+
+// Every name a directive mentions must have been declared with `let` first
+fn check_names(diagram: &Diagram) -> Result<(), String> {
+    let mut declared: Vec<&str> = Vec::new();
+
+    for (i, line) in diagram.lines.iter().enumerate() {
+        for event in &line.events {
+            let mentioned: Vec<&String> = match event {
+                Event::Let { borrows, .. } => borrows.iter().map(|b| &b.target).collect(),
+                Event::Borrow { name, borrows } => {
+                    borrows.iter().map(|b| &b.target).chain([name]).collect()
+                }
+                Event::Use(names) | Event::Drop(names) => names.iter().collect(),
+                Event::Write(name) | Event::Move(name) => vec![name],
+            };
+            if let Some(unknown) = mentioned.iter().find(|n| !declared.contains(&n.as_str())) {
+                return Err(format!(
+                    "line {}: `{}` is used before its `let`",
+                    i + 1,
+                    unknown
+                ));
+            }
+            if let Event::Let { name, .. } = event {
+                declared.push(name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Analysis
+// ============================================================================
+
+/// Where one binding lives, and the borrows it holds.
+#[derive(Debug)]
+struct Binding {
+    name: String,
+    start: usize,
+    borrow_start: usize,
+    end: Option<usize>, // explicit drop
+    moved_at: Option<usize>,
+    borrows: Vec<Borrow>,
+    last_use: usize,
+}
+
+impl Binding {
+    // The borrow it holds is still needed on this line (or later)
+    fn live_at(&self, line: usize) -> bool {
+        !self.borrows.is_empty() && self.borrow_start < line && line <= self.last_use
+    }
+
+    fn borrows_mut(&self, target: &str) -> Option<bool> {
+        self.borrows
+            .iter()
+            .find(|b| b.target == target)
+            .map(|b| b.mutable)
+    }
+}
+
+fn collect_bindings(diagram: &Diagram) -> Vec<Binding> {
+    let mut bindings: Vec<Binding> = Vec::new();
+
+    for (i, line) in diagram.lines.iter().enumerate() {
+        for event in &line.events {
+            match event {
+                Event::Let { name, borrows } => bindings.push(Binding {
+                    name: name.clone(),
+                    start: i,
+                    borrow_start: i,
+                    end: None,
+                    moved_at: None,
+                    borrows: borrows.clone(),
+                    last_use: i,
+                }),
+                Event::Borrow { name, borrows } => {
+                    for b in bindings.iter_mut().filter(|b| &b.name == name) {
+                        b.borrow_start = i;
+                        b.borrows = borrows.clone();
+                        b.last_use = i;
+                    }
+                }
+                Event::Use(names) => {
+                    for b in bindings.iter_mut().filter(|b| names.contains(&b.name)) {
+                        b.last_use = i;
+                    }
+                }
+                Event::Write(name) => {
+                    for b in bindings.iter_mut().filter(|b| &b.name == name) {
+                        b.last_use = i;
+                    }
+                }
+                Event::Move(name) => {
+                    for b in bindings.iter_mut().filter(|b| &b.name == name) {
+                        b.moved_at.get_or_insert(i);
+                    }
+                }
+                Event::Drop(names) => {
+                    for b in bindings.iter_mut().filter(|b| names.contains(&b.name)) {
+                        b.end = Some(i);
+                    }
+                }
+            }
+        }
+    }
+
+    bindings
+}
+
+fn kind(mutable: bool) -> &'static str {
+    if mutable {
+        "mutable"
+    } else {
+        "immutable"
+    }
+}
+
+fn held(mutable: bool) -> &'static str {
+    if mutable {
+        "a mutable"
+    } else {
+        "a shared"
+    }
+}
+
+/// Finds every place the snippet breaks a borrowing rule.
+pub fn find_conflicts(diagram: &Diagram) -> Vec<Conflict> {
+    let bindings = collect_bindings(diagram);
+    let mut conflicts = Vec::new();
+
+    // Borrows of `target` held by someone other than `except`, live on `line`
+    let live_borrows = |target: &str, except: &str, line: usize| -> Vec<(&Binding, bool)> {
+        bindings
+            .iter()
+            .filter(|b| b.name != except && b.live_at(line))
+            .filter_map(|b| b.borrows_mut(target).map(|mutable| (b, mutable)))
+            .collect()
+    };
+    let moved_before = |name: &str, line: usize| {
+        bindings
+            .iter()
+            .any(|b| b.name == name && b.moved_at.is_some_and(|m| m < line))
+    };
+
+    for (i, line) in diagram.lines.iter().enumerate() {
+        for event in &line.events {
+            match event {
+                Event::Let { name, borrows } | Event::Borrow { name, borrows } => {
+                    for borrow in borrows {
+                        if moved_before(&borrow.target, i) {
+                            conflicts.push(Conflict {
+                                line: i,
+                                code: "E0382",
+                                message: format!("borrow of moved value `{}`", borrow.target),
+                                bindings: vec![name.clone(), borrow.target.clone()],
+                            });
+                        }
+                        for (other, other_mut) in live_borrows(&borrow.target, name, i) {
+                            if !borrow.mutable && !other_mut {
+                                continue; // any number of & at once is fine
+                            }
+                            let code = if borrow.mutable && other_mut {
+                                "E0499"
+                            } else {
+                                "E0502"
+                            };
+                            conflicts.push(Conflict {
+                                line: i,
+                                code,
+                                message: format!(
+                                    "cannot borrow `{}` as {} because `{}` holds {} borrow",
+                                    borrow.target,
+                                    kind(borrow.mutable),
+                                    other.name,
+                                    held(other_mut)
+                                ),
+                                bindings: vec![name.clone(), other.name.clone()],
+                            });
+                        }
+                    }
+                }
+                Event::Use(names) => {
+                    for name in names {
+                        if moved_before(name, i) {
+                            conflicts.push(Conflict {
+                                line: i,
+                                code: "E0382",
+                                message: format!("use of moved value `{}`", name),
+                                bindings: vec![name.clone()],
+                            });
+                        }
+                        // Reading the owner is fine next to & borrows, not next to &mut
+                        for (other, other_mut) in live_borrows(name, name, i) {
+                            if other_mut {
+                                conflicts.push(Conflict {
+                                    line: i,
+                                    code: "E0502",
+                                    message: format!(
+                                        "cannot use `{}` while `{}` holds a mutable borrow",
+                                        name, other.name
+                                    ),
+                                    bindings: vec![name.clone(), other.name.clone()],
+                                });
+                            }
+                        }
+                    }
+                }
+                Event::Write(name) => {
+                    for (other, other_mut) in live_borrows(name, name, i) {
+                        conflicts.push(Conflict {
+                            line: i,
+                            code: if other_mut { "E0499" } else { "E0502" },
+                            message: format!(
+                                "cannot change `{}` while `{}` holds {} borrow",
+                                name,
+                                other.name,
+                                held(other_mut)
+                            ),
+                            bindings: vec![name.clone(), other.name.clone()],
+                        });
+                    }
+                }
+                Event::Move(name) => {
+                    for (other, _) in live_borrows(name, name, i) {
+                        if other.last_use > i {
+                            conflicts.push(Conflict {
+                                line: i,
+                                code: "E0505",
+                                message: format!(
+                                    "cannot move out of `{}` because `{}` still borrows it",
+                                    name, other.name
+                                ),
+                                bindings: vec![name.clone(), other.name.clone()],
+                            });
+                        }
+                    }
+                }
+                Event::Drop(names) => {
+                    for name in names {
+                        for (other, _) in live_borrows(name, name, i) {
+                            if other.last_use > i {
+                                conflicts.push(Conflict {
+                                    line: i,
+                                    code: "E0597",
+                                    message: format!(
+                                        "`{}` does not live long enough - `{}` still borrows it",
+                                        name, other.name
+                                    ),
+                                    bindings: vec![name.clone(), other.name.clone()],
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+// One character per binding per line:
+//   +  comes into scope         &  created holding a & borrow
+//   |  in scope / borrow live   m  created holding a &mut borrow
+//   *  used                     w  changed directly
+//   .  in scope, borrow done    >  moved away
+//   x  dropped                  !  breaks a borrow rule on this line
+fn cell(binding: &Binding, line: usize, events: &[Event], conflicted: bool) -> char {
+    let end = binding.end.unwrap_or(usize::MAX);
+    if line < binding.start || line > end {
+        return ' ';
+    }
+    if conflicted {
+        return '!';
+    }
+    if line == binding.borrow_start {
+        return match binding.borrows.iter().any(|b| b.mutable) {
+            _ if binding.borrows.is_empty() => '+',
+            true => 'm',
+            false => '&',
+        };
+    }
+    if line == binding.start {
+        return '+';
+    }
+    if binding.end == Some(line) {
+        return 'x';
+    }
+    if binding.moved_at == Some(line) {
+        return '>';
+    }
+
+    let mentioned = |f: &dyn Fn(&Event) -> bool| events.iter().any(f);
+    if mentioned(&|e| matches!(e, Event::Use(names) if names.contains(&binding.name))) {
+        return '*';
+    }
+    if mentioned(&|e| matches!(e, Event::Write(name) if *name == binding.name)) {
+        return 'w';
+    }
+
+    let dead = binding.moved_at.is_some_and(|m| line > m)
+        || (!binding.borrows.is_empty() && line > binding.last_use);
+    let waiting = !binding.borrows.is_empty() && line < binding.borrow_start;
+    if dead || waiting {
+        '.'
+    } else {
+        '|'
+    }
+}
+
+/// Draws the timeline: one column per binding, source on the right,
+/// and any conflicts called out on the line where they happen.
+pub fn render(diagram: &Diagram) -> String {
+    let bindings = collect_bindings(diagram);
+    let conflicts = find_conflicts(diagram);
+    let widths: Vec<usize> = bindings.iter().map(|b| b.name.len().max(1) + 1).collect();
+    let mut out = String::new();
+
+    out.push_str(&format!("{}\n\n", diagram.title));
+
+    let header: String = bindings
+        .iter()
+        .zip(&widths)
+        .map(|(b, w)| format!("{:<w$}", b.name, w = w))
+        .collect();
+    out.push_str(&format!("    | {}\n", header.trim_end()));
+    out.push_str(&format!("----+-{}\n", "-".repeat(header.len())));
+
+    for (i, line) in diagram.lines.iter().enumerate() {
+        let here: Vec<&Conflict> = conflicts.iter().filter(|c| c.line == i).collect();
+        let bars: String = bindings
+            .iter()
+            .zip(&widths)
+            .map(|(b, w)| {
+                let conflicted = here.iter().any(|c| c.bindings.contains(&b.name));
+                format!("{:<w$}", cell(b, i, &line.events, conflicted), w = w)
+            })
+            .collect();
+
+        let row = format!("{:>3} | {}  {}", i + 1, bars, line.source);
+        out.push_str(row.trim_end());
+        // First conflict on the same row, any others lined up underneath
+        for (n, conflict) in here.iter().enumerate() {
+            if n > 0 {
+                out.push('\n');
+                out.push_str(&" ".repeat(row.trim_end().len()));
+            }
+            out.push_str(&format!(
+                "   <-- error[{}]: {}",
+                conflict.code, conflict.message
+            ));
+        }
+        out.push('\n');
+    }
+
+    let borrowing: Vec<String> = bindings
+        .iter()
+        .filter(|b| !b.borrows.is_empty())
+        .map(|b| {
+            let targets: Vec<String> = b
+                .borrows
+                .iter()
+                .map(|x| format!("&{}{}", if x.mutable { "mut " } else { "" }, x.target))
+                .collect();
+            format!("{} = {}", b.name, targets.join(", "))
+        })
+        .collect();
+    if !borrowing.is_empty() {
+        out.push_str(&format!("\nBorrows: {}\n", borrowing.join("; ")));
+    }
+    if conflicts.is_empty() {
+        out.push_str("No conflicts.\n");
+    } else {
+        out.push_str(&format!("{} conflict(s) marked with !\n", conflicts.len()));
+    }
+
+    out
+}
+
+/// Explains the characters used by `render`.
+pub const LEGEND: &str = "\
+Legend:  + in scope   & holds a borrow   m holds a &mut borrow   | alive / borrow live
+         * used      w changed          > moved    . in scope, borrow over
+         x dropped   ! breaks a rule on this line";
+
+// ============================================================================
+// Built-in Diagrams
+// ============================================================================
+
+/// Pre-annotated diagrams for the borrowing and lifetimes lessons,
+/// as (lesson file, diagram file contents). Each is titled with its
+/// section's number and title, and quotes that section's code.
+pub const BUILTIN: [(&str, &str); 4] = [
+    (
+        "02_borrowing.rs",
+        include_str!("../examples/ownership/diagrams/02_borrowing.txt"),
+    ),
+    (
+        "03_mutable_borrowing.rs",
+        include_str!("../examples/ownership/diagrams/03_mutable_borrowing.txt"),
+    ),
+    (
+        "05_lifetimes.rs",
+        include_str!("../examples/ownership/diagrams/05_lifetimes.txt"),
+    ),
+    (
+        "06_advanced_lifetimes.rs",
+        include_str!("../examples/ownership/diagrams/06_advanced_lifetimes.txt"),
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(snippet: &str) -> Vec<&'static str> {
+        let diagram = parse_snippet("test", snippet).unwrap();
+        find_conflicts(&diagram)
+            .into_iter()
+            .map(|c| c.code)
+            .collect()
+    }
+
+    #[test]
+    fn shared_borrows_then_mutable_borrow_is_fine() {
+        let snippet = "\
+let mut s = String::new(); //@ let s
+let r1 = &s;               //@ let r1 = &s
+let r2 = &s;               //@ let r2 = &s
+println!(\"{r1}{r2}\");    //@ use r1, r2
+let r3 = &mut s;           //@ let r3 = &mut s
+r3.push('!');              //@ use r3";
+        assert!(codes(snippet).is_empty());
+    }
+
+    #[test]
+    fn mutable_borrow_while_shared_is_live_is_e0502() {
+        let snippet = "\
+let mut s = String::new(); //@ let s
+let r1 = &s;               //@ let r1 = &s
+let r2 = &mut s;           //@ let r2 = &mut s
+println!(\"{r1}\");        //@ use r1";
+        assert_eq!(codes(snippet), ["E0502"]);
+    }
+
+    #[test]
+    fn two_live_mutable_borrows_is_e0499() {
+        let snippet = "\
+let mut s = String::new(); //@ let s
+let r1 = &mut s;           //@ let r1 = &mut s
+let r2 = &mut s;           //@ let r2 = &mut s
+println!(\"{r1}{r2}\");    //@ use r1, r2";
+        assert_eq!(codes(snippet), ["E0499"]);
+    }
+
+    #[test]
+    fn reading_owner_during_mutable_borrow_is_e0502() {
+        let snippet = "\
+let mut s = String::new(); //@ let s
+let r = &mut s;            //@ let r = &mut s
+println!(\"{s}\");         //@ use s
+r.push('!');               //@ use r";
+        assert_eq!(codes(snippet), ["E0502"]);
+    }
+
+    #[test]
+    fn borrow_outliving_its_owner_is_e0597() {
+        let snippet = "\
+let r;                     //@ let r
+{                          
+    let x = 5;             //@ let x
+    r = &x;                //@ borrow r = &x
+}                          //@ drop x
+println!(\"{r}\");         //@ use r";
+        assert_eq!(codes(snippet), ["E0597"]);
+    }
+
+    #[test]
+    fn use_after_move_is_e0382() {
+        let snippet = "\
+let s = String::new();     //@ let s
+take(s);                   //@ move s
+println!(\"{s}\");         //@ use s";
+        assert_eq!(codes(snippet), ["E0382"]);
+    }
+
+    #[test]
+    fn unknown_names_and_directives_are_rejected() {
+        assert!(parse_snippet("t", "x //@ use nobody").is_err());
+        assert!(parse_snippet("t", "x //@ frobnicate y").is_err());
+        assert!(parse_snippet("t", "x //@ let r = s").is_err());
+    }
+
+    #[test]
+    fn builtin_diagrams_parse_and_only_marked_examples_conflict() {
+        for (file, text) in BUILTIN {
+            let diagrams = parse_file(text).unwrap_or_else(|e| panic!("{}: {}", file, e));
+            assert!(!diagrams.is_empty(), "{} has no diagrams", file);
+            for diagram in diagrams {
+                let expect_error = diagram.title.contains("(won't compile)");
+                let found = !find_conflicts(&diagram).is_empty();
+                assert_eq!(found, expect_error, "{}: {}", file, diagram.title);
+            }
+        }
+    }
+
+    // The code on a line, less any comment after it
+    fn code(line: &str) -> &str {
+        let line = line.trim();
+        crate::book::comment_start(line).map_or(line, |at| line[..at].trim_end())
+    }
+
+    #[test]
+    fn builtin_diagrams_follow_the_lesson_source() {
+        for (file, text) in BUILTIN {
+            let entry = crate::lessons::CURRICULUM
+                .iter()
+                .find(|entry| entry.file.ends_with(file))
+                .unwrap_or_else(|| panic!("{} isn't a registered lesson", file));
+            let sections = entry.lesson.sections;
+
+            for diagram in parse_file(text).unwrap() {
+                let (number, rest) = diagram.title.split_once(". ").unwrap();
+                let index = number.parse::<usize>().unwrap() - 1;
+                assert!(
+                    index < sections.len()
+                        && rest.starts_with(&format!("{}: ", sections[index].title)),
+                    "{}: `{}` doesn't name a section of {}",
+                    file,
+                    diagram.title,
+                    entry.id
+                );
+
+                // Commented-out lines count too: the ERROR examples live there
+                let source = crate::book::section_source(entry, index);
+                let lines: Vec<&str> = source
+                    .lines()
+                    .map(|line| code(line.trim().trim_start_matches("//")))
+                    .collect();
+                for line in &diagram.lines {
+                    let line = code(&line.source);
+                    assert!(
+                        line.is_empty() || lines.contains(&line),
+                        "{}: `{}` isn't in section {} of {}",
+                        file,
+                        line,
+                        index + 1,
+                        entry.id
+                    );
+                }
+            }
+        }
+    }
+}
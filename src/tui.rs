@@ -0,0 +1,712 @@
+// Terminal Lesson Browser
+// A full-screen browser drawn with ANSI escape codes, the terminal put in raw
+// mode through termios: the lessons on the left, the section's source in the
+// middle and its output on the right
+//
+//     ↑ ↓  or k j    previous / next section
+//     ← →  or h l    previous / next lesson
+//     PgUp PgDn      scroll the source
+//     Enter or r     run the section
+//     e              explain mode: the section's comments and the takeaways
+//     c              mark the section complete
+//     q  or Esc      quit
+
+use crate::book::{section_comments, section_heading, section_source};
+use crate::lessons::{topics, Entry, CURRICULUM};
+use crate::progress::{self, Progress};
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+
+const TREE_WIDTH: usize = 32;
+const SCROLL_STEP: usize = 10;
+
+/// Runs one section (numbered from 1) and returns what it printed.
+pub type Capture<'a> = &'a mut dyn FnMut(&Entry, usize) -> Result<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Run,
+    Explain,
+    Complete,
+    Quit,
+}
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// The keys in one read from the terminal. Escape sequences arrive whole;
+/// a lone Esc quits.
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let (key, used) = match rest {
+            [0x1b, b'[', b'A', ..] => (Some(Key::Up), 3),
+            [0x1b, b'[', b'B', ..] => (Some(Key::Down), 3),
+            [0x1b, b'[', b'C', ..] => (Some(Key::Right), 3),
+            [0x1b, b'[', b'D', ..] => (Some(Key::Left), 3),
+            [0x1b, b'[', b'5', b'~', ..] => (Some(Key::PageUp), 4),
+            [0x1b, b'[', b'6', b'~', ..] => (Some(Key::PageDown), 4),
+            // Some other sequence: skip it up to its final byte
+            [0x1b, b'[', tail @ ..] => {
+                let end = tail.iter().position(|b| (0x40..=0x7e).contains(b));
+                (None, 2 + end.map_or(tail.len(), |e| e + 1))
+            }
+            [0x1b] => (Some(Key::Quit), 1),
+            [byte, ..] => {
+                let key = match byte {
+                    b'k' => Some(Key::Up),
+                    b'j' => Some(Key::Down),
+                    b'h' => Some(Key::Left),
+                    b'l' => Some(Key::Right),
+                    b'K' => Some(Key::PageUp),
+                    b'J' => Some(Key::PageDown),
+                    b'\r' | b'\n' | b'r' => Some(Key::Run),
+                    b'e' => Some(Key::Explain),
+                    b'c' => Some(Key::Complete),
+                    b'q' | 0x03 | 0x1b => Some(Key::Quit), // 0x03 is Ctrl+C
+                    _ => None,
+                };
+                (key, 1)
+            }
+            [] => break,
+        };
+        keys.extend(key);
+        i += used;
+    }
+    keys
+}
+
+// ============================================================================
+// The Browser
+// ============================================================================
+
+pub struct Browser {
+    lesson: usize, // index into CURRICULUM
+    section: usize,
+    scroll: usize,
+    explain: bool,
+    outputs: BTreeMap<(usize, usize), Result<String, String>>,
+    status: String,
+    progress: Progress,
+}
+
+impl Browser {
+    pub fn new(progress: Progress) -> Browser {
+        Browser {
+            lesson: 0,
+            section: 0,
+            scroll: 0,
+            explain: false,
+            outputs: BTreeMap::new(),
+            status: String::new(),
+            progress,
+        }
+    }
+
+    fn entry(&self) -> &'static Entry {
+        &CURRICULUM[self.lesson]
+    }
+
+    fn select(&mut self, lesson: usize, section: usize) {
+        self.lesson = lesson;
+        self.section = section;
+        self.scroll = 0;
+        self.status.clear();
+    }
+
+    /// Acts on a key. Returns false once the browser should close.
+    pub fn handle(&mut self, key: Key, capture: Capture) -> bool {
+        let sections = self.entry().lesson.sections.len();
+        match key {
+            Key::Up if self.section > 0 => self.select(self.lesson, self.section - 1),
+            Key::Up if self.lesson > 0 => {
+                let last = CURRICULUM[self.lesson - 1].lesson.sections.len() - 1;
+                self.select(self.lesson - 1, last);
+            }
+            Key::Down if self.section + 1 < sections => self.select(self.lesson, self.section + 1),
+            Key::Down | Key::Right if self.lesson + 1 < CURRICULUM.len() => {
+                self.select(self.lesson + 1, 0)
+            }
+            Key::Left if self.lesson > 0 => self.select(self.lesson - 1, 0),
+            Key::PageUp => self.scroll = self.scroll.saturating_sub(SCROLL_STEP),
+            Key::PageDown => self.scroll += SCROLL_STEP,
+            Key::Run => {
+                let output = capture(self.entry(), self.section + 1);
+                self.status = match &output {
+                    Ok(_) => format!("Ran {}", section_heading(self.entry(), self.section)),
+                    Err(_) => String::from("The section failed"),
+                };
+                self.outputs.insert((self.lesson, self.section), output);
+            }
+            Key::Explain => {
+                self.explain = !self.explain;
+                self.scroll = 0;
+            }
+            Key::Complete => {
+                let (id, section) = (self.entry().id, self.section + 1);
+                self.progress.view(id, section, progress::now());
+                self.status = match self.progress.save() {
+                    Ok(()) => format!(
+                        "Marked {} complete",
+                        section_heading(self.entry(), self.section)
+                    ),
+                    Err(e) => format!("Couldn't save progress: {}", e),
+                };
+            }
+            Key::Quit => return false,
+            _ => {}
+        }
+        true
+    }
+
+    /// The screen as `rows` lines of `cols` columns, with ANSI styling.
+    pub fn render(&self, rows: usize, cols: usize) -> Vec<String> {
+        let rows = rows.max(4);
+        let height = rows - 2; // less the title bar and the key line
+        let tree_width = TREE_WIDTH.min(cols / 4);
+        let rest = cols.saturating_sub(tree_width + 2);
+        let source_width = rest * 11 / 20;
+        let output_width = rest - source_width;
+
+        let tree = self.tree(height, tree_width);
+        let middle = self.middle(height, source_width);
+        let output = self.output(height, output_width);
+
+        let entry = self.entry();
+        let mut screen = vec![reverse(&fit(
+            &format!(" Learn Rust: {}", entry.lesson.title),
+            cols,
+        ))];
+        for row in 0..height {
+            screen.push(format!("{}│{}│{}", tree[row], middle[row], output[row]));
+        }
+        let keys =
+            "↑↓ section  ←→ lesson  PgUp/PgDn scroll  Enter run  e explain  c complete  q quit";
+        let line = if self.status.is_empty() {
+            keys.to_string()
+        } else {
+            format!("{}  |  {}", self.status, keys)
+        };
+        screen.push(dim(&fit(&format!(" {}", line), cols)));
+        screen
+    }
+
+    // Topics, their lessons, and the sections of the current lesson
+    fn tree(&self, height: usize, width: usize) -> Vec<String> {
+        let mut lines = Vec::new(); // (text, selected, heading)
+        let mut selected_row = 0;
+        for (topic, entries) in topics() {
+            lines.push((topic.to_string(), false, true));
+            for entry in entries {
+                let index = CURRICULUM.iter().position(|e| e.id == entry.id).unwrap();
+                let sections = entry.lesson.sections.len();
+                let done = if self.progress.sections_viewed(entry) >= sections {
+                    "✓"
+                } else {
+                    " "
+                };
+                lines.push((
+                    format!(" {} {}. {}", done, index + 1, entry.lesson.title),
+                    false,
+                    false,
+                ));
+                if index != self.lesson {
+                    continue;
+                }
+                for (i, section) in entry.lesson.sections.iter().enumerate() {
+                    let done = if self.progress.has_viewed(entry.id, i + 1) {
+                        "✓"
+                    } else {
+                        " "
+                    };
+                    if i == self.section {
+                        selected_row = lines.len();
+                    }
+                    lines.push((
+                        format!("   {} {}. {}", done, i + 1, section.title),
+                        i == self.section,
+                        false,
+                    ));
+                }
+            }
+        }
+
+        // Keep the selected section on screen
+        let skip = (selected_row + 1).saturating_sub(height);
+        let mut column: Vec<String> = lines
+            .into_iter()
+            .skip(skip)
+            .take(height)
+            .map(|(text, selected, heading)| {
+                let text = fit(&text, width);
+                if selected {
+                    reverse(&text)
+                } else if heading {
+                    bold(&text)
+                } else {
+                    text
+                }
+            })
+            .collect();
+        column.resize(height, " ".repeat(width));
+        column
+    }
+
+    // The section's source, or in explain mode what its comments say
+    fn middle(&self, height: usize, width: usize) -> Vec<String> {
+        let entry = self.entry();
+        let (title, body) = if self.explain {
+            ("Explain", explanation(entry, self.section))
+        } else {
+            let source = section_source(entry, self.section).replace('\t', "    ");
+            ("Source", source.lines().map(str::to_string).collect())
+        };
+        let mut column = vec![
+            bold(&fit(
+                &format!(" {}  {}", section_heading(entry, self.section), title),
+                width,
+            )),
+            " ".repeat(width),
+        ];
+        let scroll = self.scroll.min(body.len().saturating_sub(1));
+        for line in body.iter().skip(scroll).take(height.saturating_sub(2)) {
+            let text = fit(&format!(" {}", line), width);
+            column.push(if line.trim_start().starts_with("//") {
+                dim(&text)
+            } else {
+                text
+            });
+        }
+        column.resize(height, " ".repeat(width));
+        column
+    }
+
+    fn output(&self, height: usize, width: usize) -> Vec<String> {
+        let mut column = vec![bold(&fit(" Output", width)), " ".repeat(width)];
+        let body: Vec<String> = match self.outputs.get(&(self.lesson, self.section)) {
+            None => vec![String::from("Press Enter to run this section")],
+            Some(Ok(output)) if output.trim().is_empty() => {
+                vec![String::from("(nothing printed)")]
+            }
+            Some(Ok(output)) => output.lines().map(str::to_string).collect(),
+            Some(Err(e)) => e.lines().map(|line| format!("error: {}", line)).collect(),
+        };
+        for line in body.iter().take(height.saturating_sub(2)) {
+            column.push(fit(&format!(" {}", line), width));
+        }
+        column.resize(height, " ".repeat(width));
+        column
+    }
+}
+
+/// What section `index` says about itself: its comments, then the lesson's
+/// takeaways if it has any.
+pub fn explanation(entry: &Entry, index: usize) -> Vec<String> {
+    let mut lines: Vec<String> = section_comments(entry, index)
+        .into_iter()
+        .map(|comment| format!("• {}", comment))
+        .collect();
+    if lines.is_empty() {
+        lines.push(String::from(
+            "This section has no comments; its code says it all.",
+        ));
+    }
+
+    if !entry.lesson.takeaways.is_empty() {
+        lines.push(String::new());
+        lines.push(String::from("Key takeaways:"));
+        for takeaway in entry.lesson.takeaways {
+            lines.push(format!("• {}", takeaway));
+        }
+    }
+    lines
+}
+
+// ============================================================================
+// Drawing
+// ============================================================================
+
+// Cut or pad to exactly `width` characters
+fn fit(text: &str, width: usize) -> String {
+    let mut line: String = text.chars().take(width).collect();
+    let len = line.chars().count();
+    line.extend(std::iter::repeat_n(' ', width - len));
+    line
+}
+
+fn bold(text: &str) -> String {
+    format!("\x1b[1m{}\x1b[0m", text)
+}
+
+fn dim(text: &str) -> String {
+    format!("\x1b[2m{}\x1b[0m", text)
+}
+
+fn reverse(text: &str) -> String {
+    format!("\x1b[7m{}\x1b[0m", text)
+}
+
+// The window's size from the terminal on stdin; LINES and COLUMNS are the
+// fallback
+#[cfg(unix)]
+fn terminal_size() -> (usize, usize) {
+    raw::window_size().unwrap_or_else(|| {
+        let var = |name: &str, default| {
+            std::env::var(name)
+                .ok()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(default)
+        };
+        (var("LINES", 24), var("COLUMNS", 80))
+    })
+}
+
+fn draw(out: &mut impl Write, screen: &[String]) -> io::Result<()> {
+    let mut frame = String::new();
+    for (row, line) in screen.iter().enumerate() {
+        frame += &format!("\x1b[{};1H{}", row + 1, line);
+    }
+    out.write_all(frame.as_bytes())?;
+    out.flush()
+}
+
+// ============================================================================
+// The Terminal
+// ============================================================================
+
+#[cfg(unix)]
+pub(crate) mod raw {
+    use std::io;
+    use std::mem::MaybeUninit;
+    use std::os::raw::{c_int, c_short, c_ulong, c_ushort};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // struct termios and the numbers the headers define, per target. Only
+    // libc looks inside a termios, but it has to be the size libc expects.
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "loongarch64",
+            target_arch = "s390x"
+        )
+    ))]
+    mod sys {
+        use std::os::raw::{c_uint, c_ulong};
+
+        // The asm-generic layout glibc, musl and bionic share
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)] // read and written by libc
+        pub struct Termios {
+            c_iflag: c_uint,
+            c_oflag: c_uint,
+            c_cflag: c_uint,
+            c_lflag: c_uint,
+            c_line: u8,
+            c_cc: [u8; 32],
+            c_ispeed: c_uint,
+            c_ospeed: c_uint,
+        }
+
+        pub type Nfds = c_ulong;
+        pub const TIOCGWINSZ: c_ulong = 0x5413;
+    }
+
+    #[cfg(any(
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    ))]
+    mod sys {
+        use std::os::raw::{c_uint, c_ulong};
+
+        // tcflag_t and speed_t are unsigned long on Apple's systems and
+        // unsigned int on the BSDs
+        #[cfg(target_vendor = "apple")]
+        type Flag = c_ulong;
+        #[cfg(not(target_vendor = "apple"))]
+        type Flag = c_uint;
+
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)] // read and written by libc
+        pub struct Termios {
+            c_iflag: Flag,
+            c_oflag: Flag,
+            c_cflag: Flag,
+            c_lflag: Flag,
+            c_cc: [u8; 20],
+            c_ispeed: Flag,
+            c_ospeed: Flag,
+        }
+
+        pub type Nfds = c_uint;
+        pub const TIOCGWINSZ: c_ulong = 0x4008_7468;
+    }
+
+    use sys::{Nfds, Termios, TIOCGWINSZ};
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Winsize {
+        rows: c_ushort,
+        cols: c_ushort,
+        x_pixels: c_ushort,
+        y_pixels: c_ushort,
+    }
+
+    #[repr(C)]
+    struct PollFd {
+        fd: c_int,
+        events: c_short,
+        revents: c_short,
+    }
+
+    extern "C" {
+        fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+        fn tcsetattr(fd: c_int, when: c_int, termios: *const Termios) -> c_int;
+        fn cfmakeraw(termios: *mut Termios);
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+        fn poll(fds: *mut PollFd, count: Nfds, timeout: c_int) -> c_int;
+        fn signal(signal: c_int, handler: extern "C" fn(c_int)) -> usize;
+    }
+
+    const STDIN: c_int = 0;
+    const TCSAFLUSH: c_int = 2;
+    const POLLIN: c_short = 1;
+    const SIGWINCH: c_int = 28;
+
+    /// Raw mode on stdin until dropped: keys arrive one by one, unechoed.
+    pub struct RawMode {
+        saved: Termios,
+    }
+
+    impl RawMode {
+        pub fn enable() -> io::Result<RawMode> {
+            let mut saved = MaybeUninit::<Termios>::uninit();
+            // SAFETY: tcgetattr fills in the whole struct or fails, and
+            // cfmakeraw only changes the copy it's given
+            unsafe {
+                if tcgetattr(STDIN, saved.as_mut_ptr()) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                let saved = saved.assume_init();
+                let mut raw = saved;
+                cfmakeraw(&mut raw);
+                if tcsetattr(STDIN, TCSAFLUSH, &raw) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(RawMode { saved })
+            }
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            // SAFETY: puts back the settings tcgetattr filled in
+            unsafe {
+                tcsetattr(STDIN, TCSAFLUSH, &self.saved);
+            }
+        }
+    }
+
+    /// Rows and columns of the terminal on stdin, if it is one.
+    pub fn window_size() -> Option<(usize, usize)> {
+        let mut size = Winsize::default();
+        // SAFETY: TIOCGWINSZ writes one struct winsize
+        if unsafe { ioctl(STDIN, TIOCGWINSZ, &mut size) } != 0 || size.rows == 0 {
+            return None;
+        }
+        Some((size.rows as usize, size.cols as usize))
+    }
+
+    static RESIZED: AtomicBool = AtomicBool::new(false);
+
+    extern "C" fn on_resize(_signal: c_int) {
+        RESIZED.store(true, Ordering::Relaxed);
+    }
+
+    /// Notes each resize of the window from now on, for wait_for_input.
+    pub fn watch_resize() {
+        // SAFETY: the handler only stores to an atomic
+        unsafe {
+            signal(SIGWINCH, on_resize);
+        }
+    }
+
+    /// Waits until there's input on stdin: true when there is, false when
+    /// the window was resized first and should be drawn again.
+    pub fn wait_for_input() -> io::Result<bool> {
+        let mut fd = PollFd {
+            fd: STDIN,
+            events: POLLIN,
+            revents: 0,
+        };
+        loop {
+            if RESIZED.swap(false, Ordering::Relaxed) {
+                return Ok(false);
+            }
+            // SAFETY: poll only writes the revents of the one entry; a
+            // signal interrupts it whether or not the handler restarts calls
+            if unsafe { poll(&mut fd, 1, -1) } >= 0 {
+                return Ok(true);
+            }
+            let error = io::Error::last_os_error();
+            if error.kind() != io::ErrorKind::Interrupted {
+                return Err(error);
+            }
+        }
+    }
+}
+
+// The alternate screen with the cursor hidden, left again when dropped
+struct Screen<'a>(io::StdoutLock<'a>);
+
+impl Drop for Screen<'_> {
+    fn drop(&mut self) {
+        self.0.write_all(b"\x1b[0m\x1b[?25h\x1b[?1049l").ok();
+        self.0.flush().ok();
+    }
+}
+
+/// Opens the browser on the terminal until the trainee quits. Both stdin
+/// and stdout should be a terminal.
+#[cfg(unix)]
+pub fn run(capture: Capture) -> Result<(), String> {
+    let mut browser = Browser::new(Progress::load()?);
+    let _raw = raw::RawMode::enable().map_err(|e| format!("raw mode: {}", e))?;
+    let mut screen = Screen(io::stdout().lock());
+    screen
+        .0
+        .write_all(b"\x1b[?1049h\x1b[?25l")
+        .map_err(|e| e.to_string())?;
+
+    raw::watch_resize();
+    let mut buf = [0u8; 32];
+    loop {
+        let (rows, cols) = terminal_size();
+        draw(&mut screen.0, &browser.render(rows, cols)).map_err(|e| e.to_string())?;
+
+        if !raw::wait_for_input().map_err(|e| e.to_string())? {
+            continue;
+        }
+        let n = io::stdin().read(&mut buf).map_err(|e| e.to_string())?;
+        if n == 0 {
+            return Ok(());
+        }
+        for key in parse_keys(&buf[..n]) {
+            if key == Key::Run {
+                browser.status = String::from("Running...");
+                draw(&mut screen.0, &browser.render(rows, cols)).map_err(|e| e.to_string())?;
+            }
+            if !browser.handle(key, capture) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub fn run(_capture: Capture) -> Result<(), String> {
+    Err(String::from("the terminal browser needs a Unix terminal"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::find;
+
+    fn strip_ansi(line: &str) -> String {
+        let mut text = String::new();
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            } else {
+                text.push(c);
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn reads_keys_and_escape_sequences() {
+        assert_eq!(
+            parse_keys(b"\x1b[A\x1b[Bjk\r"),
+            [Key::Up, Key::Down, Key::Down, Key::Up, Key::Run]
+        );
+        assert_eq!(
+            parse_keys(b"\x1b[6~\x1b[1;5Ce"),
+            [Key::PageDown, Key::Explain]
+        );
+        assert_eq!(parse_keys(b"\x1b"), [Key::Quit]);
+        assert_eq!(parse_keys(b"\x03"), [Key::Quit]);
+    }
+
+    #[test]
+    fn moves_through_sections_and_lessons() {
+        let mut browser = Browser::new(Progress::default());
+        let mut capture = |_: &Entry, _: usize| Ok(String::new());
+        for _ in 0..5 {
+            browser.handle(Key::Down, &mut capture);
+        }
+        // Ownership Basics has five sections, so this is the next lesson
+        assert_eq!((browser.lesson, browser.section), (1, 0));
+        browser.handle(Key::Up, &mut capture);
+        assert_eq!((browser.lesson, browser.section), (0, 4));
+        browser.handle(Key::Left, &mut capture);
+        assert_eq!((browser.lesson, browser.section), (0, 4));
+        assert!(!browser.handle(Key::Quit, &mut capture));
+    }
+
+    #[test]
+    fn draws_tree_source_and_output() {
+        let mut browser = Browser::new(Progress::default());
+        let mut capture =
+            |entry: &Entry, section: usize| Ok(format!("   ran {} {}\n", entry.id, section));
+        browser.handle(Key::Down, &mut capture);
+        browser.handle(Key::Run, &mut capture);
+
+        let screen = browser.render(30, 140);
+        assert_eq!(screen.len(), 30);
+        for line in &screen {
+            assert_eq!(strip_ansi(line).chars().count(), 140, "{:?}", line);
+        }
+        let text: Vec<String> = screen.iter().map(|l| strip_ansi(l)).collect();
+        let text = text.join("\n");
+        assert!(text.contains("Ownership"));
+        assert!(text.contains("9. Result"));
+        assert!(text.contains("2. Copy Types"));
+        assert!(text.contains("fn take_integer(x: i32)"));
+        assert!(text.contains("ran ownership-basics 2"));
+        assert!(screen.iter().any(|l| l.contains("\x1b[7m     2. ")));
+    }
+
+    #[test]
+    fn explains_a_section_from_its_comments() {
+        let entry = find("ownership-basics").unwrap();
+        let lines = explanation(entry, 0);
+        assert!(lines.contains(&String::from("• s is dropped here when function ends")));
+        assert!(lines.iter().any(|l| l == "Key takeaways:"));
+        assert_eq!(
+            lines.last().unwrap(),
+            &format!("• {}", entry.lesson.takeaways.last().unwrap())
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// Watch Mode
+// Re-runs a lesson whenever its source file is saved. The lessons are
+// compiled into `learn`, so each change means a rebuild: into a target
+// directory of its own, leaving the running binary alone. The new output is
+// shown as a diff against the previous run.
+
+use crate::lessons::Entry;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+/// Lines of unchanged output kept around each change.
+pub const CONTEXT: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Same(String),
+    Removed(String),
+    Added(String),
+}
+
+// ============================================================================
+// Watching and Rebuilding
+// ============================================================================
+
+fn crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The lesson's source file in this checkout.
+pub fn source_path(entry: &Entry) -> PathBuf {
+    crate_dir().join(entry.file)
+}
+
+/// When the file was last written.
+pub fn modified(path: &Path) -> Result<SystemTime, String> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Builds `learn` from the current source and returns the new executable.
+/// Compile errors come back as the error, in cargo's words.
+pub fn rebuild() -> Result<PathBuf, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let target = crate_dir().join("target").join("learn-watch");
+    let output = Command::new(cargo)
+        .current_dir(crate_dir())
+        .args(["build", "--quiet", "--bin", "learn", "--target-dir"])
+        .arg(&target)
+        .output()
+        .map_err(|e| format!("failed to run cargo: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim_end()
+            .to_string());
+    }
+    Ok(target
+        .join("debug")
+        .join(format!("learn{}", env::consts::EXE_SUFFIX)))
+}
+
+/// The whole lesson's output from a build of `learn`. A panic comes back
+/// as the error, after whatever was printed before it.
+pub fn lesson_output(exe: &Path, entry: &Entry) -> Result<String, String> {
+    let output = Command::new(exe)
+        .args(["output", entry.id])
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", exe.display(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if output.status.success() {
+        Ok(stdout)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("{}{}", stdout, stderr.trim_end()))
+    }
+}
+
+// ============================================================================
+// Diffing Output
+// ============================================================================
+
+/// The line-by-line changes from `old` to `new`, along a longest common
+/// subsequence.
+pub fn diff(old: &str, new: &str) -> Vec<Change> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // common[i][j]: the longest common run of old[i..] and new[j..]
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut changes = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            changes.push(Change::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            changes.push(Change::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            changes.push(Change::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    changes
+}
+
+/// The changes as text, "- " and "+ " marking lines, with `context`
+/// unchanged lines around each change and "..." for the rest.
+pub fn render(changes: &[Change], context: usize) -> String {
+    let changed: Vec<usize> = changes
+        .iter()
+        .enumerate()
+        .filter(|(_, change)| !matches!(change, Change::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+    let near = |i: usize| changed.iter().any(|&c| c.abs_diff(i) <= context);
+
+    let mut text = String::new();
+    let mut skipped = false;
+    for (i, change) in changes.iter().enumerate() {
+        let line = match change {
+            Change::Same(_) if !near(i) => {
+                skipped = true;
+                continue;
+            }
+            Change::Same(line) => format!("  {}", line),
+            Change::Removed(line) => format!("- {}", line),
+            Change::Added(line) => format!("+ {}", line),
+        };
+        if skipped {
+            text += "  ...\n";
+            skipped = false;
+        }
+        text += &line;
+        text.push('\n');
+    }
+    if skipped {
+        text += "  ...\n";
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::find;
+
+    #[test]
+    fn diffs_changed_lines() {
+        let old = "a\nb\nc\nd\n";
+        let new = "a\nB\nc\nd\ne\n";
+        assert_eq!(
+            diff(old, new),
+            [
+                Change::Same(String::from("a")),
+                Change::Removed(String::from("b")),
+                Change::Added(String::from("B")),
+                Change::Same(String::from("c")),
+                Change::Same(String::from("d")),
+                Change::Added(String::from("e")),
+            ]
+        );
+        assert!(diff(old, old).iter().all(|c| matches!(c, Change::Same(_))));
+        assert_eq!(diff("", "x"), [Change::Added(String::from("x"))]);
+        assert_eq!(diff("x", ""), [Change::Removed(String::from("x"))]);
+    }
+
+    #[test]
+    fn shows_changes_in_context() {
+        let old: String = (1..=10).map(|n| format!("line {}\n", n)).collect();
+        let new = old.replace("line 5\n", "line five\n");
+        assert_eq!(
+            render(&diff(&old, &new), 1),
+            "  ...\n  line 4\n- line 5\n+ line five\n  line 6\n  ...\n"
+        );
+        assert_eq!(render(&diff(&old, &old), 1), "  ...\n");
+    }
+
+    #[test]
+    fn finds_the_lesson_file() {
+        let path = source_path(find("string-types").unwrap());
+        assert!(path.ends_with("examples/ownership/04_string_types.rs"));
+        assert!(modified(&path).is_ok());
+    }
+}
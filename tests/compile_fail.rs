@@ -0,0 +1,70 @@
+// Compile-fail checks for the lessons' "this won't compile" claims
+// Each file in tests/compile_fail/ starts with the error it must produce:
+//     // error[E0597]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn rustc() -> String {
+    env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"))
+}
+
+fn cases() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    let mut cases: Vec<PathBuf> = fs::read_dir(&dir)
+        .expect("tests/compile_fail exists")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    cases.sort();
+    cases
+}
+
+fn expected_error(source: &str) -> &str {
+    source
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("// "))
+        .filter(|code| code.starts_with("error["))
+        .expect("first line names the expected error, e.g. // error[E0597]")
+}
+
+#[test]
+fn lesson_counterexamples_fail_with_the_documented_error() {
+    let out_dir = env::temp_dir().join(format!("learn-rust-compile-fail-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let cases = cases();
+    assert!(!cases.is_empty(), "no compile-fail cases found");
+
+    for case in cases {
+        let source = fs::read_to_string(&case).unwrap();
+        let expected = expected_error(&source);
+
+        // metadata only: borrow checking runs, linking doesn't
+        let output = Command::new(rustc())
+            .args(["--edition", "2021", "--emit=metadata", "--out-dir"])
+            .arg(&out_dir)
+            .arg(&case)
+            .output()
+            .expect("failed to run rustc");
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            !output.status.success(),
+            "{} compiled, but shouldn't",
+            case.display()
+        );
+        assert!(
+            stderr.contains(expected),
+            "{} should fail with {}, got:\n{}",
+            case.display(),
+            expected,
+            stderr
+        );
+    }
+
+    fs::remove_dir_all(&out_dir).ok();
+}
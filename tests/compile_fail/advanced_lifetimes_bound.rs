@@ -0,0 +1,14 @@
+// error[E0309]
+// Lesson: 06_advanced_lifetimes.rs, section 1 (Lifetime Bounds)
+// The box may be kept for all of 'a, but nothing says T lasts that long.
+// Fix: fn boxed<'a, T: Debug + 'a>
+
+use std::fmt::Debug;
+
+fn boxed<'a, T: Debug>(value: T) -> Box<dyn Debug + 'a> {
+    Box::new(value)
+}
+
+fn main() {
+    println!("{:?}", boxed(42));
+}
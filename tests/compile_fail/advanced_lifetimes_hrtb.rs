@@ -0,0 +1,17 @@
+// error[E0597]
+// Lesson: 06_advanced_lifetimes.rs, section 2 (Higher-Ranked Trait Bounds)
+// The caller chooses 'a, so it must outlive the whole call - `local` doesn't.
+// Fix: F: for<'a> Fn(&'a str) -> &'a str
+
+fn first_word<'a>(s: &'a str) -> &'a str {
+    s.split(' ').next().unwrap_or("")
+}
+
+fn apply_to_local<'a, F: Fn(&'a str) -> &'a str>(f: F) -> String {
+    let local = String::from("hello world");
+    f(&local).to_string()
+}
+
+fn main() {
+    println!("{}", apply_to_local(first_word));
+}
@@ -0,0 +1,27 @@
+// error[E0505]
+// Lesson: 06_advanced_lifetimes.rs, section 4 (Self-Referential Structs)
+// `first` borrows `novel`, so `novel` can't be moved into the struct.
+// Fix: store a Range<usize> and borrow on demand
+
+struct Excerpt<'a> {
+    text: &'a str,
+}
+
+struct Book<'a> {
+    novel: String,
+    excerpt: Excerpt<'a>,
+}
+
+fn make_book<'a>() -> Book<'a> {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first = novel.split('.').next().unwrap();
+    Book {
+        excerpt: Excerpt { text: first },
+        novel,
+    }
+}
+
+fn main() {
+    let book = make_book();
+    println!("{} {}", book.novel, book.excerpt.text);
+}
@@ -0,0 +1,12 @@
+// error[E0597]
+// Lesson: 06_advanced_lifetimes.rs, section 5 (&'static T vs T: 'static)
+// T: 'static rules out borrows of locals - pass an owned String instead.
+
+fn describe_static<T: std::fmt::Debug + 'static>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+fn main() {
+    let local = String::from("x");
+    println!("{}", describe_static(&local));
+}
@@ -0,0 +1,17 @@
+// error[E0597]
+// Lesson: 06_advanced_lifetimes.rs, section 3 (Variance)
+// &mut &'static str is invariant: 'a is forced to 'static, so the
+// short-lived `local` can't be written into `s`.
+
+fn assign<'a>(target: &mut &'a str, value: &'a str) {
+    *target = value;
+}
+
+fn main() {
+    let mut s: &'static str = "static";
+    {
+        let local = String::from("short");
+        assign(&mut s, &local);
+    }
+    println!("{}", s);
+}
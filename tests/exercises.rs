@@ -0,0 +1,48 @@
+// The reference solutions must pass every hidden test, so each exercise
+// is known to be solvable as written
+
+use learn_rust::exercises::{self, Outcome};
+
+#[test]
+fn reference_solutions_pass_the_hidden_tests() {
+    for exercise in &exercises::EXERCISES {
+        let outcome = exercises::check_source(exercise, exercise.solution, exercise.name)
+            .expect("rustc should run");
+        assert!(outcome.passed(), "{}: {:?}", exercise.name, outcome);
+    }
+}
+
+#[test]
+fn untouched_stubs_fail() {
+    // longest is missing its lifetimes; the others panic with todo!()
+    let longest = exercises::find("longest").unwrap();
+    let stub = "fn longest(x: &str, y: &str) -> &str {\n    x\n}\n";
+    match exercises::check_source(longest, stub, "longest.rs").unwrap() {
+        Outcome::CompileError(errors) => {
+            assert!(errors.contains("error[E0106]"), "{}", errors);
+            assert!(errors.contains("longest.rs:1:"), "{}", errors);
+        }
+        other => panic!("expected a compile error, got {:?}", other),
+    }
+
+    let double_all = exercises::find("double_all").unwrap();
+    let stub = "fn double_all(numbers: &mut Vec<i32>) {\n    todo!()\n}\n";
+    match exercises::check_source(double_all, stub, "double_all.rs").unwrap() {
+        Outcome::Tested(results) => {
+            assert!(results.iter().all(|r| !r.passed));
+            assert!(results[0].message.contains("not yet implemented"));
+        }
+        other => panic!("expected failing tests, got {:?}", other),
+    }
+}
+
+#[test]
+fn every_exercise_has_a_hint() {
+    for exercise in &exercises::EXERCISES {
+        assert!(
+            !exercise.hints().is_empty(),
+            "{} has no hints",
+            exercise.name
+        );
+    }
+}
@@ -0,0 +1,64 @@
+// Error explanations: every program in the table fails with the error it
+// explains, under the message rustc gives, and its fix compiles
+
+use learn_rust::explain;
+use learn_rust::quiz;
+use std::process::Command;
+
+// "error[E0382]: borrow of moved value: `s1`" reads
+// "borrow of moved value", as the table writes it
+fn message(error: &str) -> String {
+    let text = error.split_once("]: ").map_or(error, |(_, text)| text);
+    let mut words = Vec::new();
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 0 {
+            words.extend(part.split_whitespace());
+        }
+    }
+    let mut message = words.join(" ").replace(" ,", ",");
+    while message.ends_with(':') {
+        message.pop();
+    }
+    message
+}
+
+#[test]
+fn the_table_matches_the_compiler() {
+    for explanation in explain::bundled().unwrap() {
+        let error = quiz::compile_error(&explanation.fails)
+            .unwrap()
+            .unwrap_or_else(|| panic!("{}: the failing program compiles", explanation.code));
+        assert!(
+            error.starts_with(&format!("error[{}]", explanation.code)),
+            "{}: rustc says {}",
+            explanation.code,
+            error
+        );
+        assert_eq!(message(&error), explanation.message, "{}", explanation.code);
+
+        let fixed = quiz::compile_error(&explanation.fix).unwrap();
+        assert_eq!(fixed, None, "{}: the fix doesn't compile", explanation.code);
+    }
+}
+
+#[test]
+fn explains_a_code_with_its_lesson() {
+    let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["explain", "e0502"])
+        .output()
+        .expect("failed to run learn");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("E0502: cannot borrow as mutable"));
+    assert!(stdout
+        .contains("section 3. Borrow Rules (can't mix &mut with &) (`demonstrate_borrow_rules`)"));
+    assert!(stdout.contains("learn -- run mutable-borrowing 3"));
+    assert!(stdout.contains("This fails with E0502:\n\n    fn main() {\n        let mut s"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["explain", "E0001"])
+        .output()
+        .expect("failed to run learn");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("rustc --explain E0001"));
+}
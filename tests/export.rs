@@ -0,0 +1,96 @@
+// Book export: `learn export` writes a chapter per lesson plus the contents,
+// with real section output and nothing fetched from the network
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn export(format: &str, dir: &Path) -> String {
+    fs::remove_dir_all(dir).ok();
+    let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["export", "--format", format])
+        .arg(dir)
+        .env("LEARN_RUST_DATA_DIR", dir.join("data"))
+        .output()
+        .expect("failed to run learn");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn exports_a_self_contained_html_book() {
+    let dir = std::env::temp_dir().join(format!("learn-export-html-{}", std::process::id()));
+    export("html", &dir);
+
+    let pages: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "html"))
+        .collect();
+    assert_eq!(pages.len(), 10);
+
+    for page in &pages {
+        let html = fs::read_to_string(page).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        for external in ["http:", "https:", "<link", "<script", "src="] {
+            assert!(
+                !html.contains(external),
+                "{} uses {}",
+                page.display(),
+                external
+            );
+        }
+    }
+
+    let chapter = fs::read_to_string(dir.join("08-option.html")).unwrap();
+    assert!(chapter.contains("<pre class=\"output\">   User 1: Some(&quot;Alice&quot;)"));
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn exports_markdown_chapters() {
+    let dir = std::env::temp_dir().join(format!("learn-export-md-{}", std::process::id()));
+    export("md", &dir);
+
+    let chapter = fs::read_to_string(dir.join("01-ownership-basics.md")).unwrap();
+    assert!(chapter.contains("## 5. Scope and Drop\n\n```rust\n"));
+    assert!(
+        chapter.contains("```text\n   Inside: inner scope\n   Outside: s has been dropped\n```")
+    );
+    assert!(fs::read_to_string(dir.join("index.md"))
+        .unwrap()
+        .contains("1. [Ownership Basics](01-ownership-basics.md)"));
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn exports_one_anki_deck_per_topic() {
+    let dir = std::env::temp_dir().join(format!("learn-export-tsv-{}", std::process::id()));
+    export("tsv", &dir);
+
+    for topic in [
+        "ownership",
+        "borrowing",
+        "lifetimes",
+        "dereference",
+        "option",
+        "result",
+    ] {
+        let deck = fs::read_to_string(dir.join(format!("{}.tsv", topic))).unwrap();
+        assert!(deck.starts_with("#separator:tab\n#html:true\n#deck:Learn Rust::"));
+        for card in deck.lines().filter(|line| !line.starts_with('#')) {
+            let fields: Vec<&str> = card.split('\t').collect();
+            assert_eq!(fields.len(), 4, "{}", card);
+            assert!(fields[2].starts_with("examples/"), "{}", card);
+        }
+    }
+
+    // Quiz cards carry the real answer
+    let option = fs::read_to_string(dir.join("option.tsv")).unwrap();
+    assert!(option.contains("\tGuest\texamples/option_examples.rs:"));
+    fs::remove_dir_all(&dir).ok();
+}
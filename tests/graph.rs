@@ -0,0 +1,54 @@
+// Curriculum graph: `learn graph` draws the prerequisites, and strict mode
+// warns before a lesson whose prerequisites aren't finished
+
+use std::fs;
+use std::process::{Command, Output};
+
+fn learn(args: &[&str], strict: bool, data_dir: &str) -> Output {
+    let dir = std::env::temp_dir().join(format!("learn-graph-{}-{}", data_dir, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_learn"));
+    command.args(args).env("LEARN_RUST_DATA_DIR", &dir);
+    if strict {
+        command.env("LEARN_RUST_STRICT", "1");
+    } else {
+        command.env_remove("LEARN_RUST_STRICT");
+    }
+    command.output().expect("failed to run learn")
+}
+
+#[test]
+fn draws_the_curriculum() {
+    let output = learn(&["graph"], false, "text");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("   lifetimes            after borrowing\n"));
+
+    let output = learn(&["graph", "--format", "dot"], false, "dot");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("digraph curriculum {"));
+    assert!(stdout.contains("\"ownership-basics\" -> \"borrowing\";"));
+
+    let output = learn(&["graph", "--format", "svg"], false, "svg");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn strict_mode_warns_about_unfinished_prerequisites() {
+    let output = learn(&["run", "lifetimes", "1"], true, "strict");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Warning: lifetimes builds on borrowing"),
+        "{}",
+        stderr
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("1. Basic Lifetime:"));
+
+    // The first lesson requires nothing, and without strict mode nothing is said
+    let output = learn(&["run", "ownership-basics", "1"], true, "strict");
+    assert!(output.stderr.is_empty());
+    let output = learn(&["run", "lifetimes", "1"], false, "relaxed");
+    assert!(output.stderr.is_empty());
+}
@@ -0,0 +1,28 @@
+// Runs the unsafe lesson's tests under Miri when it is installed
+// Miri needs a nightly toolchain, so without it this test is skipped
+
+use std::process::Command;
+
+fn miri_installed() -> bool {
+    Command::new(env!("CARGO"))
+        .args(["miri", "--version"])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[test]
+fn unsafe_examples_pass_under_miri() {
+    if !miri_installed() {
+        eprintln!("skipping: `cargo miri` is not installed (rustup +nightly component add miri)");
+        return;
+    }
+
+    let status = Command::new(env!("CARGO"))
+        .args(["miri", "test", "--example", "unsafe_examples"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .expect("failed to run cargo miri");
+
+    assert!(status.success(), "unsafe_examples failed under Miri");
+}
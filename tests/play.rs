@@ -0,0 +1,70 @@
+// Playground: `learn play` runs a snippet against the lesson library, maps
+// rustc's errors to the snippet's lines and stops runaway programs
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn snippet(name: &str, code: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("learn-play-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, code).unwrap();
+    path
+}
+
+fn play(args: &[&str], path: &PathBuf) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_learn"))
+        .arg("play")
+        .args(args)
+        .arg(path)
+        .output()
+        .expect("failed to run learn")
+}
+
+#[test]
+fn calls_the_lesson_helpers() {
+    let path = snippet(
+        "helpers.rs",
+        "let name = find_user(2).unwrap();\n\
+         println!(\"{}\", longest(&name, \"Carol\"));\n\
+         println!(\"{:?}\", learn_rust::lessons::prelude::divide(1.0, 0.0));\n",
+    );
+    let output = play(&[], &path);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Carol\nErr(\"Cannot divide by zero\")\n"
+    );
+}
+
+#[test]
+fn reports_errors_at_the_snippet_lines() {
+    let path = snippet(
+        "moved.rs",
+        "let s = String::from(\"hi\");\nlet t = s;\nprintln!(\"{} {}\", s, t);\n",
+    );
+    let output = play(&[], &path);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error[E0382]"));
+    assert!(stderr.contains(&format!("--> {}:3:", path.display())));
+    assert!(stderr.contains("2 | let t = s;"));
+    assert!(!stderr.contains("play.rs"));
+}
+
+#[test]
+fn stops_a_runaway_snippet() {
+    let path = snippet(
+        "spin.rs",
+        "let mut n: u64 = 0;\nloop {\n    n = n.wrapping_add(1);\n}\n",
+    );
+    let output = play(&["--limit", "1"], &path);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Stopped: the snippet"), "{}", stderr);
+}
@@ -0,0 +1,76 @@
+// Quiz checks: every bundled question must be answerable from the lessons'
+// real output or from rustc, and a perfect set of answers must score fully
+
+use learn_rust::quiz::{self, Kind, Question};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn learn() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_learn"));
+    command.env(
+        "LEARN_RUST_DATA_DIR",
+        std::env::temp_dir().join("learn-quiz-test"),
+    );
+    command
+}
+
+fn right_answer(question: &Question) -> String {
+    match question.kind {
+        Kind::Predict(_) => {
+            let output = learn()
+                .args(["output", &question.lesson, &question.section.to_string()])
+                .output()
+                .expect("failed to run learn");
+            assert!(output.status.success());
+            question
+                .answer_from_output(&String::from_utf8_lossy(&output.stdout))
+                .unwrap_or_else(|| panic!("no answer in the output for {:?}", question))
+        }
+        Kind::Compiles => match quiz::compile_error(&question.snippet).unwrap() {
+            Some(_) => String::from("no"),
+            None => String::from("yes"),
+        },
+    }
+}
+
+#[test]
+fn every_question_has_its_answer_among_the_choices() {
+    for question in quiz::bundled().unwrap() {
+        let answer = right_answer(&question);
+        assert!(
+            question.choices.is_empty() || question.choices.contains(&answer),
+            "`{}` isn't a choice for {:?}",
+            answer,
+            question
+        );
+    }
+}
+
+#[test]
+fn right_answers_score_full_marks() {
+    let questions: Vec<Question> = quiz::bundled()
+        .unwrap()
+        .into_iter()
+        .filter(|q| q.lesson == "option")
+        .collect();
+    let answers: String = questions.iter().map(|q| right_answer(q) + "\n").collect();
+
+    let mut child = learn()
+        .args(["quiz", "option"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run learn");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(answers.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let expected = format!("Score: {0}/{0}", questions.len());
+    assert!(stdout.contains(&expected), "{}", stdout);
+    assert!(!stdout.contains("Not quite"));
+}
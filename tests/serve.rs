@@ -0,0 +1,99 @@
+// Lesson server: `learn serve` answers plain HTTP on localhost, rendering
+// sections with their output and streaming a section run back in chunks
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+struct Server {
+    child: Child,
+    address: String,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.child.kill().ok();
+        self.child.wait().ok();
+    }
+}
+
+fn start() -> Server {
+    let data = std::env::temp_dir().join(format!("learn-serve-{}", std::process::id()));
+    let mut child = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["serve", "--port", "0"])
+        .env("LEARN_RUST_DATA_DIR", data)
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run learn");
+
+    // "Serving the lessons at http://127.0.0.1:PORT/ (Ctrl+C to stop)"
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    let address = line
+        .split("http://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .unwrap_or_else(|| panic!("no address in {:?}", line))
+        .to_string();
+    Server { child, address }
+}
+
+// (status line, headers, body)
+fn request(server: &Server, method: &str, path: &str) -> (String, String, String) {
+    let mut stream = TcpStream::connect(&server.address).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: 0\r\n\r\n",
+        method, path, server.address
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let (status, headers) = head.split_once("\r\n").unwrap_or((head, ""));
+    (status.to_string(), headers.to_string(), body.to_string())
+}
+
+fn dechunk(mut body: &str) -> String {
+    let mut text = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n").expect("truncated chunk");
+        let size = usize::from_str_radix(size, 16).unwrap();
+        if size == 0 {
+            return text;
+        }
+        text += &rest[..size];
+        body = &rest[size + 2..];
+    }
+}
+
+#[test]
+fn serves_lessons_and_runs_sections() {
+    let server = start();
+
+    let (status, _, index) = request(&server, "GET", "/");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(index.contains("<a href=\"/lesson/option\">"));
+    assert!(index.contains("<h2>Lifetimes</h2>"));
+
+    let (status, _, page) = request(&server, "GET", "/lesson/option/1");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(page.contains("find_user"));
+    assert!(page.contains("User 1: Some(&quot;Alice&quot;)"));
+    assert!(page.contains("data-url=\"/run/option/1\""));
+
+    let (status, headers, body) = request(&server, "POST", "/run/option/3");
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(headers.contains("Transfer-Encoding: chunked"));
+    assert!(dechunk(&body).contains("Hello, Alice!"));
+
+    let (status, _, _) = request(&server, "GET", "/lesson/option/99");
+    assert_eq!(status, "HTTP/1.1 404 Not Found");
+    let (status, _, _) = request(&server, "GET", "/nope");
+    assert_eq!(status, "HTTP/1.1 404 Not Found");
+    let (status, _, _) = request(&server, "DELETE", "/");
+    assert_eq!(status, "HTTP/1.1 405 Method Not Allowed");
+}